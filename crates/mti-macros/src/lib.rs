@@ -0,0 +1,210 @@
+//! # `mti_macros`
+//!
+//! Procedural macros for the [`mti`](https://docs.rs/mti) crate.
+//!
+//! This crate is not meant to be used directly. Enable the `macros` feature of `mti` and use
+//! the macros through `mti::prelude`:
+//!
+//! ```toml
+//! [dependencies]
+//! mti = { version = "1", features = ["macros"] }
+//! ```
+//!
+//! ## `#[derive(MagicId)]`
+//!
+//! Turns a struct wrapping a single `MagicTypeId` into a type-safe identifier whose prefix is
+//! fixed at compile time:
+//!
+//! ```
+//! use mti::prelude::*;
+//!
+//! #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, MagicId)]
+//! #[mti(prefix = "user", version = "v7")]
+//! struct UserId(MagicTypeId);
+//!
+//! let id = UserId::new();
+//! assert!(id.as_str().starts_with("user_"));
+//!
+//! let parsed = UserId::parse(id.as_str()).unwrap();
+//! assert_eq!(parsed, id);
+//!
+//! // Identifiers with another prefix are rejected.
+//! assert!(UserId::parse("order_01h455vb4pex5vsknk084sn02q").is_err());
+//! ```
+//!
+//! The prefix is checked with the same rules as `TypeIdPrefix` at compile time, so an invalid
+//! prefix is a compile error rather than a runtime panic:
+//!
+//! ```compile_fail
+//! use mti::prelude::*;
+//!
+//! #[derive(MagicId)]
+//! #[mti(prefix = "User")]
+//! struct UserId(MagicTypeId);
+//! ```
+//!
+//! ```compile_fail
+//! use mti::prelude::*;
+//!
+//! #[derive(MagicId)]
+//! #[mti(prefix = "_user")]
+//! struct UserId(MagicTypeId);
+//! ```
+//!
+//! See [`MagicId`] for the full list of attributes and generated items.
+//!
+//! ## `typeid!` and `prefix!`
+//!
+//! Check identifier and prefix literals at compile time instead of unwrapping a runtime parse:
+//!
+//! ```
+//! use mti::prelude::*;
+//!
+//! let admin_id: MagicTypeId = typeid!("user_01h455vb4pex5vsknk084sn02q");
+//! let user_prefix: TypeIdPrefix = prefix!("user");
+//! assert_eq!(admin_id.prefix(), &user_prefix);
+//! ```
+//!
+//! A literal that breaks a `TypeID` rule fails to compile, and the error names the rule:
+//!
+//! ```compile_fail
+//! use mti::prelude::*;
+//!
+//! // error: invalid TypeID suffix "81h455vb4pex5vsknk084sn02q": Invalid `TypeID` suffix:
+//! //        First character of suffix must be '7' or less
+//! let id = typeid!("user_81h455vb4pex5vsknk084sn02q");
+//! ```
+
+mod literal;
+mod magic_id;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput, LitStr};
+
+/// Derives a type-safe identifier for a struct wrapping a single `MagicTypeId`.
+///
+/// The struct may be a tuple struct (`struct UserId(MagicTypeId);`) or have a single named
+/// field. Generic structs are not supported.
+///
+/// # Attributes
+///
+/// * `#[mti(prefix = "...")]` (required): The prefix of every identifier of this type. It must
+///   be a valid, non-empty `TypeID` prefix; invalid prefixes are rejected at compile time.
+/// * `#[mti(version = "...")]` (optional): The UUID version used by `new()`. One of `"v1"`,
+///   `"v4"`, `"v6"` or `"v7"`. Defaults to `"v7"`.
+///
+/// # Generated items
+///
+/// * `const PREFIX: &'static str`
+/// * `fn new() -> Self` and a `Default` implementation calling it
+/// * `fn from_uuid(uuid: Uuid) -> Self`
+/// * `fn parse(s: &str) -> Result<Self, MagicTypeIdError>`, which rejects other prefixes
+/// * `fn into_inner(self) -> MagicTypeId`
+/// * `FromStr`, `Display`, `Deref<Target = MagicTypeId>`, `AsRef<MagicTypeId>`, `AsRef<str>`,
+///   `From<Self> for MagicTypeId` and `TryFrom<MagicTypeId>`
+/// * `Serialize` and `Deserialize` when the `serde` feature of `mti` is enabled
+/// * Diesel's `AsExpression`, `ToSql`, `FromSql` and `Queryable` for `Text`, `Uuid` and `Binary`
+///   columns when the `diesel` feature of `mti` is enabled, like a `TypedId`
+/// * `sqlx::Type`, `Encode` and `Decode` when the `sqlx` feature of `mti` is enabled, storing
+///   the UUID of the suffix like a `TypedId`
+/// * `ToSql` and `FromSql` from `rusqlite` when the `rusqlite` feature of `mti` is enabled,
+///   writing a 16-byte blob like a `TypedId`
+///
+/// Comparison, hashing, `Debug` and `Clone` are left to the standard derives.
+///
+/// # Examples
+///
+/// ```
+/// use mti::prelude::*;
+///
+/// #[derive(Debug, Clone, PartialEq, Eq, MagicId)]
+/// #[mti(prefix = "order", version = "v4")]
+/// struct OrderId {
+///     id: MagicTypeId,
+/// }
+///
+/// let uuid = Uuid::new_v4();
+/// let order_id = OrderId::from_uuid(uuid);
+/// assert_eq!(order_id.prefix().as_str(), OrderId::PREFIX);
+/// assert_eq!(order_id.suffix().to_uuid(), uuid);
+///
+/// let id: MagicTypeId = order_id.clone().into();
+/// assert_eq!(OrderId::try_from(id).unwrap(), order_id);
+/// ```
+#[proc_macro_derive(MagicId, attributes(mti))]
+pub fn derive_magic_id(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    magic_id::expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Creates a `MagicTypeId` from a string literal that is validated at compile time.
+///
+/// The literal is split like `MagicTypeId::from_str`: everything before the last underscore is
+/// the prefix, checked with the `TypeIdPrefix` rules, and the rest is the suffix, checked against
+/// the base32 alphabet and the rule that its first character must be `'7'` or less. A literal
+/// without an underscore is a bare suffix with an empty prefix.
+///
+/// The expansion constructs the identifier infallibly; there is no `unwrap` at runtime.
+///
+/// # Examples
+///
+/// ```
+/// use mti::prelude::*;
+///
+/// let id = typeid!("user_01h455vb4pex5vsknk084sn02q");
+/// assert_eq!(id.prefix().as_str(), "user");
+/// assert_eq!(id.suffix().to_string(), "01h455vb4pex5vsknk084sn02q");
+///
+/// let bare = typeid!("00000000000000000000000000");
+/// assert!(bare.prefix().is_empty());
+/// ```
+///
+/// Invalid literals are rejected by the compiler:
+///
+/// ```compile_fail
+/// use mti::prelude::*;
+///
+/// let id = typeid!("User_01h455vb4pex5vsknk084sn02q");
+/// ```
+///
+/// ```compile_fail
+/// use mti::prelude::*;
+///
+/// let id = typeid!("user_01h455vb4pex5vsknk084sn02");
+/// ```
+#[proc_macro]
+pub fn typeid(input: TokenStream) -> TokenStream {
+    let lit = parse_macro_input!(input as LitStr);
+    literal::expand_typeid(&lit)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Creates a `TypeIdPrefix` from a string literal that is validated at compile time.
+///
+/// The literal must satisfy the `TypeIdPrefix` rules; the compile error of an invalid literal
+/// names the rule it violates.
+///
+/// # Examples
+///
+/// ```
+/// use mti::prelude::*;
+///
+/// let prefix = prefix!("order_item");
+/// assert_eq!(prefix.as_str(), "order_item");
+/// ```
+///
+/// ```compile_fail
+/// use mti::prelude::*;
+///
+/// let prefix = prefix!("order_");
+/// ```
+#[proc_macro]
+pub fn prefix(input: TokenStream) -> TokenStream {
+    let lit = parse_macro_input!(input as LitStr);
+    literal::expand_prefix(&lit)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,68 @@
+//! Expansion of the `typeid!` and `prefix!` literal macros.
+
+use std::str::FromStr;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::LitStr;
+use typeid_prefix::TypeIdPrefix;
+use typeid_suffix::prelude::TypeIdSuffix;
+
+/// Validates `lit` as a `TypeIdPrefix`, reporting the violated rule on failure.
+pub fn validate_prefix(lit: &LitStr, prefix: &str) -> syn::Result<()> {
+    TypeIdPrefix::from_str(prefix).map(|_| ()).map_err(|err| {
+        syn::Error::new(
+            lit.span(),
+            format!("invalid TypeID prefix \"{prefix}\": {err}"),
+        )
+    })
+}
+
+/// Validates `lit` as a `TypeIdSuffix` and returns the UUID it encodes.
+fn validate_suffix(lit: &LitStr, suffix: &str) -> syn::Result<u128> {
+    TypeIdSuffix::from_str(suffix)
+        .map(|suffix| suffix.to_uuid().as_u128())
+        .map_err(|err| {
+            syn::Error::new(
+                lit.span(),
+                format!("invalid TypeID suffix \"{suffix}\": {err}"),
+            )
+        })
+}
+
+/// Expands `typeid!("prefix_suffix")`.
+///
+/// The literal is split exactly like `MagicTypeId::from_str`: on the last underscore, with an
+/// identifier that has no underscore taken as a bare suffix.
+pub fn expand_typeid(lit: &LitStr) -> syn::Result<TokenStream> {
+    let value = lit.value();
+    let (prefix, suffix) = match value.rsplit_once('_') {
+        Some(("", _)) => {
+            return Err(syn::Error::new(
+                lit.span(),
+                format!(
+                    "invalid TypeID \"{value}\": the prefix before the final underscore cannot be empty"
+                ),
+            ))
+        }
+        Some((prefix, suffix)) => {
+            validate_prefix(lit, prefix)?;
+            (prefix, suffix)
+        }
+        None => ("", value.as_str()),
+    };
+    let uuid = validate_suffix(lit, suffix)?;
+
+    Ok(quote! {
+        ::mti::__private::type_id_literal(#prefix, #uuid)
+    })
+}
+
+/// Expands `prefix!("prefix")`.
+pub fn expand_prefix(lit: &LitStr) -> syn::Result<TokenStream> {
+    validate_prefix(lit, &lit.value())?;
+
+    Ok(quote! {
+        ::mti::__private::prefix_literal(#lit)
+    })
+}
@@ -0,0 +1,259 @@
+//! Expansion of `#[derive(MagicId)]`.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Fields, LitStr, Member};
+
+use crate::literal::validate_prefix;
+
+/// The UUID versions a derived identifier can generate in `new()`.
+enum VersionArg {
+    V1,
+    V4,
+    V6,
+    V7,
+}
+
+impl VersionArg {
+    fn parse(lit: &LitStr) -> syn::Result<Self> {
+        match lit.value().to_ascii_lowercase().as_str() {
+            "v1" => Ok(Self::V1),
+            "v4" => Ok(Self::V4),
+            "v6" => Ok(Self::V6),
+            "v7" => Ok(Self::V7),
+            other => Err(syn::Error::new(
+                lit.span(),
+                format!("unsupported UUID version \"{other}\": expected one of \"v1\", \"v4\", \"v6\" or \"v7\""),
+            )),
+        }
+    }
+
+    fn marker_type(&self) -> TokenStream {
+        let ident = match self {
+            Self::V1 => format_ident!("V1"),
+            Self::V4 => format_ident!("V4"),
+            Self::V6 => format_ident!("V6"),
+            Self::V7 => format_ident!("V7"),
+        };
+        quote!(::mti::prelude::#ident)
+    }
+}
+
+/// The arguments of the `#[mti(...)]` attribute.
+struct MagicIdArgs {
+    prefix: LitStr,
+    version: VersionArg,
+}
+
+impl MagicIdArgs {
+    fn parse(input: &DeriveInput) -> syn::Result<Self> {
+        let mut prefix = None;
+        let mut version = None;
+
+        for attr in input
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("mti"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("prefix") {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    validate_prefix(&lit, &lit.value())?;
+                    prefix = Some(lit);
+                    Ok(())
+                } else if meta.path.is_ident("version") {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    version = Some(VersionArg::parse(&lit)?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown `mti` attribute: expected `prefix` or `version`"))
+                }
+            })?;
+        }
+
+        let prefix = prefix.ok_or_else(|| {
+            syn::Error::new_spanned(
+                &input.ident,
+                "missing prefix: add `#[mti(prefix = \"...\")]` to the type",
+            )
+        })?;
+
+        Ok(Self {
+            prefix,
+            version: version.unwrap_or(VersionArg::V7),
+        })
+    }
+}
+
+/// Returns the single field of a newtype struct.
+fn inner_field(input: &DeriveInput) -> syn::Result<Member> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "MagicId can only be derived for structs wrapping a single `MagicTypeId`",
+        ));
+    };
+
+    let mut fields = match &data.fields {
+        Fields::Named(fields) => fields.named.iter(),
+        Fields::Unnamed(fields) => fields.unnamed.iter(),
+        Fields::Unit => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "MagicId can only be derived for structs wrapping a single `MagicTypeId`",
+            ))
+        }
+    };
+
+    match (fields.next(), fields.next()) {
+        (Some(field), None) => Ok(field
+            .ident
+            .clone()
+            .map_or_else(|| Member::Unnamed(0.into()), Member::Named)),
+        _ => Err(syn::Error::new_spanned(
+            &data.fields,
+            "MagicId can only be derived for structs with exactly one field",
+        )),
+    }
+}
+
+/// Expands `#[derive(MagicId)]` for `input`.
+pub fn expand(input: &DeriveInput) -> syn::Result<TokenStream> {
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "MagicId cannot be derived for generic types",
+        ));
+    }
+
+    let args = MagicIdArgs::parse(input)?;
+    let field = inner_field(input)?;
+    let ident = &input.ident;
+    let prefix = &args.prefix;
+    let version = args.version.marker_type();
+
+    let wrap = |inner: TokenStream| match &field {
+        Member::Named(name) => quote!(Self { #name: #inner }),
+        Member::Unnamed(_) => quote!(Self(#inner)),
+    };
+    let from_new = wrap(quote!(::mti::__private::new_type_id::<#version>(Self::PREFIX)));
+    let from_uuid = wrap(quote!(::mti::__private::type_id_from_uuid(
+        Self::PREFIX,
+        uuid
+    )));
+    let from_id = wrap(quote!(id));
+
+    let prefix_doc = format!(
+        "The prefix carried by every `{ident}`: `{}`.",
+        prefix.value()
+    );
+    let new_doc = format!("Creates a new `{ident}` with a freshly generated suffix.");
+    let from_uuid_doc = format!("Creates a `{ident}` from an existing UUID.");
+    let parse_doc = format!(
+        "Parses a string into a `{ident}`.\n\n\
+         # Errors\n\n\
+         Returns a `MagicTypeIdError` if the string is not a valid `MagicTypeId` \
+         or if its prefix is not `{}`.",
+        prefix.value()
+    );
+    let into_inner_doc =
+        format!("Consumes the `{ident}` and returns the underlying `MagicTypeId`.");
+
+    let trait_impls = trait_impls(ident, &field, &from_id);
+
+    Ok(quote! {
+        impl #ident {
+            #[doc = #prefix_doc]
+            pub const PREFIX: &'static str = #prefix;
+
+            #[doc = #new_doc]
+            #[must_use]
+            pub fn new() -> Self {
+                #from_new
+            }
+
+            #[doc = #from_uuid_doc]
+            #[must_use]
+            pub fn from_uuid(uuid: ::mti::prelude::Uuid) -> Self {
+                #from_uuid
+            }
+
+            #[doc = #parse_doc]
+            pub fn parse(s: &str) -> ::core::result::Result<Self, ::mti::prelude::MagicTypeIdError> {
+                ::mti::__private::parse_with_prefix(s, Self::PREFIX).map(|id| #from_id)
+            }
+
+            #[doc = #into_inner_doc]
+            #[must_use]
+            pub fn into_inner(self) -> ::mti::prelude::MagicTypeId {
+                self.#field
+            }
+        }
+
+        #trait_impls
+        ::mti::__mti_serde_impls!(#ident);
+        ::mti::__mti_axum_impls!(#ident);
+        ::mti::__mti_diesel_impls!(#ident);
+        ::mti::__mti_sqlx_impls!(#ident);
+        ::mti::__mti_rusqlite_impls!(#ident);
+    })
+}
+
+/// Generates the standard trait implementations shared by every derived identifier.
+fn trait_impls(ident: &syn::Ident, field: &Member, from_id: &TokenStream) -> TokenStream {
+    quote! {
+        impl ::core::default::Default for #ident {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl ::core::str::FromStr for #ident {
+            type Err = ::mti::prelude::MagicTypeIdError;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                Self::parse(s)
+            }
+        }
+
+        impl ::core::fmt::Display for #ident {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(&self.#field, f)
+            }
+        }
+
+        impl ::core::ops::Deref for #ident {
+            type Target = ::mti::prelude::MagicTypeId;
+
+            fn deref(&self) -> &Self::Target {
+                &self.#field
+            }
+        }
+
+        impl ::core::convert::AsRef<::mti::prelude::MagicTypeId> for #ident {
+            fn as_ref(&self) -> &::mti::prelude::MagicTypeId {
+                &self.#field
+            }
+        }
+
+        impl ::core::convert::AsRef<str> for #ident {
+            fn as_ref(&self) -> &str {
+                self.#field.as_str()
+            }
+        }
+
+        impl ::core::convert::From<#ident> for ::mti::prelude::MagicTypeId {
+            fn from(value: #ident) -> Self {
+                value.#field
+            }
+        }
+
+        impl ::core::convert::TryFrom<::mti::prelude::MagicTypeId> for #ident {
+            type Error = ::mti::prelude::MagicTypeIdError;
+
+            fn try_from(id: ::mti::prelude::MagicTypeId) -> ::core::result::Result<Self, Self::Error> {
+                ::mti::__private::ensure_prefix(id, Self::PREFIX).map(|id| #from_id)
+            }
+        }
+    }
+}
@@ -0,0 +1,75 @@
+//! Benchmarks comparing `CompactTypeId` with `MagicTypeId`.
+//!
+//! Run with `cargo bench -p mti --bench compact_type_id`.
+#![allow(missing_docs, unused_results)]
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::hint::black_box;
+use std::str::FromStr;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use mti::prelude::*;
+
+const INPUT: &str = "user_01h455vb4pex5vsknk084sn02q";
+const OTHER: &str = "user_01h455vb4pex5vsknk084sn02r";
+
+fn hash_of<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn bench_parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse");
+    group.bench_function("MagicTypeId", |b| {
+        b.iter(|| MagicTypeId::from_str(black_box(INPUT)));
+    });
+    group.bench_function("CompactTypeId", |b| {
+        b.iter(|| CompactTypeId::from_str(black_box(INPUT)));
+    });
+    group.finish();
+}
+
+fn bench_clone(c: &mut Criterion) {
+    let magic = MagicTypeId::from_str(INPUT).unwrap();
+    let compact = CompactTypeId::from_str(INPUT).unwrap();
+
+    let mut group = c.benchmark_group("clone");
+    group.bench_function("MagicTypeId", |b| b.iter(|| black_box(&magic).clone()));
+    group.bench_function("CompactTypeId", |b| b.iter(|| *black_box(&compact)));
+    group.finish();
+}
+
+fn bench_hash(c: &mut Criterion) {
+    let magic = MagicTypeId::from_str(INPUT).unwrap();
+    let compact = CompactTypeId::from_str(INPUT).unwrap();
+
+    let mut group = c.benchmark_group("hash");
+    group.bench_function("MagicTypeId", |b| b.iter(|| hash_of(black_box(&magic))));
+    group.bench_function("CompactTypeId", |b| b.iter(|| hash_of(black_box(&compact))));
+    group.finish();
+}
+
+fn bench_compare(c: &mut Criterion) {
+    let magic = (
+        MagicTypeId::from_str(INPUT).unwrap(),
+        MagicTypeId::from_str(OTHER).unwrap(),
+    );
+    let compact = (
+        CompactTypeId::from_str(INPUT).unwrap(),
+        CompactTypeId::from_str(OTHER).unwrap(),
+    );
+
+    let mut group = c.benchmark_group("compare");
+    group.bench_function("MagicTypeId", |b| {
+        b.iter(|| black_box(&magic.0).cmp(black_box(&magic.1)));
+    });
+    group.bench_function("CompactTypeId", |b| {
+        b.iter(|| black_box(&compact.0).cmp(black_box(&compact.1)));
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_parse, bench_clone, bench_hash, bench_compare);
+criterion_main!(benches);
@@ -0,0 +1,43 @@
+//! Benchmarks comparing parsing through a `PrefixRegistry` with plain parsing.
+//!
+//! Run with `cargo bench -p mti --bench registry`.
+#![allow(missing_docs, unused_results)]
+
+use std::hint::black_box;
+use std::str::FromStr;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use mti::prelude::*;
+
+const INPUT: &str = "user_01h455vb4pex5vsknk084sn02q";
+
+fn registry() -> PrefixRegistry {
+    let mut registry = PrefixRegistry::new();
+    registry
+        .register(EntityKind::new("user", "User").with_versions(&[SuffixVersion::V7]))
+        .unwrap();
+    // Pad the registry to a realistic number of entity kinds
+    for first in 'a'..='f' {
+        for second in 'a'..='j' {
+            let prefix: &'static str = format!("{first}{second}").leak();
+            registry.register(EntityKind::new(prefix, prefix)).unwrap();
+        }
+    }
+    registry
+}
+
+fn bench_parse(c: &mut Criterion) {
+    let registry = registry();
+
+    let mut group = c.benchmark_group("registry_parse");
+    group.bench_function("MagicTypeId", |b| {
+        b.iter(|| MagicTypeId::from_str(black_box(INPUT)));
+    });
+    group.bench_function("PrefixRegistry", |b| {
+        b.iter(|| registry.parse(black_box(INPUT)).map(RegisteredId::into_id));
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_parse);
+criterion_main!(benches);
@@ -0,0 +1,529 @@
+//! Axum extractors that parse identifiers from path and query parameters.
+//!
+//! Taking a `Path<String>` and parsing it in the handler means every handler maps parse errors
+//! to responses by hand. This module provides [`IdPath`] and [`IdQuery`], which parse the
+//! parameters into any type implementing [`FromIdParams`] and reject the request with an
+//! [`IdRejection`] if a parameter is missing or invalid.
+//!
+//! [`FromIdParams`] is implemented for `MagicTypeId`, [`TypedId`] and derived identifiers, which
+//! take the only parameter, and for tuples of any identifier type whose `FromStr` error is
+//! [`MagicTypeIdError`], which take the parameters in order. Structs with named parameters
+//! implement it with [`IdParams::get`].
+//!
+//! An `IdRejection` for an invalid identifier is a `400 Bad Request` with a JSON body naming the
+//! parameter and the rule that failed:
+//!
+//! ```json
+//! {
+//!   "error": "invalid_id",
+//!   "location": "path",
+//!   "field": "order_id",
+//!   "rule": "prefix_mismatch",
+//!   "component": "prefix",
+//!   "position": 0,
+//!   "message": "Prefix error: expected prefix 'order', found 'user'"
+//! }
+//! ```
+
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+use std::str::FromStr;
+
+use ::axum::async_trait;
+use ::axum::extract::rejection::{QueryRejection, RawPathParamsRejection};
+use ::axum::extract::{FromRequestParts, Query, RawPathParams};
+use ::axum::http::header::CONTENT_TYPE;
+use ::axum::http::request::Parts;
+use ::axum::http::StatusCode;
+use ::axum::response::{IntoResponse, Response};
+use typeid_prefix::ValidationError;
+use typeid_suffix::prelude::*;
+
+use crate::errors::MagicTypeIdError;
+use crate::magic_type_id::MagicTypeId;
+use crate::typed_id::{PrefixMarker, TypedId};
+
+/// Extracts identifiers from the path parameters of the matched route.
+///
+/// `T` is usually a single identifier, for a route with one parameter, or a tuple of
+/// identifiers, for a route with several. See [`FromIdParams`].
+///
+/// # Examples
+///
+/// ```
+/// use axum::{routing::get, Router};
+/// use mti::axum::IdPath;
+/// use mti::prelude::*;
+///
+/// struct User;
+/// impl PrefixMarker for User {
+///     const PREFIX: &'static str = "user";
+/// }
+///
+/// struct Order;
+/// impl PrefixMarker for Order {
+///     const PREFIX: &'static str = "order";
+/// }
+///
+/// async fn show_user(IdPath(user_id): IdPath<TypedId<User>>) -> String {
+///     user_id.to_string()
+/// }
+///
+/// async fn show_order(
+///     IdPath((user_id, order_id)): IdPath<(TypedId<User>, TypedId<Order>)>,
+/// ) -> String {
+///     format!("{order_id} of {user_id}")
+/// }
+///
+/// let app: Router = Router::new()
+///     .route("/users/:user_id", get(show_user))
+///     .route("/users/:user_id/orders/:order_id", get(show_order));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IdPath<T>(pub T);
+
+/// Extracts identifiers from the query string.
+///
+/// `T` is usually a struct implementing [`FromIdParams`] with [`IdParams::get`], which looks
+/// parameters up by name. Parameters that `T` does not ask for are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use axum::{routing::get, Router};
+/// use mti::axum::{FromIdParams, IdParams, IdQuery, IdRejection};
+/// use mti::prelude::*;
+///
+/// struct User;
+/// impl PrefixMarker for User {
+///     const PREFIX: &'static str = "user";
+/// }
+///
+/// struct Filter {
+///     owner: TypedId<User>,
+///     after: Option<MagicTypeId>,
+/// }
+///
+/// impl FromIdParams for Filter {
+///     fn from_id_params(params: &IdParams<'_>) -> Result<Self, IdRejection> {
+///         Ok(Self {
+///             owner: params.get("owner")?,
+///             after: params.get_optional("after")?,
+///         })
+///     }
+/// }
+///
+/// async fn list_orders(IdQuery(filter): IdQuery<Filter>) -> String {
+///     filter.owner.to_string()
+/// }
+///
+/// let app: Router = Router::new().route("/orders", get(list_orders));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IdQuery<T>(pub T);
+
+#[async_trait]
+impl<S, T> FromRequestParts<S> for IdPath<T>
+where
+    S: Send + Sync,
+    T: FromIdParams,
+{
+    type Rejection = IdRejection;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let raw = RawPathParams::from_request_parts(parts, state)
+            .await
+            .map_err(|rejection| IdRejection::new(Location::Path, Kind::Path(rejection)))?;
+        let pairs: Vec<(&str, &str)> = raw.iter().collect();
+        T::from_id_params(&IdParams::new(Location::Path, &pairs)).map(Self)
+    }
+}
+
+#[async_trait]
+impl<S, T> FromRequestParts<S> for IdQuery<T>
+where
+    S: Send + Sync,
+    T: FromIdParams,
+{
+    type Rejection = IdRejection;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let Query(raw) = Query::<Vec<(String, String)>>::try_from_uri(&parts.uri)
+            .map_err(|rejection| IdRejection::new(Location::Query, Kind::Query(rejection)))?;
+        let pairs: Vec<(&str, &str)> = raw
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+            .collect();
+        T::from_id_params(&IdParams::new(Location::Query, &pairs)).map(Self)
+    }
+}
+
+impl<T> Deref for IdPath<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for IdPath<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T> Deref for IdQuery<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for IdQuery<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+/// A type that [`IdPath`] and [`IdQuery`] can build from request parameters.
+///
+/// This trait is implemented for `MagicTypeId`, `TypedId`, identifiers derived with
+/// `#[derive(MagicId)]` and enums declared with [`id_enum!`](crate::id_enum), which require
+/// exactly one parameter. It is also implemented for tuples of up to six identifier types, which
+/// require exactly as many parameters and parse them in order. Implement it for a struct to look
+/// parameters up by name.
+pub trait FromIdParams: Sized {
+    /// Builds `Self` from the parameters of a request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IdRejection`] if a parameter is missing or is not a valid identifier.
+    fn from_id_params(params: &IdParams<'_>) -> Result<Self, IdRejection>;
+}
+
+impl FromIdParams for MagicTypeId {
+    fn from_id_params(params: &IdParams<'_>) -> Result<Self, IdRejection> {
+        params.expect_count(1)?;
+        params.parse_at(0)
+    }
+}
+
+impl<P: PrefixMarker> FromIdParams for TypedId<P> {
+    fn from_id_params(params: &IdParams<'_>) -> Result<Self, IdRejection> {
+        params.expect_count(1)?;
+        params.parse_at(0)
+    }
+}
+
+/// Implements `FromIdParams` for a tuple of identifiers, parsing one parameter per element.
+macro_rules! impl_from_id_params_for_tuple {
+    ($count:literal; $($ty:ident $index:tt),+) => {
+        impl<$($ty),+> FromIdParams for ($($ty,)+)
+        where
+            $($ty: FromStr<Err = MagicTypeIdError>,)+
+        {
+            fn from_id_params(params: &IdParams<'_>) -> Result<Self, IdRejection> {
+                params.expect_count($count)?;
+                Ok(($(params.parse_at::<$ty>($index)?,)+))
+            }
+        }
+    };
+}
+
+impl_from_id_params_for_tuple!(1; A 0);
+impl_from_id_params_for_tuple!(2; A 0, B 1);
+impl_from_id_params_for_tuple!(3; A 0, B 1, C 2);
+impl_from_id_params_for_tuple!(4; A 0, B 1, C 2, D 3);
+impl_from_id_params_for_tuple!(5; A 0, B 1, C 2, D 3, E 4);
+impl_from_id_params_for_tuple!(6; A 0, B 1, C 2, D 3, E 4, F 5);
+
+/// The parameters of a request, as seen by [`FromIdParams`].
+#[derive(Debug, Clone, Copy)]
+pub struct IdParams<'a> {
+    location: Location,
+    pairs: &'a [(&'a str, &'a str)],
+}
+
+impl<'a> IdParams<'a> {
+    const fn new(location: Location, pairs: &'a [(&'a str, &'a str)]) -> Self {
+        Self { location, pairs }
+    }
+
+    /// Parses the parameter called `name`.
+    ///
+    /// If a query string repeats the parameter, its first value is used.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IdRejection`] if there is no such parameter, or if its value is not a valid
+    /// `T`.
+    pub fn get<T>(&self, name: &str) -> Result<T, IdRejection>
+    where
+        T: FromStr<Err = MagicTypeIdError>,
+    {
+        self.get_optional(name)?
+            .ok_or_else(|| IdRejection::new(self.location, Kind::Missing(name.to_string())))
+    }
+
+    /// Parses the parameter called `name`, if there is one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IdRejection`] if the parameter's value is not a valid `T`.
+    pub fn get_optional<T>(&self, name: &str) -> Result<Option<T>, IdRejection>
+    where
+        T: FromStr<Err = MagicTypeIdError>,
+    {
+        self.pairs
+            .iter()
+            .find(|(key, _)| *key == name)
+            .map(|&(key, value)| self.parse(key, value))
+            .transpose()
+    }
+
+    /// Returns the number of parameters.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.pairs.len()
+    }
+
+    /// Returns `true` if there are no parameters.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.pairs.is_empty()
+    }
+
+    /// Fails unless there are exactly `expected` parameters.
+    const fn expect_count(&self, expected: usize) -> Result<(), IdRejection> {
+        if self.pairs.len() == expected {
+            Ok(())
+        } else {
+            Err(IdRejection::new(
+                self.location,
+                Kind::WrongCount {
+                    expected,
+                    got: self.pairs.len(),
+                },
+            ))
+        }
+    }
+
+    /// Parses the parameter at `index`, which must exist.
+    fn parse_at<T>(&self, index: usize) -> Result<T, IdRejection>
+    where
+        T: FromStr<Err = MagicTypeIdError>,
+    {
+        let (key, value) = self.pairs[index];
+        self.parse(key, value)
+    }
+
+    fn parse<T>(&self, key: &str, value: &str) -> Result<T, IdRejection>
+    where
+        T: FromStr<Err = MagicTypeIdError>,
+    {
+        T::from_str(value).map_err(|error| {
+            IdRejection::new(
+                self.location,
+                Kind::Invalid {
+                    field: key.to_string(),
+                    value: value.to_string(),
+                    error,
+                },
+            )
+        })
+    }
+}
+
+/// The part of the request that a parameter comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Location {
+    Path,
+    Query,
+}
+
+impl Location {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Path => "path",
+            Self::Query => "query",
+        }
+    }
+}
+
+/// The rejection of [`IdPath`] and [`IdQuery`].
+///
+/// A missing or invalid identifier becomes a `400 Bad Request` with a JSON body. Its `error` is
+/// `"missing_id"` or `"invalid_id"`, `location` is `"path"` or `"query"`, and `field` names the
+/// parameter. For an invalid identifier, `rule` names the rule that failed (see
+/// [`rule`](IdRejection::rule)), `component` is `"prefix"` or `"suffix"`, `position` is the byte
+/// offset of the problem in the value, and `message` is the [`MagicTypeIdError`] message.
+///
+/// A route whose parameters do not match the extracted type is a programming error and becomes
+/// a `500 Internal Server Error`, like the rejections of axum's own `Path`. Rejections of the
+/// underlying path and query parsing are passed through.
+#[derive(Debug)]
+pub struct IdRejection {
+    location: Location,
+    kind: Kind,
+}
+
+#[derive(Debug)]
+enum Kind {
+    Invalid {
+        field: String,
+        value: String,
+        error: MagicTypeIdError,
+    },
+    Missing(String),
+    WrongCount {
+        expected: usize,
+        got: usize,
+    },
+    Path(RawPathParamsRejection),
+    Query(QueryRejection),
+}
+
+impl IdRejection {
+    const fn new(location: Location, kind: Kind) -> Self {
+        Self { location, kind }
+    }
+
+    /// Returns the name of the parameter that was missing or invalid, if any.
+    #[must_use]
+    pub fn field(&self) -> Option<&str> {
+        match &self.kind {
+            Kind::Invalid { field, .. } | Kind::Missing(field) => Some(field),
+            Kind::WrongCount { .. } | Kind::Path(_) | Kind::Query(_) => None,
+        }
+    }
+
+    /// Returns the error of an invalid identifier.
+    #[must_use]
+    pub const fn error(&self) -> Option<&MagicTypeIdError> {
+        match &self.kind {
+            Kind::Invalid { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+
+    /// Returns a stable, `snake_case` name for the rule that an invalid identifier broke, such
+    /// as `"prefix_mismatch"` or `"suffix_invalid_character"`.
+    #[must_use]
+    pub const fn rule(&self) -> Option<&'static str> {
+        match &self.kind {
+            Kind::Invalid { error, .. } => Some(rule(error)),
+            _ => None,
+        }
+    }
+
+    /// Returns the status code of the response.
+    #[must_use]
+    pub fn status(&self) -> StatusCode {
+        match &self.kind {
+            Kind::Invalid { .. } | Kind::Missing(_) => StatusCode::BAD_REQUEST,
+            Kind::WrongCount { .. } => StatusCode::INTERNAL_SERVER_ERROR,
+            Kind::Path(rejection) => rejection.status(),
+            Kind::Query(rejection) => rejection.status(),
+        }
+    }
+}
+
+impl fmt::Display for IdRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let location = self.location.as_str();
+        match &self.kind {
+            Kind::Invalid { field, error, .. } => {
+                write!(f, "Invalid {location} parameter '{field}': {error}")
+            }
+            Kind::Missing(field) => write!(f, "Missing {location} parameter '{field}'"),
+            Kind::WrongCount { expected, got } => write!(
+                f,
+                "Expected {expected} {location} parameter(s), but the route has {got}"
+            ),
+            Kind::Path(rejection) => fmt::Display::fmt(rejection, f),
+            Kind::Query(rejection) => fmt::Display::fmt(rejection, f),
+        }
+    }
+}
+
+impl std::error::Error for IdRejection {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.kind {
+            Kind::Invalid { error, .. } => Some(error),
+            Kind::Path(rejection) => Some(rejection),
+            Kind::Query(rejection) => Some(rejection),
+            Kind::Missing(_) | Kind::WrongCount { .. } => None,
+        }
+    }
+}
+
+impl IntoResponse for IdRejection {
+    fn into_response(self) -> Response {
+        let location = self.location.as_str();
+        let body = match self.kind {
+            Kind::Invalid {
+                field,
+                value,
+                error,
+            } => serde_json::json!({
+                "error": "invalid_id",
+                "location": location,
+                "field": field,
+                "rule": rule(&error),
+                "component": error.component().to_string(),
+                "position": error.clone().with_input(value).position(),
+                "message": error.to_string(),
+            }),
+            Kind::Missing(field) => serde_json::json!({
+                "error": "missing_id",
+                "location": location,
+                "field": field,
+                "message": format!("Missing {location} parameter '{field}'"),
+            }),
+            Kind::WrongCount { .. } => {
+                return (StatusCode::INTERNAL_SERVER_ERROR, self.to_string()).into_response()
+            }
+            Kind::Path(rejection) => return rejection.into_response(),
+            Kind::Query(rejection) => return rejection.into_response(),
+        };
+        (
+            StatusCode::BAD_REQUEST,
+            [(CONTENT_TYPE, "application/json")],
+            body.to_string(),
+        )
+            .into_response()
+    }
+}
+
+/// Returns the name of the rule that `error` reports as broken.
+const fn rule(error: &MagicTypeIdError) -> &'static str {
+    match error {
+        MagicTypeIdError::Prefix(err) => match err {
+            ValidationError::ExceedsMaxLength => "prefix_too_long",
+            ValidationError::ContainsInvalidCharacters { .. } => "prefix_invalid_character",
+            ValidationError::StartsWithUnderscore | ValidationError::InvalidStartCharacter => {
+                "prefix_invalid_start"
+            }
+            ValidationError::EndsWithUnderscore | ValidationError::InvalidEndCharacter => {
+                "prefix_invalid_end"
+            }
+            ValidationError::IsEmpty => "prefix_empty",
+        },
+        MagicTypeIdError::Suffix(DecodeError::InvalidSuffix(reason)) => match reason {
+            InvalidSuffixReason::InvalidLength => "suffix_invalid_length",
+            InvalidSuffixReason::NonAsciiCharacter { .. }
+            | InvalidSuffixReason::InvalidCharacter { .. } => "suffix_invalid_character",
+            InvalidSuffixReason::InvalidFirstCharacter => "suffix_overflow",
+        },
+        MagicTypeIdError::Suffix(_) => "suffix_invalid_uuid",
+        MagicTypeIdError::PrefixMismatch { .. } => "prefix_mismatch",
+        MagicTypeIdError::PrefixNotAllowed { .. } => "prefix_not_allowed",
+        MagicTypeIdError::UnknownPrefix { .. } => "unknown_prefix",
+        MagicTypeIdError::EmptyPrefixNotAllowed => "prefix_required",
+        MagicTypeIdError::VersionNotAllowed { .. } => "version_not_allowed",
+        MagicTypeIdError::VariantNotAllowed => "variant_not_allowed",
+        MagicTypeIdError::NilNotAllowed => "nil_not_allowed",
+        MagicTypeIdError::MaxNotAllowed => "max_not_allowed",
+    }
+}
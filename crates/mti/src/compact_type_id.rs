@@ -0,0 +1,297 @@
+//! Allocation-free, fixed-size identifiers.
+//!
+//! This module provides [`CompactTypeId`], a `Copy` alternative to [`MagicTypeId`] that stores
+//! the prefix inline and the suffix as a `u128`. It parses and formats without touching the heap,
+//! which makes it a good fit for hot maps, queues and other places where identifiers are copied
+//! often.
+
+use std::cmp::Ordering;
+use std::fmt::{self, Debug, Display, Formatter, Write};
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+use typeid_prefix::prelude::*;
+use typeid_suffix::prelude::*;
+
+use crate::errors::MagicTypeIdError;
+use crate::magic_type_id::MagicTypeId;
+use crate::magic_type_id_ref::MagicTypeIdRef;
+
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+#[cfg(feature = "serde")]
+use crate::serde_support;
+
+#[cfg(feature = "instrument")]
+use tracing::instrument;
+
+/// The maximum length of a `TypeID` prefix, as enforced by `TypeIdPrefix`.
+const MAX_PREFIX_LEN: usize = 63;
+
+/// A fixed-size, `Copy` representation of a `MagicTypeId`.
+///
+/// `CompactTypeId` holds the prefix in an inline 63-byte buffer and the suffix as the `u128` value
+/// of its UUID, so it never allocates: parsing validates the input in place, formatting encodes the
+/// suffix on the stack, and copying is a plain memory copy.
+///
+/// It parses, formats and orders exactly like [`MagicTypeId`], and converts losslessly to and from
+/// it. Use `MagicTypeId` when you need a borrowed `&str` of the whole identifier, and
+/// `CompactTypeId` when identifiers are stored and copied in bulk.
+///
+/// # Examples
+///
+/// ```
+/// use std::str::FromStr;
+/// use mti::prelude::*;
+///
+/// let id = CompactTypeId::from_str("user_01h455vb4pex5vsknk084sn02q").unwrap();
+/// let copy = id; // `CompactTypeId` is `Copy`
+///
+/// assert_eq!(id.prefix(), "user");
+/// assert_eq!(copy.to_string(), "user_01h455vb4pex5vsknk084sn02q");
+///
+/// // Lossless conversions to and from `MagicTypeId`
+/// let magic: MagicTypeId = id.into();
+/// assert_eq!(CompactTypeId::from(&magic), id);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct CompactTypeId {
+    suffix: u128,
+    prefix_len: u8,
+    prefix: [u8; MAX_PREFIX_LEN],
+}
+
+impl CompactTypeId {
+    /// Creates a new `CompactTypeId` from a prefix and a suffix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use mti::prelude::*;
+    ///
+    /// let prefix = TypeIdPrefix::from_str("user").unwrap();
+    /// let suffix = TypeIdSuffix::new::<V7>();
+    /// let id = CompactTypeId::new(&prefix, &suffix);
+    ///
+    /// assert_eq!(id.prefix(), "user");
+    /// assert_eq!(id.suffix(), suffix);
+    /// ```
+    #[must_use]
+    pub fn new(prefix: &TypeIdPrefix, suffix: &TypeIdSuffix) -> Self {
+        Self::from_parts(prefix.as_str(), suffix.to_uuid())
+    }
+
+    /// Creates a new `CompactTypeId` from a prefix and a UUID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use mti::prelude::*;
+    ///
+    /// let prefix = TypeIdPrefix::from_str("user").unwrap();
+    /// let id = CompactTypeId::from_uuid(&prefix, Uuid::nil());
+    ///
+    /// assert_eq!(id.to_string(), "user_00000000000000000000000000");
+    /// ```
+    #[must_use]
+    pub fn from_uuid(prefix: &TypeIdPrefix, uuid: Uuid) -> Self {
+        Self::from_parts(prefix.as_str(), uuid)
+    }
+
+    /// Builds a `CompactTypeId` from a prefix that is known to be valid or empty.
+    fn from_parts(prefix: &str, uuid: Uuid) -> Self {
+        let mut buf = [0; MAX_PREFIX_LEN];
+        let len = prefix.len().min(MAX_PREFIX_LEN);
+        buf[..len].copy_from_slice(&prefix.as_bytes()[..len]);
+        Self {
+            suffix: uuid.as_u128(),
+            #[allow(clippy::cast_possible_truncation)]
+            prefix_len: len as u8,
+            prefix: buf,
+        }
+    }
+
+    /// Returns the prefix, or an empty string if the identifier has no prefix.
+    #[must_use]
+    pub fn prefix(&self) -> &str {
+        // The buffer only ever holds a validated, ASCII-only prefix.
+        std::str::from_utf8(&self.prefix[..usize::from(self.prefix_len)]).unwrap_or_default()
+    }
+
+    /// Returns the suffix.
+    #[must_use]
+    pub fn suffix(&self) -> TypeIdSuffix {
+        TypeIdSuffix::from(self.uuid())
+    }
+
+    /// Returns the UUID encoded by the suffix.
+    #[must_use]
+    pub const fn uuid(&self) -> Uuid {
+        Uuid::from_u128(self.suffix)
+    }
+
+    /// Converts this identifier into a `MagicTypeId`.
+    ///
+    /// This allocates the prefix and string representation of the `MagicTypeId`.
+    #[must_use]
+    pub fn to_magic_type_id(&self) -> MagicTypeId {
+        let prefix = if self.prefix_len == 0 {
+            TypeIdPrefix::default()
+        } else {
+            // The stored prefix was validated when this identifier was created.
+            TypeIdPrefix::from_str(self.prefix()).unwrap_or_default()
+        };
+        MagicTypeId::new(prefix, self.suffix())
+    }
+}
+
+impl FromStr for CompactTypeId {
+    type Err = MagicTypeIdError;
+
+    /// Parses a string into a `CompactTypeId` without allocating.
+    ///
+    /// The string must follow the same format as for `MagicTypeId`: "`prefix_suffix`", or just
+    /// "suffix" if there is no prefix.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `MagicTypeIdError` if the prefix or the suffix is invalid, exactly as parsing a
+    /// `MagicTypeId` would.
+    #[cfg_attr(feature = "instrument", instrument(level = "debug", fields(input = %s)))]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let id = MagicTypeIdRef::parse(s)?;
+        Ok(Self::from_parts(id.prefix(), id.uuid()))
+    }
+}
+
+impl TryFrom<&str> for CompactTypeId {
+    type Error = MagicTypeIdError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::from_str(value)
+    }
+}
+
+impl Display for CompactTypeId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.prefix_len > 0 {
+            f.write_str(self.prefix())?;
+            f.write_char('_')?;
+        }
+        Display::fmt(&self.suffix(), f)
+    }
+}
+
+impl Debug for CompactTypeId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CompactTypeId")
+            .field("prefix", &self.prefix())
+            .field("suffix", &self.suffix())
+            .finish()
+    }
+}
+
+impl Ord for CompactTypeId {
+    /// Orders by suffix, then by prefix, exactly like `MagicTypeId`.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.suffix
+            .cmp(&other.suffix)
+            .then_with(|| self.prefix().cmp(other.prefix()))
+    }
+}
+
+impl PartialOrd for CompactTypeId {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Hash for CompactTypeId {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.suffix.hash(state);
+        self.prefix().hash(state);
+    }
+}
+
+impl From<&MagicTypeId> for CompactTypeId {
+    fn from(value: &MagicTypeId) -> Self {
+        Self::new(value.prefix(), value.suffix())
+    }
+}
+
+impl From<MagicTypeId> for CompactTypeId {
+    fn from(value: MagicTypeId) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<CompactTypeId> for MagicTypeId {
+    fn from(value: CompactTypeId) -> Self {
+        value.to_magic_type_id()
+    }
+}
+
+impl PartialEq<MagicTypeId> for CompactTypeId {
+    fn eq(&self, other: &MagicTypeId) -> bool {
+        self.suffix == other.suffix().to_uuid().as_u128()
+            && self.prefix() == other.prefix().as_str()
+    }
+}
+
+impl PartialEq<CompactTypeId> for MagicTypeId {
+    fn eq(&self, other: &CompactTypeId) -> bool {
+        other == self
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for CompactTypeId {
+    /// Serializes the identifier like a [`MagicTypeId`]: as a string in human-readable formats,
+    /// and as its prefix followed by the 16 bytes of its UUID in binary formats.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            serde_support::serialize_compact(serializer, self.prefix(), self.uuid())
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for CompactTypeId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct CompactTypeIdVisitor;
+
+        impl de::Visitor<'_> for CompactTypeIdVisitor {
+            type Value = CompactTypeId;
+
+            fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                f.write_str("a TypeID string")
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                CompactTypeId::from_str(v).map_err(E::custom)
+            }
+        }
+
+        if !deserializer.is_human_readable() {
+            return serde_support::deserialize_compact(deserializer, |prefix, uuid| {
+                Ok(Self::from_parts(prefix, uuid))
+            });
+        }
+        // Parse from a borrowed string when the format allows it, avoiding an intermediate String
+        deserializer.deserialize_str(CompactTypeIdVisitor)
+    }
+}
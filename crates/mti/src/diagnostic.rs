@@ -0,0 +1,325 @@
+//! Position-aware reports for parse errors.
+//!
+//! The errors of the prefix and suffix crates describe where a problem is relative to their own
+//! component. This module provides [`ParseDiagnostic`], which pairs a [`MagicTypeIdError`] with
+//! the input that caused it, locates the problem in the full identifier, and renders the input
+//! with a caret under it. With the `miette` feature, `ParseDiagnostic` also implements
+//! `miette::Diagnostic`.
+
+use std::fmt;
+use std::ops::Range;
+
+use typeid_prefix::ValidationError;
+use typeid_suffix::prelude::*;
+
+use crate::errors::MagicTypeIdError;
+
+/// The part of an identifier that an error refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IdComponent {
+    /// The type prefix, before the last underscore.
+    Prefix,
+    /// The base32-encoded UUID, after the last underscore.
+    Suffix,
+}
+
+impl fmt::Display for IdComponent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Prefix => write!(f, "prefix"),
+            Self::Suffix => write!(f, "suffix"),
+        }
+    }
+}
+
+impl MagicTypeIdError {
+    /// Returns the component of the identifier that this error refers to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use mti::prelude::*;
+    ///
+    /// let error = MagicTypeId::from_str("user_01h455vb4pex5vsknk084sn0uq").unwrap_err();
+    /// assert_eq!(error.component(), IdComponent::Suffix);
+    /// ```
+    #[must_use]
+    pub const fn component(&self) -> IdComponent {
+        match self {
+            Self::Prefix(_)
+            | Self::PrefixMismatch { .. }
+            | Self::PrefixNotAllowed { .. }
+            | Self::UnknownPrefix { .. }
+            | Self::EmptyPrefixNotAllowed => IdComponent::Prefix,
+            Self::Suffix(_)
+            | Self::VersionNotAllowed { .. }
+            | Self::VariantNotAllowed
+            | Self::NilNotAllowed
+            | Self::MaxNotAllowed => IdComponent::Suffix,
+        }
+    }
+
+    /// Pairs this error with the input that caused it, to locate and render the problem.
+    ///
+    /// `input` must be the string whose parsing returned this error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use mti::prelude::*;
+    ///
+    /// let input = "user_01h455vb4pex5vsknk084sn0uq";
+    /// let diagnostic = MagicTypeId::from_str(input).unwrap_err().with_input(input);
+    /// assert_eq!(diagnostic.span(), 29..30);
+    /// ```
+    #[must_use]
+    pub fn with_input(self, input: impl Into<String>) -> ParseDiagnostic {
+        ParseDiagnostic::new(input.into(), self)
+    }
+}
+
+/// A [`MagicTypeIdError`] together with the input that caused it.
+///
+/// The diagnostic locates the problem as a byte range of the whole input, so that positions
+/// reported relative to the prefix or suffix can be shown in context. Errors about a single
+/// character point at that character, and errors about a whole component cover all of it.
+///
+/// # Examples
+///
+/// ```
+/// use std::str::FromStr;
+/// use mti::prelude::*;
+///
+/// let input = "user_01h455vb4pex5vsknk084sn0uq";
+/// let diagnostic = MagicTypeId::from_str(input).unwrap_err().with_input(input);
+///
+/// assert_eq!(diagnostic.component(), IdComponent::Suffix);
+/// assert_eq!(diagnostic.position(), 29);
+/// assert_eq!(
+///     diagnostic.render(),
+///     "Suffix error: Invalid `TypeID` suffix: Suffix contains character 'u' at position 24, \
+///      which is not in the base32 alphabet\n\
+///      \x20 user_01h455vb4pex5vsknk084sn0uq\n\
+///      \x20                              ^ not in the base32 alphabet"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDiagnostic {
+    input: String,
+    error: MagicTypeIdError,
+    span: Range<usize>,
+}
+
+impl ParseDiagnostic {
+    /// Locates `error` in `input`.
+    fn new(input: String, error: MagicTypeIdError) -> Self {
+        let span = locate(&input, &error);
+        Self { input, error, span }
+    }
+
+    /// Returns the input that caused the error.
+    #[must_use]
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// Returns the error.
+    #[must_use]
+    pub const fn error(&self) -> &MagicTypeIdError {
+        &self.error
+    }
+
+    /// Returns the error, discarding the input.
+    #[must_use]
+    pub fn into_error(self) -> MagicTypeIdError {
+        self.error
+    }
+
+    /// Returns the component of the identifier that the error refers to.
+    #[must_use]
+    pub const fn component(&self) -> IdComponent {
+        self.error.component()
+    }
+
+    /// Returns the byte range of the input that the error refers to.
+    ///
+    /// The range is empty when the problem is a missing part, such as an empty prefix.
+    #[must_use]
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+
+    /// Returns the byte offset in the input at which the problem starts.
+    #[must_use]
+    pub const fn position(&self) -> usize {
+        self.span.start
+    }
+
+    /// Returns a short description of the problem at [`span`](Self::span).
+    #[must_use]
+    pub const fn label(&self) -> &'static str {
+        match &self.error {
+            MagicTypeIdError::Prefix(err) => match err {
+                ValidationError::ExceedsMaxLength => "exceeds 63 characters",
+                ValidationError::ContainsInvalidCharacters { .. } => {
+                    "not a lowercase ASCII letter or underscore"
+                }
+                ValidationError::StartsWithUnderscore | ValidationError::InvalidStartCharacter => {
+                    "must start with a lowercase letter"
+                }
+                ValidationError::EndsWithUnderscore | ValidationError::InvalidEndCharacter => {
+                    "must end with a lowercase letter"
+                }
+                ValidationError::IsEmpty => "empty prefix",
+            },
+            MagicTypeIdError::Suffix(DecodeError::InvalidSuffix(reason)) => match reason {
+                InvalidSuffixReason::InvalidLength => "must be 26 characters long",
+                InvalidSuffixReason::NonAsciiCharacter { .. } => "not an ASCII character",
+                InvalidSuffixReason::InvalidFirstCharacter => "must be '7' or less",
+                InvalidSuffixReason::InvalidCharacter { .. } => "not in the base32 alphabet",
+            },
+            MagicTypeIdError::Suffix(_) => "invalid UUID",
+            MagicTypeIdError::PrefixMismatch { .. } | MagicTypeIdError::PrefixNotAllowed { .. } => {
+                "prefix not allowed"
+            }
+            MagicTypeIdError::UnknownPrefix { .. } => "unknown prefix",
+            MagicTypeIdError::EmptyPrefixNotAllowed => "missing prefix",
+            MagicTypeIdError::VersionNotAllowed { .. } => "UUID version not allowed",
+            MagicTypeIdError::VariantNotAllowed => "UUID variant not allowed",
+            MagicTypeIdError::NilNotAllowed => "Nil UUID not allowed",
+            MagicTypeIdError::MaxNotAllowed => "Max UUID not allowed",
+        }
+    }
+
+    /// Renders the error message, followed by the input with a caret under the problem.
+    ///
+    /// Columns are counted in characters, so the carets line up for any input whose characters
+    /// are all displayed one column wide.
+    #[must_use]
+    pub fn render(&self) -> String {
+        let column = self
+            .input
+            .get(..self.span.start)
+            .map_or(self.span.start, |before| before.chars().count());
+        let width = self
+            .input
+            .get(self.span.clone())
+            .map_or(0, |problem| problem.chars().count())
+            .max(1);
+        format!(
+            "{}\n  {}\n  {}{} {}",
+            self.error,
+            self.input,
+            " ".repeat(column),
+            "^".repeat(width),
+            self.label()
+        )
+    }
+}
+
+impl fmt::Display for ParseDiagnostic {
+    /// Formats the error message, like the wrapped [`MagicTypeIdError`].
+    ///
+    /// Use [`render`](ParseDiagnostic::render) to include the input and the caret.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.error, f)
+    }
+}
+
+impl std::error::Error for ParseDiagnostic {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl From<ParseDiagnostic> for MagicTypeIdError {
+    fn from(diagnostic: ParseDiagnostic) -> Self {
+        diagnostic.error
+    }
+}
+
+/// Finds the byte range of `input` that `error` refers to, splitting the input into prefix and
+/// suffix the same way parsing does.
+fn locate(input: &str, error: &MagicTypeIdError) -> Range<usize> {
+    let (prefix, suffix_start) = input
+        .rsplit_once('_')
+        .map_or(("", 0), |(prefix, _)| (prefix, prefix.len() + 1));
+    let suffix = &input[suffix_start..];
+
+    let span = match error {
+        MagicTypeIdError::Prefix(err) => match err {
+            ValidationError::ExceedsMaxLength => 63..prefix.len(),
+            ValidationError::ContainsInvalidCharacters {
+                position,
+                character,
+            } => *position..position + character.len_utf8(),
+            ValidationError::StartsWithUnderscore | ValidationError::InvalidStartCharacter => {
+                first_char(prefix, 0)
+            }
+            ValidationError::EndsWithUnderscore | ValidationError::InvalidEndCharacter => {
+                last_char(prefix, 0)
+            }
+            ValidationError::IsEmpty => 0..0,
+        },
+        MagicTypeIdError::PrefixMismatch { .. }
+        | MagicTypeIdError::PrefixNotAllowed { .. }
+        | MagicTypeIdError::UnknownPrefix { .. }
+        | MagicTypeIdError::EmptyPrefixNotAllowed => 0..prefix.len(),
+        MagicTypeIdError::Suffix(DecodeError::InvalidSuffix(reason)) => match reason {
+            InvalidSuffixReason::NonAsciiCharacter {
+                position,
+                character,
+            }
+            | InvalidSuffixReason::InvalidCharacter {
+                position,
+                character,
+            } => suffix_start + position..suffix_start + position + character.len_utf8(),
+            InvalidSuffixReason::InvalidFirstCharacter => first_char(suffix, suffix_start),
+            InvalidSuffixReason::InvalidLength => suffix_start..input.len(),
+        },
+        MagicTypeIdError::Suffix(_)
+        | MagicTypeIdError::VersionNotAllowed { .. }
+        | MagicTypeIdError::VariantNotAllowed
+        | MagicTypeIdError::NilNotAllowed
+        | MagicTypeIdError::MaxNotAllowed => suffix_start..input.len(),
+    };
+    // Guard against an input that did not produce the error
+    span.start.min(input.len())..span.end.min(input.len())
+}
+
+/// Returns the range of the first character of `component`, which starts at `offset`.
+fn first_char(component: &str, offset: usize) -> Range<usize> {
+    let len = component.chars().next().map_or(0, char::len_utf8);
+    offset..offset + len
+}
+
+/// Returns the range of the last character of `component`, which starts at `offset`.
+fn last_char(component: &str, offset: usize) -> Range<usize> {
+    let len = component.chars().next_back().map_or(0, char::len_utf8);
+    offset + component.len() - len..offset + component.len()
+}
+
+#[cfg(feature = "miette")]
+impl miette::Diagnostic for MagicTypeIdError {
+    fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        Some(Box::new(format!("mti::{}", self.component())))
+    }
+}
+
+#[cfg(feature = "miette")]
+impl miette::Diagnostic for ParseDiagnostic {
+    fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        miette::Diagnostic::code(&self.error)
+    }
+
+    fn source_code(&self) -> Option<&dyn miette::SourceCode> {
+        Some(&self.input)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = miette::LabeledSpan> + '_>> {
+        let label = miette::LabeledSpan::new_with_span(Some(self.label().to_string()), self.span());
+        Some(Box::new(std::iter::once(label)))
+    }
+}
@@ -28,6 +28,56 @@ pub enum MagicTypeIdError {
     /// These errors occur when there's an issue with the suffix part of a `MagicTypeId`,
     /// such as invalid encoding or an incorrect UUID format.
     Suffix(DecodeError),
+
+    /// The identifier is well-formed but carries a different prefix than required.
+    ///
+    /// This error occurs when parsing into a typed identifier such as
+    /// [`TypedId`](crate::prelude::TypedId), whose prefix is fixed by its marker type.
+    PrefixMismatch {
+        /// The prefix that was required.
+        expected: String,
+        /// The prefix that was actually found.
+        found: String,
+    },
+
+    /// The identifier's prefix is not one of the prefixes allowed by the
+    /// [`ParseOptions`](crate::prelude::ParseOptions) it was parsed with.
+    PrefixNotAllowed {
+        /// The prefixes that were allowed.
+        allowed: Vec<String>,
+        /// The prefix that was actually found.
+        found: String,
+    },
+
+    /// The identifier's prefix is not registered in the
+    /// [`PrefixRegistry`](crate::prelude::PrefixRegistry) it was parsed with.
+    UnknownPrefix {
+        /// The prefix that was actually found.
+        found: String,
+    },
+
+    /// The identifier has no prefix, but the [`ParseOptions`](crate::prelude::ParseOptions) it
+    /// was parsed with require one.
+    EmptyPrefixNotAllowed,
+
+    /// The identifier's UUID version is not one of the versions allowed by the
+    /// [`ParseOptions`](crate::prelude::ParseOptions) it was parsed with.
+    VersionNotAllowed {
+        /// The version that was actually found.
+        found: SuffixVersion,
+    },
+
+    /// The identifier's UUID is not of the RFC 9562 variant, which the
+    /// [`ParseOptions`](crate::prelude::ParseOptions) it was parsed with require.
+    VariantNotAllowed,
+
+    /// The identifier holds the Nil UUID, which the
+    /// [`ParseOptions`](crate::prelude::ParseOptions) it was parsed with reject.
+    NilNotAllowed,
+
+    /// The identifier holds the Max UUID, which the
+    /// [`ParseOptions`](crate::prelude::ParseOptions) it was parsed with reject.
+    MaxNotAllowed,
 }
 
 impl fmt::Display for MagicTypeIdError {
@@ -35,6 +85,28 @@ impl fmt::Display for MagicTypeIdError {
         match self {
             Self::Prefix(err) => write!(f, "Prefix error: {err}"),
             Self::Suffix(err) => write!(f, "Suffix error: {err}"),
+            Self::PrefixMismatch { expected, found } => {
+                write!(
+                    f,
+                    "Prefix error: expected prefix '{expected}', found '{found}'"
+                )
+            }
+            Self::PrefixNotAllowed { allowed, found } => {
+                write!(
+                    f,
+                    "Prefix error: prefix '{found}' is not one of {allowed:?}"
+                )
+            }
+            Self::UnknownPrefix { found } => write!(f, "Prefix error: unknown prefix '{found}'"),
+            Self::EmptyPrefixNotAllowed => write!(f, "Prefix error: a prefix is required"),
+            Self::VersionNotAllowed { found } => {
+                write!(f, "Suffix error: UUID version '{found}' is not allowed")
+            }
+            Self::VariantNotAllowed => {
+                write!(f, "Suffix error: UUID variant is not RFC 9562")
+            }
+            Self::NilNotAllowed => write!(f, "Suffix error: the Nil UUID is not allowed"),
+            Self::MaxNotAllowed => write!(f, "Suffix error: the Max UUID is not allowed"),
         }
     }
 }
@@ -44,6 +116,14 @@ impl std::error::Error for MagicTypeIdError {
         match self {
             Self::Prefix(err) => Some(err),
             Self::Suffix(err) => Some(err),
+            Self::PrefixMismatch { .. }
+            | Self::PrefixNotAllowed { .. }
+            | Self::UnknownPrefix { .. }
+            | Self::EmptyPrefixNotAllowed
+            | Self::VersionNotAllowed { .. }
+            | Self::VariantNotAllowed
+            | Self::NilNotAllowed
+            | Self::MaxNotAllowed => None,
         }
     }
 }
@@ -65,3 +145,44 @@ impl From<DecodeError> for MagicTypeIdError {
         Self::Suffix(err)
     }
 }
+
+/// Represents errors that can occur when registering kinds in a
+/// [`PrefixRegistry`](crate::prelude::PrefixRegistry).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RegistryError {
+    /// A prefix or alias is not a valid `TypeID` prefix.
+    InvalidPrefix {
+        /// The invalid prefix or alias.
+        prefix: &'static str,
+        /// Why it is invalid.
+        error: ValidationError,
+    },
+
+    /// A prefix or alias is already registered, as a prefix or as an alias.
+    DuplicatePrefix {
+        /// The duplicated prefix or alias.
+        prefix: &'static str,
+    },
+}
+
+impl fmt::Display for RegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidPrefix { prefix, error } => {
+                write!(f, "Registry error: invalid prefix '{prefix}': {error}")
+            }
+            Self::DuplicatePrefix { prefix } => {
+                write!(f, "Registry error: prefix '{prefix}' is already registered")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RegistryError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InvalidPrefix { error, .. } => Some(error),
+            Self::DuplicatePrefix { .. } => None,
+        }
+    }
+}
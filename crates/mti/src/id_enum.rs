@@ -0,0 +1,271 @@
+//! Enums that hold an identifier of any of several entity types.
+//!
+//! Endpoints that accept an identifier of any entity type, such as an audit log or a "get by
+//! ID" route, otherwise have to dispatch on `id.prefix().as_str()` with string matches. This
+//! module provides [`id_enum!`](crate::id_enum), which declares an enum with one variant per
+//! prefix whose parsing routes each identifier to the variant of its prefix, along with a
+//! fieldless "kind" enum that matches can be written against exhaustively.
+
+/// Declares an enum with one variant per prefix, and a fieldless enum of its kinds.
+///
+/// ```text
+/// id_enum! {
+///     #[derive(...)]
+///     pub enum AnyId: AnyIdKind {
+///         User = "user",
+///         Order(OrderId) = "order",
+///     }
+/// }
+/// ```
+///
+/// Each variant holds a [`MagicTypeId`](crate::prelude::MagicTypeId), or the type given in
+/// parentheses. Such a type must implement `TryFrom<MagicTypeId, Error = MagicTypeIdError>`,
+/// `AsRef<MagicTypeId>` and `Into<MagicTypeId>`, as [`TypedId`](crate::prelude::TypedId) and
+/// types deriving `MagicId` do. Attributes and doc comments on the enum and its variants are
+/// kept, so derive what you need, such as `Debug`, `Clone`, `PartialEq` or `Hash`.
+///
+/// The macro generates:
+///
+/// * The kind enum (`AnyIdKind` above), a fieldless `Copy` enum with the same variants, and
+///   `ALL` and `prefix()` on it.
+/// * `kind()`, `prefix()`, `suffix()`, `as_str()`, `as_magic_type_id()` and `into_inner()` on
+///   the enum, and its `PREFIXES` and `parse()`.
+/// * `FromStr`, `Display`, `TryFrom<MagicTypeId>`, `AsRef<MagicTypeId>`, `AsRef<str>`, the
+///   conversion into `MagicTypeId` and, with the `serde` feature, `Serialize` and
+///   `Deserialize`.
+/// * With the `diesel` feature, Diesel's `AsExpression`, `ToSql`, `FromSql` and `Queryable` for
+///   `Text` columns, with the `sqlx` feature, `sqlx::Type`, `Encode` and `Decode` as text, and
+///   with the `rusqlite` feature, `ToSql` and `FromSql` as text. Loading fails if the stored
+///   prefix is not one of the declared prefixes.
+///
+/// Parsing fails with [`MagicTypeIdError::PrefixNotAllowed`](crate::prelude::MagicTypeIdError::PrefixNotAllowed)
+/// if the prefix is not one of the declared prefixes. Declared prefixes are compared as
+/// strings: they should be valid `TypeID` prefixes, since an invalid one never matches, and
+/// declaring a prefix twice triggers an `unreachable_patterns` warning.
+///
+/// Matching on `kind()` rather than on the prefix string means that adding a variant makes
+/// every `match` without a wildcard arm fail to compile until it handles the new kind.
+///
+/// # Examples
+///
+/// ```
+/// use mti::prelude::*;
+///
+/// struct Order;
+/// impl PrefixMarker for Order {
+///     const PREFIX: &'static str = "order";
+/// }
+///
+/// mti::id_enum! {
+///     /// An identifier of any entity that can be audited.
+///     #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+///     pub enum AnyId: AnyIdKind {
+///         /// A user.
+///         User = "user",
+///         /// An order.
+///         Order(TypedId<Order>) = "order",
+///     }
+/// }
+///
+/// let id: AnyId = "order_01h455vb4pex5vsknk084sn02q".parse().unwrap();
+/// assert_eq!(id.kind(), AnyIdKind::Order);
+/// assert!(matches!(id, AnyId::Order(ref order) if order.as_str() == id.as_str()));
+///
+/// let table = match id.kind() {
+///     AnyIdKind::User => "users",
+///     AnyIdKind::Order => "orders",
+/// };
+/// assert_eq!(table, "orders");
+///
+/// assert!(matches!(
+///     AnyId::parse("invoice_01h455vb4pex5vsknk084sn02q"),
+///     Err(MagicTypeIdError::PrefixNotAllowed { .. })
+/// ));
+/// ```
+#[macro_export]
+macro_rules! id_enum {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident : $kind:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident $(($payload:ty))? = $prefix:literal
+            ),+ $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant($crate::__mti_id_enum_payload!(@type $($payload)?)),
+            )+
+        }
+
+        #[doc = ::core::concat!("The kind of a [`", ::core::stringify!($name), "`], without its identifier.")]
+        #[derive(
+            ::core::fmt::Debug,
+            ::core::clone::Clone,
+            ::core::marker::Copy,
+            ::core::cmp::PartialEq,
+            ::core::cmp::Eq,
+            ::core::cmp::PartialOrd,
+            ::core::cmp::Ord,
+            ::core::hash::Hash,
+        )]
+        $vis enum $kind {
+            $(
+                #[doc = ::core::concat!("Identifiers with the prefix `", $prefix, "`.")]
+                $variant,
+            )+
+        }
+
+        impl $kind {
+            /// Every kind, in declaration order.
+            pub const ALL: &'static [Self] = &[$(Self::$variant),+];
+
+            /// Returns the prefix of identifiers of this kind.
+            #[must_use]
+            pub const fn prefix(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $prefix,)+
+                }
+            }
+        }
+
+        impl $name {
+            /// The declared prefixes, in declaration order.
+            pub const PREFIXES: &'static [&'static str] = &[$($prefix),+];
+
+            /// Parses `input` into the variant of its prefix.
+            ///
+            /// # Errors
+            ///
+            /// Returns a `MagicTypeIdError` if `input` is not a valid `MagicTypeId`, or
+            /// `MagicTypeIdError::PrefixNotAllowed` if its prefix is not declared.
+            pub fn parse(input: &str) -> ::core::result::Result<Self, $crate::prelude::MagicTypeIdError> {
+                <$crate::prelude::MagicTypeId as ::core::str::FromStr>::from_str(input)
+                    .and_then(<Self as ::core::convert::TryFrom<$crate::prelude::MagicTypeId>>::try_from)
+            }
+
+            /// Returns the kind of this identifier.
+            #[must_use]
+            pub const fn kind(&self) -> $kind {
+                match self {
+                    $(Self::$variant(_) => $kind::$variant,)+
+                }
+            }
+
+            /// Returns a reference to the underlying `MagicTypeId`.
+            #[must_use]
+            pub fn as_magic_type_id(&self) -> &$crate::prelude::MagicTypeId {
+                match self {
+                    $(Self::$variant(id) => $crate::__mti_id_enum_payload!(@as_ref id; $($payload)?),)+
+                }
+            }
+
+            /// Returns the prefix of this identifier.
+            #[must_use]
+            pub fn prefix(&self) -> &$crate::prelude::TypeIdPrefix {
+                self.as_magic_type_id().prefix()
+            }
+
+            /// Returns the suffix of this identifier.
+            #[must_use]
+            pub fn suffix(&self) -> &$crate::prelude::TypeIdSuffix {
+                self.as_magic_type_id().suffix()
+            }
+
+            /// Returns this identifier as a string slice.
+            #[must_use]
+            pub fn as_str(&self) -> &str {
+                self.as_magic_type_id().as_str()
+            }
+
+            /// Consumes the enum and returns the underlying `MagicTypeId`.
+            #[must_use]
+            pub fn into_inner(self) -> $crate::prelude::MagicTypeId {
+                match self {
+                    $(Self::$variant(id) => ::core::convert::Into::into(id),)+
+                }
+            }
+        }
+
+        impl ::core::convert::TryFrom<$crate::prelude::MagicTypeId> for $name {
+            type Error = $crate::prelude::MagicTypeIdError;
+
+            fn try_from(id: $crate::prelude::MagicTypeId) -> ::core::result::Result<Self, Self::Error> {
+                match id.prefix().as_str() {
+                    $($prefix => $crate::__mti_id_enum_payload!(@from id; $($payload)?).map(Self::$variant),)+
+                    found => ::core::result::Result::Err($crate::prelude::MagicTypeIdError::PrefixNotAllowed {
+                        allowed: Self::PREFIXES.iter().copied().map(::std::string::String::from).collect(),
+                        found: ::std::string::String::from(found),
+                    }),
+                }
+            }
+        }
+
+        impl ::core::convert::From<$name> for $crate::prelude::MagicTypeId {
+            fn from(value: $name) -> Self {
+                value.into_inner()
+            }
+        }
+
+        impl ::core::str::FromStr for $name {
+            type Err = $crate::prelude::MagicTypeIdError;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                Self::parse(s)
+            }
+        }
+
+        impl ::core::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(self.as_magic_type_id(), f)
+            }
+        }
+
+        impl ::core::convert::AsRef<$crate::prelude::MagicTypeId> for $name {
+            fn as_ref(&self) -> &$crate::prelude::MagicTypeId {
+                self.as_magic_type_id()
+            }
+        }
+
+        impl ::core::convert::AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                self.as_str()
+            }
+        }
+
+        $crate::__mti_serde_impls!($name);
+        $crate::__mti_axum_impls!($name);
+        $crate::__mti_diesel_impls!(@text $name);
+        $crate::__mti_sqlx_impls!(@text $name);
+        $crate::__mti_rusqlite_impls!(@text $name);
+    };
+}
+
+/// Expands to the payload type of an `id_enum!` variant, or to conversions from and to it.
+///
+/// Variants without an explicit type hold a `MagicTypeId`, which needs no conversion.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __mti_id_enum_payload {
+    (@type) => {
+        $crate::prelude::MagicTypeId
+    };
+    (@type $payload:ty) => {
+        $payload
+    };
+    (@from $id:ident;) => {
+        ::core::result::Result::<_, $crate::prelude::MagicTypeIdError>::Ok($id)
+    };
+    (@from $id:ident; $payload:ty) => {
+        <$payload as ::core::convert::TryFrom<$crate::prelude::MagicTypeId>>::try_from($id)
+    };
+    (@as_ref $id:ident;) => {
+        $id
+    };
+    (@as_ref $id:ident; $payload:ty) => {
+        <$payload as ::core::convert::AsRef<$crate::prelude::MagicTypeId>>::as_ref($id)
+    };
+}
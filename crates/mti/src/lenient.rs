@@ -0,0 +1,216 @@
+//! Lenient parsing of identifiers typed or pasted by humans.
+//!
+//! The `TypeID` specification decodes suffixes strictly: they must be lowercase and use only the
+//! characters of the base32 alphabet. Identifiers copied from emails, screenshots or support
+//! tickets often break these rules in predictable ways. This module provides
+//! [`MagicTypeId::parse_lenient`], which repairs such input before parsing it strictly, and
+//! reports every [`Correction`] it applied so that a user interface can ask "did you mean…?".
+
+use std::fmt;
+
+use crate::magic_type_id::MagicTypeId;
+
+/// The pairs of opening and closing quotes that [`LenientOptions::strip_quotes`] removes.
+const QUOTES: [(char, char); 5] = [
+    ('"', '"'),
+    ('\'', '\''),
+    ('`', '`'),
+    ('\u{201C}', '\u{201D}'),
+    ('\u{2018}', '\u{2019}'),
+];
+
+/// Options for [`MagicTypeId::parse_lenient`].
+///
+/// Lenient parsing always trims surrounding whitespace, folds uppercase letters to lowercase, and
+/// replaces the characters that Crockford's base32 treats as look-alikes in the suffix (`i` and
+/// `l` become `1`, and `o` becomes `0`). Stripping quotes is opt-in.
+///
+/// # Examples
+///
+/// ```
+/// use mti::prelude::*;
+///
+/// let options = LenientOptions::new().strip_quotes(true);
+/// let parsed = MagicTypeId::parse_lenient(" \"user_01h455vb4pex5vsknk084sn02q\" ", &options).unwrap();
+/// assert_eq!(parsed.id().as_str(), "user_01h455vb4pex5vsknk084sn02q");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct LenientOptions {
+    strip_quotes: bool,
+}
+
+impl LenientOptions {
+    /// Creates options that repair whitespace, case and look-alike characters, but keep quotes.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            strip_quotes: false,
+        }
+    }
+
+    /// Sets whether a matching pair of quotes around the identifier is removed.
+    ///
+    /// Straight double and single quotes, backticks, and typographic double and single quotes
+    /// are recognized. Defaults to `false`.
+    #[must_use]
+    pub const fn strip_quotes(mut self, strip: bool) -> Self {
+        self.strip_quotes = strip;
+        self
+    }
+
+    /// Repairs `input` as described in [`LenientOptions`], returning the normalized string and
+    /// the corrections that were made.
+    pub(crate) fn normalize(self, input: &str) -> (String, Vec<Correction>) {
+        let mut corrections = Vec::new();
+        let (offset, trimmed) = strip_surroundings(input, self.strip_quotes, &mut corrections);
+
+        let suffix_start = trimmed.rfind('_').map_or(0, |separator| separator + 1);
+        let mut canonical = String::with_capacity(trimmed.len());
+        for (index, found) in trimmed.char_indices() {
+            let position = offset + index;
+            let in_suffix = index >= suffix_start;
+            let lowercase = found.to_ascii_lowercase();
+            let replacement = match lowercase {
+                'i' | 'l' if in_suffix => '1',
+                'o' if in_suffix => '0',
+                _ => lowercase,
+            };
+            if replacement != lowercase {
+                corrections.push(Correction::ReplacedLookAlike {
+                    position,
+                    found,
+                    replacement,
+                });
+            } else if lowercase != found {
+                corrections.push(Correction::Lowercased { position, found });
+            }
+            canonical.push(replacement);
+        }
+        (canonical, corrections)
+    }
+}
+
+/// A change that [`MagicTypeId::parse_lenient`] made to its input.
+///
+/// Positions are byte offsets into the original input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Correction {
+    /// Whitespace around the identifier was removed.
+    TrimmedWhitespace,
+
+    /// A pair of quotes around the identifier was removed.
+    StrippedQuotes {
+        /// The opening quote.
+        quote: char,
+    },
+
+    /// An uppercase letter was replaced by its lowercase form.
+    Lowercased {
+        /// The position of the letter.
+        position: usize,
+        /// The letter as it appeared in the input.
+        found: char,
+    },
+
+    /// A character outside the base32 alphabet was replaced by the digit it resembles.
+    ReplacedLookAlike {
+        /// The position of the character.
+        position: usize,
+        /// The character as it appeared in the input.
+        found: char,
+        /// The digit that replaced it.
+        replacement: char,
+    },
+}
+
+impl fmt::Display for Correction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TrimmedWhitespace => write!(f, "removed surrounding whitespace"),
+            Self::StrippedQuotes { quote } => write!(f, "removed surrounding {quote} quotes"),
+            Self::Lowercased { position, found } => {
+                write!(f, "lowercased '{found}' at position {position}")
+            }
+            Self::ReplacedLookAlike {
+                position,
+                found,
+                replacement,
+            } => write!(
+                f,
+                "replaced '{found}' with '{replacement}' at position {position}"
+            ),
+        }
+    }
+}
+
+/// The result of [`MagicTypeId::parse_lenient`]: the canonical identifier and the corrections
+/// that were needed to obtain it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LenientParse {
+    id: MagicTypeId,
+    corrections: Vec<Correction>,
+}
+
+impl LenientParse {
+    /// Pairs a parsed identifier with the corrections made to its input.
+    pub(crate) const fn new(id: MagicTypeId, corrections: Vec<Correction>) -> Self {
+        Self { id, corrections }
+    }
+
+    /// Returns the canonical identifier.
+    #[must_use]
+    pub const fn id(&self) -> &MagicTypeId {
+        &self.id
+    }
+
+    /// Returns the corrections that were applied to the input, in the order they were made.
+    #[must_use]
+    pub fn corrections(&self) -> &[Correction] {
+        &self.corrections
+    }
+
+    /// Returns whether the input had to be corrected, that is, whether it was not already a
+    /// canonical identifier.
+    #[must_use]
+    pub const fn was_corrected(&self) -> bool {
+        !self.corrections.is_empty()
+    }
+
+    /// Returns the canonical identifier, discarding the corrections.
+    #[must_use]
+    pub fn into_id(self) -> MagicTypeId {
+        self.id
+    }
+}
+
+/// Removes whitespace and, if `strip_quotes` is set, quotes around `input`, returning the byte offset of what
+/// remains along with it.
+fn strip_surroundings<'a>(
+    input: &'a str,
+    strip_quotes: bool,
+    corrections: &mut Vec<Correction>,
+) -> (usize, &'a str) {
+    let mut offset = input.len() - input.trim_start().len();
+    let mut trimmed = input.trim();
+    let mut trimmed_whitespace = trimmed.len() != input.len();
+
+    if strip_quotes {
+        let quoted = QUOTES.iter().find_map(|&(open, close)| {
+            trimmed
+                .strip_prefix(open)
+                .and_then(|inner| inner.strip_suffix(close))
+                .map(|inner| (open, inner))
+        });
+        if let Some((quote, inner)) = quoted {
+            corrections.push(Correction::StrippedQuotes { quote });
+            offset += quote.len_utf8() + inner.len() - inner.trim_start().len();
+            trimmed_whitespace |= inner.trim().len() != inner.len();
+            trimmed = inner.trim();
+        }
+    }
+
+    if trimmed_whitespace {
+        corrections.insert(0, Correction::TrimmedWhitespace);
+    }
+    (offset, trimmed)
+}
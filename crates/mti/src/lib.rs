@@ -111,28 +111,28 @@
 //!
 //! ### Custom Type-Safe ID Types
 //!
-//! Create custom ID types for enhanced type safety:
+//! Use [`TypedId`](prelude::TypedId) to give every entity its own identifier type. The prefix is fixed
+//! by a marker type implementing [`PrefixMarker`](prelude::PrefixMarker), and parsing rejects any other prefix:
 //!
 //! ```rust
+//! use std::str::FromStr;
 //! use mti::prelude::*;
 //!
-//! struct UserId(MagicTypeId);
-//! struct OrderId(MagicTypeId);
-//!
-//! impl UserId {
-//!     fn new() -> Self {
-//!         Self("user".create_type_id::<V7>())
-//!     }
+//! struct User;
+//! impl PrefixMarker for User {
+//!     const PREFIX: &'static str = "user";
 //! }
 //!
-//! impl OrderId {
-//!     fn new() -> Self {
-//!         Self("order".create_type_id::<V7>())
-//!     }
+//! struct Order;
+//! impl PrefixMarker for Order {
+//!     const PREFIX: &'static str = "order";
 //! }
 //!
-//! let user_id = UserId::new();
-//! let order_id = OrderId::new();
+//! type UserId = TypedId<User>;
+//! type OrderId = TypedId<Order>;
+//!
+//! let user_id = UserId::new::<V7>();
+//! let order_id = OrderId::new::<V7>();
 //!
 //! // Compile-time type safety
 //! fn process_user(id: UserId) { /* ... */ }
@@ -141,41 +141,119 @@
 //! process_user(user_id);
 //! process_order(order_id);
 //! // process_user(order_id); // This would cause a compile-time error!
+//!
+//! // Runtime parsing enforces the prefix as well
+//! assert!(UserId::from_str("order_01h455vb4pex5vsknk084sn02q").is_err());
+//! ```
+//!
+//! ### Deriving ID Newtypes
+//!
+//! With the `macros` feature enabled, `#[derive(MagicId)]` generates the constructors, parsing,
+//! formatting, conversions and (with `serde`) serialization for a newtype around `MagicTypeId`.
+//! Invalid prefixes are rejected at compile time:
+//!
+//! ```rust
+//! # #[cfg(feature = "macros")]
+//! # {
+//! use mti::prelude::*;
+//!
+//! #[derive(Debug, Clone, PartialEq, Eq, Hash, MagicId)]
+//! #[mti(prefix = "user", version = "v7")]
+//! struct UserId(MagicTypeId);
+//!
+//! let user_id = UserId::new();
+//! assert_eq!(UserId::parse(user_id.as_str()).unwrap(), user_id);
+//! assert!(UserId::parse("order_01h455vb4pex5vsknk084sn02q").is_err());
+//! # }
+//! ```
+//!
+//! The same feature provides `typeid!` and `prefix!`, which check literals at compile time
+//! instead of unwrapping a runtime parse:
+//!
+//! ```rust
+//! # #[cfg(feature = "macros")]
+//! # {
+//! use mti::prelude::*;
+//!
+//! let system_user = typeid!("user_01h455vb4pex5vsknk084sn02q");
+//! assert_eq!(system_user.prefix(), &prefix!("user"));
+//! # }
 //! ```
 //!
 //! ### Seamless Database Integration
 //!
-//! `MagicTypeId`s can be easily integrated with database libraries:
+//! With the `sqlx` feature, identifiers can be bound to queries and read from rows directly. A
+//! `MagicTypeId` is stored as text. A [`TypedId`](prelude::TypedId) or
+//! [`TypeIdSuffix`](prelude::TypeIdSuffix) is stored as the UUID of its suffix (a native UUID
+//! column, or a 16-byte blob), and a `TypedId` adds its prefix back when decoded. A `TypedId` can
+//! also be decoded from a text column holding a whole `MagicTypeId`, in which case its prefix is
+//! checked:
 //!
-//! ```rust,ignore
+//! ```rust
+//! # #[cfg(feature = "sqlx")]
+//! # mod example {
 //! use mti::prelude::*;
-//! use some_db_library::Database;
+//! use sqlx::SqliteConnection;
 //!
-//! #[derive(Debug)]
-//! struct User {
-//!     id: MagicTypeId,
-//!     name: String,
+//! struct Order;
+//! impl PrefixMarker for Order {
+//!     const PREFIX: &'static str = "order";
 //! }
 //!
-//! fn create_user(db: &Database, name: &str) -> User {
-//!     let user = User {
-//!         id: "user".magic_type_id::<V7>(),
-//!         name: name.to_string(),
-//!     };
-//!     db.insert("users", &user);
-//!     user
+//! async fn create_order(
+//!     conn: &mut SqliteConnection,
+//!     user_id: &MagicTypeId,
+//! ) -> sqlx::Result<TypedId<Order>> {
+//!     let order_id = TypedId::<Order>::new::<V7>();
+//!     // `id` is a BLOB holding the UUID, `user_id` is TEXT holding "user_01h4..."
+//!     sqlx::query("INSERT INTO orders (id, user_id) VALUES (?, ?)")
+//!         .bind(&order_id)
+//!         .bind(user_id)
+//!         .execute(conn)
+//!         .await?;
+//!     Ok(order_id)
 //! }
 //!
-//! fn get_user(db: &Database, id: &MagicTypeId) -> Option<User> {
-//!     db.get("users", id)
+//! async fn order_owner(
+//!     conn: &mut SqliteConnection,
+//!     id: &TypedId<Order>,
+//! ) -> sqlx::Result<MagicTypeId> {
+//!     sqlx::query_scalar("SELECT user_id FROM orders WHERE id = ?")
+//!         .bind(id)
+//!         .fetch_one(conn)
+//!         .await
 //! }
+//! # }
 //! ```
 //!
+//! The `diesel` feature provides the same two storage modes for Diesel: `MagicTypeId` maps to
+//! `Text` columns, and `TypedId` and `TypeIdSuffix` map to `Uuid` or `Binary` columns. All three
+//! implement `AsExpression` and `FromSqlRow`, so they can be used as fields of `Queryable` and
+//! `Insertable` structs, and loading a `TypedId` from a `Text` column checks its prefix.
+//!
+//! The `rusqlite` feature implements `ToSql` and `FromSql` from `rusqlite`. A `MagicTypeId` is
+//! written as text and a `TypedId` or `TypeIdSuffix` as a 16-byte blob, and invalid stored values
+//! fail with [`FromSqlError::Other`](https://docs.rs/rusqlite/latest/rusqlite/types/enum.FromSqlError.html)
+//! holding the [`MagicTypeIdError`](prelude::MagicTypeIdError).
+//!
+//! With any of the `sqlx`, `diesel` and `rusqlite` features, types deriving `MagicId` get the
+//! same impls as a `TypedId`, and `id_enum!` enums are stored as text only, since they need the
+//! stored prefix to pick a variant.
+//!
+//! ### Web Frameworks
+//!
+//! With the `axum` feature, the `mti::axum` module provides extractors that parse and
+//! prefix-check identifiers from path and query parameters, and reject invalid ones with a
+//! structured `400 Bad Request`. With the `tower` feature, the `mti::tower` module provides a
+//! middleware that gives every request a `TypeID` correlation identifier.
+//!
 //! ## Performance and Safety
 //!
 //! Magic Type ID is designed with performance and safety in mind:
 //!
 //! - Zero-cost abstractions for string-like operations.
+//! - [`CompactTypeId`](prelude::CompactTypeId), a `Copy` identifier that parses and formats without heap allocation.
+//! - [`MagicTypeIdRef`](prelude::MagicTypeIdRef), a borrowed view that validates an identifier in place without copying it.
 //! - Built on top of the thoroughly tested and verified `TypeIdPrefix` and `TypeIdSuffix` crates.
 //! - Efficient UUID generation and manipulation.
 //!
@@ -183,6 +261,7 @@
 //!
 //! - Explore the [`magic_type_id::MagicTypeId`] struct for core functionality.
 //! - Check out the [`magic_type_id_ext::MagicTypeIdExt`] trait for powerful string extension methods.
+//! - Use [`TypedId`](prelude::TypedId) for identifiers whose prefix is checked by the compiler.
 //! - See the `errors` module for comprehensive error handling.
 //!
 //! ## Contributing
@@ -200,9 +279,44 @@
 //!
 //! Happy coding with Magic Type ID! 🎩✨
 
+#[cfg(feature = "axum")]
+pub mod axum;
+mod compact_type_id;
+mod diagnostic;
 mod errors;
+mod id_enum;
+mod lenient;
+mod macro_support;
 mod magic_type_id;
 mod magic_type_id_ext;
+mod magic_type_id_ref;
+mod parse_options;
+mod registry;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "serde")]
+mod serde_support;
+#[cfg(feature = "tower")]
+pub mod tower;
+mod typed_id;
+
+#[doc(hidden)]
+pub mod __private {
+    //! Support code for macro-generated items. Not public API.
+    pub use crate::macro_support::*;
+
+    #[cfg(feature = "serde")]
+    pub use serde;
+
+    #[cfg(feature = "diesel")]
+    pub use diesel;
+
+    #[cfg(feature = "sqlx")]
+    pub use sqlx;
+
+    #[cfg(feature = "rusqlite")]
+    pub use rusqlite;
+}
 
 /// A prelude module that re-exports the most commonly used types and traits.
 ///
@@ -232,11 +346,43 @@ pub mod prelude {
     /// `MagicTypeId` represents a type-safe identifier combining a prefix and a UUID-based suffix.
     pub use crate::magic_type_id::MagicTypeId;
 
+    /// Re-exports `MagicTypeIdRef`, a borrowed `MagicTypeId` that is validated without copying its input.
+    pub use crate::magic_type_id_ref::MagicTypeIdRef;
+
     /// Re-exports the `MagicTypeIdExt` trait, which provides extension methods for creating and manipulating `MagicTypeId`s.
     ///
     /// This trait is implemented for `str`, allowing for easy creation of `MagicTypeId`s from string literals.
     pub use crate::magic_type_id_ext::MagicTypeIdExt;
 
+    /// Re-exports `ParseOptions`, which configures the validation rules of [`MagicTypeId::parse_with`].
+    pub use crate::parse_options::ParseOptions;
+
+    /// Re-exports `ParseDiagnostic` and `IdComponent`, which locate parse errors in their input.
+    pub use crate::diagnostic::{IdComponent, ParseDiagnostic};
+
+    /// Re-exports the types of lenient parsing, which repairs identifiers typed or pasted by humans.
+    pub use crate::lenient::{Correction, LenientOptions, LenientParse};
+
+    /// Re-exports `PrefixRegistry` and `EntityKind`, which map known prefixes to the entities they identify.
+    pub use crate::registry::{EntityKind, PrefixRegistry, RegisteredId};
+
+    /// Re-exports the `id_enum!` macro, which declares an enum of identifiers routed by prefix.
+    pub use crate::id_enum;
+
+    /// Re-exports `CompactTypeId`, an allocation-free, `Copy` representation of a `MagicTypeId`.
+    pub use crate::compact_type_id::CompactTypeId;
+
+    /// Re-exports `TypedId` and `PrefixMarker`, which fix the prefix of an identifier at compile time.
+    pub use crate::typed_id::{PrefixMarker, TypedId};
+
+    /// Re-exports the procedural macros when the "macros" feature is enabled.
+    ///
+    /// `#[derive(MagicId)]` turns a struct wrapping a `MagicTypeId` into an identifier type whose
+    /// prefix is validated at compile time, and `typeid!` and `prefix!` check identifier and prefix
+    /// literals at compile time.
+    #[cfg(feature = "macros")]
+    pub use mti_macros::{prefix, typeid, MagicId};
+
     /// Re-exports the `tracing` crate when the "instrument" feature is enabled.
     ///
     /// This allows users of this crate to access tracing functionality without having to
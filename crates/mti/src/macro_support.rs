@@ -0,0 +1,520 @@
+//! Runtime support for the code generated by the `mti_macros` procedural macros.
+//!
+//! Everything in this module is re-exported through the hidden `__private` module and is not
+//! part of the public API. Prefixes passed to these functions have already been validated at
+//! compile time by the macro that generated the call.
+
+use std::str::FromStr;
+
+use typeid_prefix::TypeIdPrefix;
+use typeid_suffix::prelude::*;
+
+use crate::errors::MagicTypeIdError;
+use crate::magic_type_id::MagicTypeId;
+
+/// Builds a `TypeIdPrefix` from a prefix that was validated at compile time.
+fn validated_prefix(prefix: &'static str) -> TypeIdPrefix {
+    TypeIdPrefix::from_str(prefix).unwrap_or_else(|err| {
+        unreachable!("prefix '{prefix}' was validated at compile time: {err}")
+    })
+}
+
+/// Creates a `MagicTypeId` with the given prefix and a freshly generated suffix.
+#[must_use]
+pub fn new_type_id<V>(prefix: &'static str) -> MagicTypeId
+where
+    V: UuidVersion + Default,
+{
+    MagicTypeId::new(validated_prefix(prefix), TypeIdSuffix::new::<V>())
+}
+
+/// Creates a `MagicTypeId` with the given prefix and UUID.
+#[must_use]
+pub fn type_id_from_uuid(prefix: &'static str, uuid: Uuid) -> MagicTypeId {
+    MagicTypeId::new(validated_prefix(prefix), TypeIdSuffix::from(uuid))
+}
+
+/// Creates a `MagicTypeId` from the parts of a literal validated at compile time by `typeid!`.
+///
+/// An empty `prefix` stands for an identifier without a prefix.
+#[must_use]
+pub fn type_id_literal(prefix: &'static str, suffix: u128) -> MagicTypeId {
+    let prefix = if prefix.is_empty() {
+        TypeIdPrefix::default()
+    } else {
+        validated_prefix(prefix)
+    };
+    MagicTypeId::new(prefix, TypeIdSuffix::from(Uuid::from_u128(suffix)))
+}
+
+/// Creates a `TypeIdPrefix` from a literal validated at compile time by `prefix!`.
+#[must_use]
+pub fn prefix_literal(prefix: &'static str) -> TypeIdPrefix {
+    validated_prefix(prefix)
+}
+
+/// Parses `input` as a `MagicTypeId` and checks that it carries `prefix`.
+///
+/// # Errors
+///
+/// Returns a `MagicTypeIdError` if `input` is not a valid `MagicTypeId`, or
+/// [`MagicTypeIdError::PrefixMismatch`] if its prefix differs from `prefix`.
+pub fn parse_with_prefix(input: &str, prefix: &str) -> Result<MagicTypeId, MagicTypeIdError> {
+    MagicTypeId::from_str(input).and_then(|id| id.ensure_prefix(prefix))
+}
+
+/// Checks that `id` carries `prefix`.
+///
+/// # Errors
+///
+/// Returns [`MagicTypeIdError::PrefixMismatch`] if the prefix of `id` differs from `prefix`.
+pub fn ensure_prefix(id: MagicTypeId, prefix: &str) -> Result<MagicTypeId, MagicTypeIdError> {
+    id.ensure_prefix(prefix)
+}
+
+/// Implements `Serialize` and `Deserialize` for a derived identifier when the `serde` feature of
+/// `mti` is enabled, and expands to nothing otherwise.
+///
+/// The feature check happens here rather than in the procedural macro so that it always follows
+/// the features of the `mti` crate the generated code is compiled against.
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __mti_serde_impls {
+    ($ty:ident) => {
+        impl $crate::__private::serde::Serialize for $ty {
+            fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                S: $crate::__private::serde::Serializer,
+            {
+                let id: &$crate::prelude::MagicTypeId = ::core::convert::AsRef::as_ref(self);
+                $crate::__private::serde::Serialize::serialize(id, serializer)
+            }
+        }
+
+        impl<'de> $crate::__private::serde::Deserialize<'de> for $ty {
+            fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+            where
+                D: $crate::__private::serde::Deserializer<'de>,
+            {
+                let id = <$crate::prelude::MagicTypeId as $crate::__private::serde::Deserialize<
+                    'de,
+                >>::deserialize(deserializer)?;
+                <Self as ::core::convert::TryFrom<$crate::prelude::MagicTypeId>>::try_from(id)
+                    .map_err(<D::Error as $crate::__private::serde::de::Error>::custom)
+            }
+        }
+    };
+}
+
+/// Implements `Serialize` and `Deserialize` for a derived identifier when the `serde` feature of
+/// `mti` is enabled, and expands to nothing otherwise.
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __mti_serde_impls {
+    ($ty:ident) => {};
+}
+
+/// Implements [`FromIdParams`](crate::axum::FromIdParams) for a derived identifier when the
+/// `axum` feature of `mti` is enabled, and expands to nothing otherwise.
+///
+/// Like [`__mti_serde_impls`], the feature check happens here rather than in the procedural macro.
+#[cfg(feature = "axum")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __mti_axum_impls {
+    ($ty:ident) => {
+        impl $crate::axum::FromIdParams for $ty {
+            fn from_id_params(
+                params: &$crate::axum::IdParams<'_>,
+            ) -> ::core::result::Result<Self, $crate::axum::IdRejection> {
+                <($ty,) as $crate::axum::FromIdParams>::from_id_params(params).map(|(id,)| id)
+            }
+        }
+    };
+}
+
+/// Implements [`FromIdParams`](crate::axum::FromIdParams) for a derived identifier when the
+/// `axum` feature of `mti` is enabled, and expands to nothing otherwise.
+#[cfg(not(feature = "axum"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __mti_axum_impls {
+    ($ty:ident) => {};
+}
+
+/// Implements Diesel's `AsExpression`, `ToSql`, `FromSql` and `Queryable` for a derived
+/// identifier or an `id_enum!` when the `diesel` feature of `mti` is enabled, and expands to
+/// nothing otherwise.
+///
+/// Every identifier can be stored whole as `Text`, and loading it checks the prefix through
+/// `TryFrom<MagicTypeId>`. A derived identifier, whose prefix is fixed, can also be stored as
+/// the `Uuid` or `Binary` of its suffix, like a [`TypedId`](crate::prelude::TypedId). An
+/// `id_enum!`, which needs the prefix to pick a variant, is passed as `@text` and only gets the
+/// `Text` impls.
+///
+/// Like [`__mti_serde_impls`], the feature check happens here rather than in the procedural macro.
+#[cfg(feature = "diesel")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __mti_diesel_impls {
+    ($ty:ident) => {
+        $crate::__mti_diesel_impls!(@text $ty);
+        $crate::__mti_diesel_impls!(@suffix $ty, Uuid);
+        $crate::__mti_diesel_impls!(@suffix $ty, Binary);
+    };
+    (@text $ty:ident) => {
+        $crate::__mti_diesel_impls!(@to_sql $ty, Text, $crate::prelude::MagicTypeId);
+        $crate::__mti_diesel_impls!(@queryable $ty);
+
+        /// Loads the identifier from text holding a whole `MagicTypeId`, checking its prefix.
+        impl<__DB> $crate::__private::diesel::deserialize::FromSql<
+            $crate::__private::diesel::sql_types::Text,
+            __DB,
+        > for $ty
+        where
+            __DB: $crate::__private::diesel::backend::Backend,
+            $crate::prelude::MagicTypeId: $crate::__private::diesel::deserialize::FromSql<
+                $crate::__private::diesel::sql_types::Text,
+                __DB,
+            >,
+        {
+            fn from_sql(
+                bytes: __DB::RawValue<'_>,
+            ) -> $crate::__private::diesel::deserialize::Result<Self> {
+                let id: $crate::prelude::MagicTypeId =
+                    $crate::__private::diesel::deserialize::FromSql::<
+                        $crate::__private::diesel::sql_types::Text,
+                        __DB,
+                    >::from_sql(bytes)?;
+                ::core::result::Result::Ok(
+                    <Self as ::core::convert::TryFrom<$crate::prelude::MagicTypeId>>::try_from(id)?,
+                )
+            }
+        }
+    };
+    (@suffix $ty:ident, $sql_type:ident) => {
+        $crate::__mti_diesel_impls!(@to_sql $ty, $sql_type, $crate::prelude::TypeIdSuffix);
+
+        /// Loads the identifier from the UUID of its suffix, adding its prefix.
+        impl<__DB> $crate::__private::diesel::deserialize::FromSql<
+            $crate::__private::diesel::sql_types::$sql_type,
+            __DB,
+        > for $ty
+        where
+            __DB: $crate::__private::diesel::backend::Backend,
+            $crate::prelude::TypeIdSuffix: $crate::__private::diesel::deserialize::FromSql<
+                $crate::__private::diesel::sql_types::$sql_type,
+                __DB,
+            >,
+        {
+            fn from_sql(
+                bytes: __DB::RawValue<'_>,
+            ) -> $crate::__private::diesel::deserialize::Result<Self> {
+                <$crate::prelude::TypeIdSuffix as $crate::__private::diesel::deserialize::FromSql<
+                    $crate::__private::diesel::sql_types::$sql_type,
+                    __DB,
+                >>::from_sql(bytes)
+                .map(|suffix| Self::from_uuid(suffix.to_uuid()))
+            }
+        }
+    };
+    (@get Text, $id:expr) => {
+        ::core::convert::AsRef::<$crate::prelude::MagicTypeId>::as_ref($id)
+    };
+    (@get $sql_type:ident, $id:expr) => {
+        ::core::convert::AsRef::<$crate::prelude::MagicTypeId>::as_ref($id).suffix()
+    };
+    (@to_sql $ty:ident, $sql_type:ident, $inner:ty) => {
+        $crate::__mti_diesel_impls!(@expression $ty, $sql_type, $inner,
+            $crate::__private::diesel::sql_types::$sql_type);
+        $crate::__mti_diesel_impls!(@expression $ty, $sql_type, $inner,
+            $crate::__private::diesel::sql_types::Nullable<
+                $crate::__private::diesel::sql_types::$sql_type,
+            >);
+
+        impl<__DB> $crate::__private::diesel::serialize::ToSql<
+            $crate::__private::diesel::sql_types::$sql_type,
+            __DB,
+        > for $ty
+        where
+            __DB: $crate::__private::diesel::backend::Backend,
+            $inner: $crate::__private::diesel::serialize::ToSql<
+                $crate::__private::diesel::sql_types::$sql_type,
+                __DB,
+            >,
+        {
+            fn to_sql<'__b>(
+                &'__b self,
+                out: &mut $crate::__private::diesel::serialize::Output<'__b, '_, __DB>,
+            ) -> $crate::__private::diesel::serialize::Result {
+                <$inner as $crate::__private::diesel::serialize::ToSql<
+                    $crate::__private::diesel::sql_types::$sql_type,
+                    __DB,
+                >>::to_sql($crate::__mti_diesel_impls!(@get $sql_type, self), out)
+            }
+        }
+    };
+    (@expression $ty:ident, $sql_type:ident, $inner:ty, $st:ty) => {
+        impl $crate::__private::diesel::expression::AsExpression<$st> for $ty {
+            type Expression =
+                <$inner as $crate::__private::diesel::expression::AsExpression<$st>>::Expression;
+
+            fn as_expression(self) -> Self::Expression {
+                let inner = ::core::clone::Clone::clone(
+                    $crate::__mti_diesel_impls!(@get $sql_type, &self),
+                );
+                <$inner as $crate::__private::diesel::expression::AsExpression<$st>>::as_expression(
+                    inner,
+                )
+            }
+        }
+
+        impl<'__expr> $crate::__private::diesel::expression::AsExpression<$st> for &'__expr $ty {
+            type Expression = <
+                &'__expr $inner as $crate::__private::diesel::expression::AsExpression<$st>
+            >::Expression;
+
+            fn as_expression(self) -> Self::Expression {
+                let inner: &'__expr $inner = $crate::__mti_diesel_impls!(@get $sql_type, self);
+                $crate::__private::diesel::expression::AsExpression::<$st>::as_expression(inner)
+            }
+        }
+    };
+    (@queryable $ty:ident) => {
+        impl<__DB, __ST> $crate::__private::diesel::deserialize::Queryable<__ST, __DB> for $ty
+        where
+            __DB: $crate::__private::diesel::backend::Backend,
+            __ST: $crate::__private::diesel::sql_types::SingleValue,
+            Self: $crate::__private::diesel::deserialize::FromSql<__ST, __DB>,
+        {
+            type Row = Self;
+
+            fn build(row: Self) -> $crate::__private::diesel::deserialize::Result<Self> {
+                ::core::result::Result::Ok(row)
+            }
+        }
+    };
+}
+
+/// Implements Diesel's `AsExpression`, `ToSql`, `FromSql` and `Queryable` for a derived
+/// identifier or an `id_enum!` when the `diesel` feature of `mti` is enabled, and expands to
+/// nothing otherwise.
+#[cfg(not(feature = "diesel"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __mti_diesel_impls {
+    ($($tt:tt)*) => {};
+}
+
+/// Implements `sqlx::Type`, `Encode` and `Decode` for a derived identifier or an `id_enum!` when
+/// the `sqlx` feature of `mti` is enabled, and expands to nothing otherwise.
+///
+/// A derived identifier is stored like a [`TypedId`](crate::prelude::TypedId), as the UUID of
+/// its suffix, and can also be decoded from text holding a whole `MagicTypeId` with its prefix.
+/// An `id_enum!`, which needs the prefix to pick a variant, is passed as `@text` and is stored
+/// and decoded as text only. Decoding text checks the prefix through `FromStr`.
+///
+/// Like [`__mti_serde_impls`], the feature check happens here rather than in the procedural macro.
+#[cfg(feature = "sqlx")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __mti_sqlx_impls {
+    ($ty:ident) => {
+        impl<__DB: $crate::__private::sqlx::Database> $crate::__private::sqlx::Type<__DB> for $ty
+        where
+            $crate::prelude::Uuid: $crate::__private::sqlx::Type<__DB>,
+            str: $crate::__private::sqlx::Type<__DB>,
+        {
+            fn type_info() -> __DB::TypeInfo {
+                <$crate::prelude::Uuid as $crate::__private::sqlx::Type<__DB>>::type_info()
+            }
+
+            fn compatible(ty: &__DB::TypeInfo) -> bool {
+                <$crate::prelude::Uuid as $crate::__private::sqlx::Type<__DB>>::compatible(ty)
+                    || <str as $crate::__private::sqlx::Type<__DB>>::compatible(ty)
+            }
+        }
+
+        impl<'__q, __DB> $crate::__private::sqlx::Encode<'__q, __DB> for $ty
+        where
+            __DB: $crate::__private::sqlx::Database,
+            $crate::prelude::TypeIdSuffix: $crate::__private::sqlx::Encode<'__q, __DB>,
+        {
+            fn encode_by_ref(
+                &self,
+                buf: &mut <__DB as $crate::__private::sqlx::Database>::ArgumentBuffer<'__q>,
+            ) -> ::core::result::Result<
+                $crate::__private::sqlx::encode::IsNull,
+                $crate::__private::sqlx::error::BoxDynError,
+            > {
+                let id: &$crate::prelude::MagicTypeId = ::core::convert::AsRef::as_ref(self);
+                $crate::__private::sqlx::Encode::<'__q, __DB>::encode_by_ref(id.suffix(), buf)
+            }
+        }
+
+        impl<'__r, __DB> $crate::__private::sqlx::Decode<'__r, __DB> for $ty
+        where
+            __DB: $crate::__private::sqlx::Database,
+            $crate::prelude::Uuid:
+                $crate::__private::sqlx::Type<__DB> + $crate::__private::sqlx::Decode<'__r, __DB>,
+            str: $crate::__private::sqlx::Type<__DB>,
+            &'__r str: $crate::__private::sqlx::Decode<'__r, __DB>,
+        {
+            fn decode(
+                value: <__DB as $crate::__private::sqlx::Database>::ValueRef<'__r>,
+            ) -> ::core::result::Result<Self, $crate::__private::sqlx::error::BoxDynError> {
+                let is_text = {
+                    let ty = $crate::__private::sqlx::ValueRef::type_info(&value);
+                    let uuid =
+                        <$crate::prelude::Uuid as $crate::__private::sqlx::Type<__DB>>::type_info();
+                    // Some drivers also decode a `Uuid` from text, so match the UUID type exactly
+                    *ty != uuid && <str as $crate::__private::sqlx::Type<__DB>>::compatible(&ty)
+                };
+                if is_text {
+                    let s: &'__r str =
+                        $crate::__private::sqlx::Decode::<'__r, __DB>::decode(value)?;
+                    return ::core::result::Result::Ok(<Self as ::core::str::FromStr>::from_str(
+                        s,
+                    )?);
+                }
+                let uuid: $crate::prelude::Uuid =
+                    $crate::__private::sqlx::Decode::<'__r, __DB>::decode(value)?;
+                ::core::result::Result::Ok(Self::from_uuid(uuid))
+            }
+        }
+    };
+    (@text $ty:ident) => {
+        impl<__DB: $crate::__private::sqlx::Database> $crate::__private::sqlx::Type<__DB> for $ty
+        where
+            str: $crate::__private::sqlx::Type<__DB>,
+        {
+            fn type_info() -> __DB::TypeInfo {
+                <str as $crate::__private::sqlx::Type<__DB>>::type_info()
+            }
+
+            fn compatible(ty: &__DB::TypeInfo) -> bool {
+                <str as $crate::__private::sqlx::Type<__DB>>::compatible(ty)
+            }
+        }
+
+        impl<'__q, __DB> $crate::__private::sqlx::Encode<'__q, __DB> for $ty
+        where
+            __DB: $crate::__private::sqlx::Database,
+            $crate::prelude::MagicTypeId: $crate::__private::sqlx::Encode<'__q, __DB>,
+        {
+            fn encode_by_ref(
+                &self,
+                buf: &mut <__DB as $crate::__private::sqlx::Database>::ArgumentBuffer<'__q>,
+            ) -> ::core::result::Result<
+                $crate::__private::sqlx::encode::IsNull,
+                $crate::__private::sqlx::error::BoxDynError,
+            > {
+                let id: &$crate::prelude::MagicTypeId = ::core::convert::AsRef::as_ref(self);
+                $crate::__private::sqlx::Encode::<'__q, __DB>::encode_by_ref(id, buf)
+            }
+        }
+
+        impl<'__r, __DB> $crate::__private::sqlx::Decode<'__r, __DB> for $ty
+        where
+            __DB: $crate::__private::sqlx::Database,
+            &'__r str: $crate::__private::sqlx::Decode<'__r, __DB>,
+        {
+            fn decode(
+                value: <__DB as $crate::__private::sqlx::Database>::ValueRef<'__r>,
+            ) -> ::core::result::Result<Self, $crate::__private::sqlx::error::BoxDynError> {
+                let s: &'__r str = $crate::__private::sqlx::Decode::<'__r, __DB>::decode(value)?;
+                ::core::result::Result::Ok(<Self as ::core::str::FromStr>::from_str(s)?)
+            }
+        }
+    };
+}
+
+/// Implements `sqlx::Type`, `Encode` and `Decode` for a derived identifier or an `id_enum!` when
+/// the `sqlx` feature of `mti` is enabled, and expands to nothing otherwise.
+#[cfg(not(feature = "sqlx"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __mti_sqlx_impls {
+    ($($tt:tt)*) => {};
+}
+
+/// Implements `ToSql` and `FromSql` from `rusqlite` for a derived identifier or an `id_enum!` when
+/// the `rusqlite` feature of `mti` is enabled, and expands to nothing otherwise.
+///
+/// A derived identifier is written like a [`TypedId`](crate::prelude::TypedId), as a 16-byte
+/// blob holding the UUID of its suffix, and reads back from such a blob or from text holding a
+/// whole `MagicTypeId`. An `id_enum!`, which needs the prefix to pick a variant, is passed as
+/// `@text` and is written and read as text only. Reading text checks the prefix through
+/// `FromStr`.
+///
+/// Like [`__mti_serde_impls`], the feature check happens here rather than in the procedural macro.
+#[cfg(feature = "rusqlite")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __mti_rusqlite_impls {
+    ($ty:ident) => {
+        impl $crate::__private::rusqlite::types::ToSql for $ty {
+            fn to_sql(
+                &self,
+            ) -> $crate::__private::rusqlite::Result<
+                $crate::__private::rusqlite::types::ToSqlOutput<'_>,
+            > {
+                let id: &$crate::prelude::MagicTypeId = ::core::convert::AsRef::as_ref(self);
+                $crate::__private::rusqlite::types::ToSql::to_sql(id.suffix())
+            }
+        }
+
+        impl $crate::__private::rusqlite::types::FromSql for $ty {
+            fn column_result(
+                value: $crate::__private::rusqlite::types::ValueRef<'_>,
+            ) -> $crate::__private::rusqlite::types::FromSqlResult<Self> {
+                match value {
+                    $crate::__private::rusqlite::types::ValueRef::Text(_) => {
+                        $crate::__mti_rusqlite_impls!(@from_text value)
+                    }
+                    _ => {
+                        let suffix: $crate::prelude::TypeIdSuffix =
+                            $crate::__private::rusqlite::types::FromSql::column_result(value)?;
+                        ::core::result::Result::Ok(Self::from_uuid(suffix.to_uuid()))
+                    }
+                }
+            }
+        }
+    };
+    (@text $ty:ident) => {
+        impl $crate::__private::rusqlite::types::ToSql for $ty {
+            fn to_sql(
+                &self,
+            ) -> $crate::__private::rusqlite::Result<
+                $crate::__private::rusqlite::types::ToSqlOutput<'_>,
+            > {
+                let id: &$crate::prelude::MagicTypeId = ::core::convert::AsRef::as_ref(self);
+                $crate::__private::rusqlite::types::ToSql::to_sql(id)
+            }
+        }
+
+        impl $crate::__private::rusqlite::types::FromSql for $ty {
+            fn column_result(
+                value: $crate::__private::rusqlite::types::ValueRef<'_>,
+            ) -> $crate::__private::rusqlite::types::FromSqlResult<Self> {
+                $crate::__mti_rusqlite_impls!(@from_text value)
+            }
+        }
+    };
+    (@from_text $value:ident) => {
+        <Self as ::core::str::FromStr>::from_str($value.as_str()?).map_err(|e| {
+            $crate::__private::rusqlite::types::FromSqlError::Other(::std::boxed::Box::new(e))
+        })
+    };
+}
+
+/// Implements `ToSql` and `FromSql` from `rusqlite` for a derived identifier or an `id_enum!` when
+/// the `rusqlite` feature of `mti` is enabled, and expands to nothing otherwise.
+#[cfg(not(feature = "rusqlite"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __mti_rusqlite_impls {
+    ($($tt:tt)*) => {};
+}
@@ -1,16 +1,22 @@
 use crate::errors::MagicTypeIdError;
+use crate::lenient::{LenientOptions, LenientParse};
+use crate::parse_options::ParseOptions;
 use std::borrow::Borrow;
 use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
 use std::hash::{Hash, Hasher};
 use std::ops::Deref;
 use std::str::FromStr;
+use std::time::SystemTime;
 use typeid_prefix::{TypeIdPrefix, ValidationError};
 use typeid_suffix::prelude::*;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
+#[cfg(feature = "serde")]
+use crate::serde_support;
+
 #[cfg(feature = "instrument")]
 use tracing::{debug, instrument, trace};
 
@@ -52,15 +58,20 @@ use tracing::{debug, instrument, trace};
 /// # Sorting
 ///
 /// When `MagicTypeId` is created with a `V7` UUID, it provides a natural sorting order:
-/// 1. **Primary Sorting**: By the timestamp in the `UUIDv7` suffix. This means that identifiers
-///    generated later will appear after those generated earlier.
-/// 2. **Secondary Sorting**: If the timestamps are equal, then sorting is based on the lexicographical order
+/// 1. **Primary Sorting**: By the UUID of the suffix, read as a 128-bit integer. For `UUIDv7` this is
+///    the timestamp, so identifiers generated later will appear after those generated earlier.
+/// 2. **Secondary Sorting**: If the suffixes are equal, then sorting is based on the lexicographical order
 ///    of the prefix. This ensures consistent ordering even when identifiers are created at the same time.
 ///
+/// This is a total order for any mix of UUID versions. To order identifiers of different time-based
+/// versions (such as V1 and V7) by their timestamps, use [`MagicTypeId::chronological_cmp`].
+///
+/// `UUIDv7` identifiers created within the same millisecond are not ordered by creation. When that
+/// order matters, create them from a shared [`MonotonicGenerator`](crate::prelude::MonotonicGenerator),
+/// which guarantees strictly increasing suffixes.
+///
 /// ```rust
 /// use std::str::FromStr;
-/// use std::thread::sleep;
-/// use std::time::Duration;
 /// use mti::prelude::*;
 /// use typeid_prefix::prelude::*;
 /// use typeid_suffix::prelude::*;
@@ -68,16 +79,21 @@ use tracing::{debug, instrument, trace};
 /// let prefix1 = TypeIdPrefix::from_str("user").unwrap();
 /// let prefix2 = TypeIdPrefix::from_str("admin").unwrap();
 ///
-/// let id1 = MagicTypeId::new(prefix1.clone(), TypeIdSuffix::new::<V7>());
-/// sleep(Duration::from_millis(10));  // Ensure different timestamps
-/// let id2 = MagicTypeId::new(prefix1.clone(), TypeIdSuffix::new::<V7>());
+/// let generator = MonotonicGenerator::new();
+/// let id1 = MagicTypeId::new(prefix1.clone(), generator.next_suffix());
+/// let id2 = MagicTypeId::new(prefix1.clone(), generator.next_suffix());
 /// let id3 = MagicTypeId::new(prefix2.clone(), TypeIdSuffix::from_str(&id2.suffix().to_string()).unwrap());
 ///
-/// assert!(id1 < id2, "Expected id1 to be less than id2 due to earlier timestamp");
+/// assert!(id1 < id2, "Expected id1 to be less than id2 because it was generated first");
 /// assert_eq!(id2.suffix(), id3.suffix(), "Suffixes for id2 and id3 should be the same");
 /// assert!(id3 < id2, "Expected id3 to be less than id2 due to lexicographically smaller prefix when timestamps are equal");
 /// ```
 #[derive(Default, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow)
+)]
+#[cfg_attr(feature = "diesel", diesel(sql_type = diesel::sql_types::Text))]
 pub struct MagicTypeId {
     prefix: TypeIdPrefix,
     suffix: TypeIdSuffix,
@@ -256,6 +272,353 @@ impl MagicTypeId {
     pub const fn as_str(&self) -> &str {
         self.string_repr.as_str()
     }
+
+    /// Returns the version of the UUID in the suffix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mti::prelude::*;
+    ///
+    /// let id = "user".create_type_id::<V7>();
+    /// assert_eq!(id.version(), SuffixVersion::V7);
+    /// ```
+    #[must_use]
+    pub const fn version(&self) -> SuffixVersion {
+        self.suffix.version()
+    }
+
+    /// Returns the variant of the UUID in the suffix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mti::prelude::*;
+    ///
+    /// let id = "user".create_type_id::<V4>();
+    /// assert_eq!(id.variant(), Variant::RFC4122);
+    /// ```
+    #[must_use]
+    pub const fn variant(&self) -> Variant {
+        self.suffix.variant()
+    }
+
+    /// Returns whether the UUID in the suffix is of version `V`.
+    ///
+    /// This lets an API reject identifiers minted with the wrong generator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use mti::prelude::*;
+    ///
+    /// fn accept_order_id(id: &MagicTypeId) -> Result<(), String> {
+    ///     if id.is_version::<V7>() {
+    ///         Ok(())
+    ///     } else {
+    ///         Err(format!("expected a UUIDv7 order ID, found {}", id.version()))
+    ///     }
+    /// }
+    ///
+    /// assert!(accept_order_id(&"order".create_type_id::<V7>()).is_ok());
+    /// assert_eq!(
+    ///     accept_order_id(&"order".create_type_id::<V4>()),
+    ///     Err("expected a UUIDv7 order ID, found v4".to_string())
+    /// );
+    /// ```
+    #[must_use]
+    pub fn is_version<V: UuidVersion>(&self) -> bool {
+        self.suffix.is_version::<V>()
+    }
+
+    /// Returns whether the suffix carries a creation timestamp, that is, whether it holds a V1,
+    /// V6 or V7 UUID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mti::prelude::*;
+    ///
+    /// assert!("user".create_type_id::<V6>().is_time_based());
+    /// assert!(!"user".create_type_id::<V4>().is_time_based());
+    /// ```
+    #[must_use]
+    pub const fn is_time_based(&self) -> bool {
+        self.suffix.is_time_based()
+    }
+
+    /// Returns whether the identifier sorts by creation time, that is, whether its suffix holds a
+    /// V6 or V7 UUID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mti::prelude::*;
+    ///
+    /// assert!("user".create_type_id::<V7>().is_sortable());
+    /// assert!(!"user".create_type_id::<V4>().is_sortable());
+    /// ```
+    #[must_use]
+    pub const fn is_sortable(&self) -> bool {
+        self.suffix.is_sortable()
+    }
+
+    /// Returns the time at which a time-based identifier was created.
+    ///
+    /// See [`TypeIdSuffix::timestamp`] for how V1, V6 and V7 timestamps are read.
+    ///
+    /// # Returns
+    ///
+    /// `None` if the suffix is not a V1, V6 or V7 UUID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use std::time::{Duration, UNIX_EPOCH};
+    /// use mti::prelude::*;
+    ///
+    /// let id = MagicTypeId::from_str("user_01h455vb4pex5vsknk084sn02q").unwrap();
+    /// assert_eq!(id.timestamp(), Some(UNIX_EPOCH + Duration::from_millis(1_688_096_058_518)));
+    ///
+    /// assert_eq!("user".create_type_id::<V4>().timestamp(), None);
+    /// ```
+    ///
+    /// [`TypeIdSuffix::timestamp`]: crate::prelude::TypeIdSuffix::timestamp
+    #[must_use]
+    pub fn timestamp(&self) -> Option<SystemTime> {
+        self.suffix.timestamp()
+    }
+
+    /// Returns the creation time of a time-based identifier in milliseconds since the Unix epoch.
+    ///
+    /// # Returns
+    ///
+    /// `None` if the suffix is not a V1, V6 or V7 UUID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use mti::prelude::*;
+    ///
+    /// let id = MagicTypeId::from_str("user_01h455vb4pex5vsknk084sn02q").unwrap();
+    /// assert_eq!(id.timestamp_millis(), Some(1_688_096_058_518));
+    /// ```
+    #[must_use]
+    pub fn timestamp_millis(&self) -> Option<i64> {
+        self.suffix.timestamp_millis()
+    }
+
+    /// Returns the creation time of a time-based identifier in 100-nanosecond intervals since the
+    /// Unix epoch.
+    ///
+    /// # Returns
+    ///
+    /// `None` if the suffix is not a V1, V6 or V7 UUID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use mti::prelude::*;
+    ///
+    /// let id = MagicTypeId::from_str("user_01h455vb4pex5vsknk084sn02q").unwrap();
+    /// assert_eq!(id.timestamp_100ns(), Some(16_880_960_585_180_000));
+    /// ```
+    #[must_use]
+    pub fn timestamp_100ns(&self) -> Option<i64> {
+        self.suffix.timestamp_100ns()
+    }
+
+    /// Returns the smallest `UUIDv7` identifier with `prefix` for the millisecond containing `time`.
+    ///
+    /// Together with [`MagicTypeId::max_for_time`], this builds the bounds of an index range scan:
+    /// every `UUIDv7` identifier with the same prefix created between two times sorts between
+    /// `min_for_time(prefix, start)` and `max_for_time(prefix, end)`, both as a `MagicTypeId` and as
+    /// a string. See [`TypeIdSuffix::min_for_time`] for how out-of-range times are handled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use std::time::{Duration, SystemTime};
+    /// use mti::prelude::*;
+    ///
+    /// let prefix = TypeIdPrefix::from_str("order").unwrap();
+    /// let start = SystemTime::now() - Duration::from_secs(3600);
+    /// let order_id = "order".create_type_id::<V7>();
+    /// let end = SystemTime::now();
+    ///
+    /// // e.g. `WHERE id BETWEEN $1 AND $2`
+    /// let lower = MagicTypeId::min_for_time(prefix.clone(), start);
+    /// let upper = MagicTypeId::max_for_time(prefix, end);
+    /// assert!(lower.as_str() <= order_id.as_str() && order_id.as_str() <= upper.as_str());
+    /// ```
+    ///
+    /// [`TypeIdSuffix::min_for_time`]: crate::prelude::TypeIdSuffix::min_for_time
+    #[must_use]
+    pub fn min_for_time(prefix: TypeIdPrefix, time: SystemTime) -> Self {
+        Self::new(prefix, TypeIdSuffix::min_for_time(time))
+    }
+
+    /// Returns the largest `UUIDv7` identifier with `prefix` for the millisecond containing `time`.
+    ///
+    /// See [`MagicTypeId::min_for_time`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use std::time::{Duration, UNIX_EPOCH};
+    /// use mti::prelude::*;
+    ///
+    /// let prefix = TypeIdPrefix::from_str("order").unwrap();
+    /// let time = UNIX_EPOCH + Duration::from_millis(1_688_096_058_518);
+    ///
+    /// let upper = MagicTypeId::max_for_time(prefix, time);
+    /// assert_eq!(upper.as_str(), "order_01h455vb4pfzzvzzzzzzzzzzzz");
+    /// assert!(upper > MagicTypeId::from_str("order_01h455vb4pex5vsknk084sn02q").unwrap());
+    /// ```
+    #[must_use]
+    pub fn max_for_time(prefix: TypeIdPrefix, time: SystemTime) -> Self {
+        Self::new(prefix, TypeIdSuffix::max_for_time(time))
+    }
+
+    /// Compares two identifiers by the time embedded in their suffixes, then by prefix.
+    ///
+    /// This is the time-aware counterpart of [`Ord`]: it orders V1, V6 and V7 identifiers by
+    /// their timestamps even when their UUID versions differ. See
+    /// [`TypeIdSuffix::chronological_cmp`] for details.
+    ///
+    /// # Returns
+    ///
+    /// `None` if either identifier does not contain a time-based UUID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use mti::prelude::*;
+    ///
+    /// let earlier = "user".create_type_id::<V1>();
+    /// std::thread::sleep(std::time::Duration::from_millis(2));
+    /// let later = "user".create_type_id::<V7>();
+    ///
+    /// assert_eq!(earlier.chronological_cmp(&later), Some(Ordering::Less));
+    /// assert_eq!(earlier.chronological_cmp(&"user".create_type_id::<V4>()), None);
+    /// ```
+    ///
+    /// [`TypeIdSuffix::chronological_cmp`]: crate::prelude::TypeIdSuffix::chronological_cmp
+    #[must_use]
+    pub fn chronological_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.suffix
+            .chronological_cmp(&other.suffix)
+            .map(|ordering| ordering.then_with(|| self.prefix.cmp(&other.prefix)))
+    }
+
+    /// Parses a string into a `MagicTypeId` and checks it against `options`.
+    ///
+    /// The string is first parsed like [`MagicTypeId::from_str`](FromStr::from_str), then every
+    /// rule of `options` is applied. See [`ParseOptions`] for the available rules.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`MagicTypeId::from_str`](FromStr::from_str) if the string is not a
+    /// valid identifier, and the error variant of the first rule of `options` that it breaks
+    /// otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mti::prelude::*;
+    ///
+    /// let options = ParseOptions::strict()
+    ///     .require_prefix("order")
+    ///     .allowed_versions([SuffixVersion::V7]);
+    ///
+    /// let order_id = MagicTypeId::parse_with("order_01h455vb4pex5vsknk084sn02q", &options).unwrap();
+    /// assert_eq!(order_id.prefix().as_str(), "order");
+    ///
+    /// let v4_id = "order".create_type_id::<V4>();
+    /// assert_eq!(
+    ///     MagicTypeId::parse_with(&v4_id, &options),
+    ///     Err(MagicTypeIdError::VersionNotAllowed { found: SuffixVersion::V4 })
+    /// );
+    /// ```
+    #[cfg_attr(
+        feature = "instrument",
+        instrument(level = "debug", skip(options), fields(input = %s))
+    )]
+    pub fn parse_with(s: &str, options: &ParseOptions) -> Result<Self, MagicTypeIdError> {
+        options.validate(Self::from_str(s)?)
+    }
+
+    /// Parses an identifier typed or pasted by a human, repairing common mistakes first.
+    ///
+    /// The input is normalized as described in [`LenientOptions`] and then parsed strictly, like
+    /// [`MagicTypeId::from_str`](FromStr::from_str). Only the suffix is checked for look-alike
+    /// characters, because `i`, `l` and `o` are valid in prefixes.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`MagicTypeId::from_str`](FromStr::from_str) if the normalized input
+    /// is still not a valid identifier.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mti::prelude::*;
+    ///
+    /// let parsed = MagicTypeId::parse_lenient("USER_O1H455VB4PEX5VSKNK084SN02Q", &LenientOptions::new()).unwrap();
+    /// assert_eq!(parsed.id().as_str(), "user_01h455vb4pex5vsknk084sn02q");
+    /// assert_eq!(
+    ///     parsed.corrections()[4],
+    ///     Correction::ReplacedLookAlike { position: 5, found: 'O', replacement: '0' }
+    /// );
+    /// assert!(parsed.was_corrected());
+    ///
+    /// // Canonical input needs no corrections
+    /// let exact = MagicTypeId::parse_lenient("user_01h455vb4pex5vsknk084sn02q", &LenientOptions::new()).unwrap();
+    /// assert!(!exact.was_corrected());
+    /// ```
+    #[cfg_attr(
+        feature = "instrument",
+        instrument(level = "debug", skip(options), fields(input = %input))
+    )]
+    pub fn parse_lenient(
+        input: &str,
+        options: &LenientOptions,
+    ) -> Result<LenientParse, MagicTypeIdError> {
+        let (canonical, corrections) = options.normalize(input);
+
+        #[cfg(feature = "instrument")]
+        debug!(
+            "Normalized '{}' to '{}' with {} corrections",
+            input,
+            canonical,
+            corrections.len()
+        );
+        Ok(LenientParse::new(Self::from_str(&canonical)?, corrections))
+    }
+
+    /// Checks that the prefix equals `expected`, returning the identifier unchanged if it does.
+    pub(crate) fn ensure_prefix(self, expected: &str) -> Result<Self, MagicTypeIdError> {
+        if self.prefix.as_str() == expected {
+            Ok(self)
+        } else {
+            #[cfg(feature = "instrument")]
+            debug!(
+                "Prefix mismatch: expected '{}', found '{}'",
+                expected, self.prefix
+            );
+            Err(MagicTypeIdError::PrefixMismatch {
+                expected: expected.to_string(),
+                found: self.prefix.to_string(),
+            })
+        }
+    }
 }
 
 impl Display for MagicTypeId {
@@ -374,12 +737,21 @@ impl PartialEq<MagicTypeId> for &str {
 
 #[cfg(feature = "serde")]
 impl Serialize for MagicTypeId {
+    /// Serializes the `MagicTypeId` as a string in human-readable formats, and as its prefix
+    /// followed by the 16 bytes of its UUID in binary formats.
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        // Serialize the MagicTypeId as a string
-        serializer.serialize_str(&self.string_repr)
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.string_repr)
+        } else {
+            serde_support::serialize_compact(
+                serializer,
+                self.prefix.as_str(),
+                self.suffix.to_uuid(),
+            )
+        }
     }
 }
 
@@ -389,8 +761,112 @@ impl<'de> Deserialize<'de> for MagicTypeId {
     where
         D: Deserializer<'de>,
     {
+        if !deserializer.is_human_readable() {
+            return serde_support::deserialize_compact(deserializer, |prefix, uuid| {
+                let prefix = if prefix.is_empty() {
+                    TypeIdPrefix::default()
+                } else {
+                    TypeIdPrefix::from_str(prefix)?
+                };
+                Ok(Self::new(prefix, TypeIdSuffix::from(uuid)))
+            });
+        }
         // Deserialize the string into a MagicTypeId
         let s = String::deserialize(deserializer)?;
         Self::from_str(&s).map_err(serde::de::Error::custom)
     }
 }
+
+#[cfg(feature = "sqlx")]
+impl<DB: sqlx::Database> sqlx::Type<DB> for MagicTypeId
+where
+    str: sqlx::Type<DB>,
+{
+    /// A `MagicTypeId` is stored as text.
+    fn type_info() -> DB::TypeInfo {
+        <str as sqlx::Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <str as sqlx::Type<DB>>::compatible(ty)
+    }
+}
+
+#[cfg(feature = "sqlx")]
+impl<'q, DB: sqlx::Database> sqlx::Encode<'q, DB> for MagicTypeId
+where
+    String: sqlx::Encode<'q, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as sqlx::Database>::ArgumentBuffer<'q>,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        <String as sqlx::Encode<'q, DB>>::encode(self.string_repr.clone(), buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        self.string_repr.len()
+    }
+}
+
+#[cfg(feature = "sqlx")]
+impl<'r, DB: sqlx::Database> sqlx::Decode<'r, DB> for MagicTypeId
+where
+    &'r str: sqlx::Decode<'r, DB>,
+{
+    /// Decodes a `MagicTypeId` from text, validating it like [`FromStr`].
+    fn decode(
+        value: <DB as sqlx::Database>::ValueRef<'r>,
+    ) -> Result<Self, sqlx::error::BoxDynError> {
+        let s = <&str as sqlx::Decode<'r, DB>>::decode(value)?;
+        Ok(Self::from_str(s)?)
+    }
+}
+
+/// A `MagicTypeId` is stored as text.
+#[cfg(feature = "diesel")]
+impl<DB: diesel::backend::Backend> diesel::serialize::ToSql<diesel::sql_types::Text, DB>
+    for MagicTypeId
+where
+    str: diesel::serialize::ToSql<diesel::sql_types::Text, DB>,
+{
+    fn to_sql<'b>(
+        &'b self,
+        out: &mut diesel::serialize::Output<'b, '_, DB>,
+    ) -> diesel::serialize::Result {
+        self.string_repr.as_str().to_sql(out)
+    }
+}
+
+#[cfg(feature = "diesel")]
+impl<DB: diesel::backend::Backend> diesel::deserialize::FromSql<diesel::sql_types::Text, DB>
+    for MagicTypeId
+where
+    String: diesel::deserialize::FromSql<diesel::sql_types::Text, DB>,
+{
+    /// Loads a `MagicTypeId` from text, validating it like [`FromStr`].
+    fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+        let s = String::from_sql(bytes)?;
+        Ok(Self::from_str(&s)?)
+    }
+}
+
+/// A `MagicTypeId` is stored as text.
+///
+/// To store only the 16 bytes of the UUID, bind [`suffix()`](MagicTypeId::suffix) or use a
+/// [`TypedId`](crate::prelude::TypedId), which adds the prefix back when read.
+#[cfg(feature = "rusqlite")]
+impl rusqlite::types::ToSql for MagicTypeId {
+    fn to_sql(&self) -> rusqlite::Result<rusqlite::types::ToSqlOutput<'_>> {
+        Ok(self.string_repr.as_str().into())
+    }
+}
+
+#[cfg(feature = "rusqlite")]
+impl rusqlite::types::FromSql for MagicTypeId {
+    /// Reads a `MagicTypeId` from text, failing with the [`MagicTypeIdError`] if it is invalid.
+    fn column_result(value: rusqlite::types::ValueRef<'_>) -> rusqlite::types::FromSqlResult<Self> {
+        Self::from_str(value.as_str()?)
+            .map_err(|e| rusqlite::types::FromSqlError::Other(Box::new(e)))
+    }
+}
@@ -1,10 +1,12 @@
 use std::str::FromStr;
+use std::time::SystemTime;
 
 use typeid_prefix::prelude::*;
 use typeid_suffix::prelude::*;
 
 use crate::errors::MagicTypeIdError;
 use crate::magic_type_id::MagicTypeId;
+use crate::magic_type_id_ref::MagicTypeIdRef;
 
 #[cfg(feature = "instrument")]
 use tracing::{debug, instrument, trace, warn};
@@ -199,6 +201,39 @@ pub trait MagicTypeIdExt {
     /// ```
     fn uuid(&self) -> Result<Uuid, MagicTypeIdError>;
 
+    /// Extracts the creation time from the suffix of a `TypeID`.
+    ///
+    /// This method validates the suffix like [`suffix`](MagicTypeIdExt::suffix) and reads the
+    /// timestamp of a V1, V6 or V7 UUID, converting Gregorian timestamps to Unix time.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Some(SystemTime))` if the suffix contains a time-based UUID.
+    /// - `Ok(None)` if the suffix is valid but its UUID carries no timestamp.
+    /// - `Err(MagicTypeIdError)` if the suffix is invalid.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The suffix is not a valid base32-encoded UUID.
+    /// - The `TypeID` string is empty or improperly formatted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::{Duration, UNIX_EPOCH};
+    /// use mti::prelude::*;
+    ///
+    /// let created = "user_01h455vb4pex5vsknk084sn02q".timestamp().unwrap();
+    /// assert_eq!(created, Some(UNIX_EPOCH + Duration::from_millis(1_688_096_058_518)));
+    ///
+    /// let v4_id = "user".create_type_id::<V4>();
+    /// assert_eq!(v4_id.as_str().timestamp().unwrap(), None);
+    ///
+    /// assert!("user_invalid".timestamp().is_err());
+    /// ```
+    fn timestamp(&self) -> Result<Option<SystemTime>, MagicTypeIdError>;
+
     /// Creates a new `MagicTypeId` with the string as prefix and a new UUID of the specified version.
     ///
     /// This method sanitizes the input string to ensure a valid prefix is created. The sanitization process:
@@ -341,6 +376,143 @@ pub trait MagicTypeIdExt {
         suffix: TypeIdSuffix,
     ) -> Result<MagicTypeId, MagicTypeIdError>;
 
+    /// Creates a `MagicTypeId` with the string as prefix and the next `UUIDv7` from `generator`.
+    ///
+    /// This method sanitizes the input string exactly like [`create_type_id`](MagicTypeIdExt::create_type_id).
+    /// Identifiers created from the same [`MonotonicGenerator`] are strictly increasing, even
+    /// within the same millisecond or when the system clock moves backwards.
+    ///
+    /// # Arguments
+    ///
+    /// * `generator` - The generator that provides the suffix.
+    ///
+    /// # Returns
+    ///
+    /// A new `MagicTypeId` with a sanitized prefix and a `UUIDv7` suffix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mti::prelude::*;
+    ///
+    /// static IDS: MonotonicGenerator = MonotonicGenerator::new();
+    ///
+    /// let first = "event".create_type_id_monotonic(&IDS);
+    /// let second = "event".create_type_id_monotonic(&IDS);
+    /// assert!(first < second);
+    /// ```
+    fn create_type_id_monotonic<S: IdSource>(
+        &self,
+        generator: &MonotonicGenerator<S>,
+    ) -> MagicTypeId;
+
+    /// Attempts to create a `MagicTypeId` with the string as prefix and the next `UUIDv7` from
+    /// `generator`.
+    ///
+    /// This method does not sanitize the input, so it will fail if the prefix is invalid.
+    ///
+    /// # Arguments
+    ///
+    /// * `generator` - The generator that provides the suffix.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either the new `MagicTypeId` or a `MagicTypeIdError`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the prefix is invalid. The generator is not
+    /// advanced in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mti::prelude::*;
+    ///
+    /// let generator = MonotonicGenerator::new();
+    ///
+    /// let id = "event".try_create_type_id_monotonic(&generator).unwrap();
+    /// assert!(id.to_string().starts_with("event_"));
+    ///
+    /// assert!("Invalid Prefix!".try_create_type_id_monotonic(&generator).is_err());
+    /// ```
+    fn try_create_type_id_monotonic<S: IdSource>(
+        &self,
+        generator: &MonotonicGenerator<S>,
+    ) -> Result<MagicTypeId, MagicTypeIdError>;
+
+    /// Creates a `MagicTypeId` with the string as prefix and a new UUID of the specified version,
+    /// generated from `source`.
+    ///
+    /// This method sanitizes the input string exactly like [`create_type_id`](MagicTypeIdExt::create_type_id),
+    /// but reads the clock and the random bits from `source` instead of the system. With a
+    /// [`SeededIdSource`], the same source configuration always produces the same identifiers,
+    /// which makes generated IDs usable in snapshot tests.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `V`: A UUID version that implements `FromIdSource`.
+    /// * `S`: The type of the source.
+    ///
+    /// # Returns
+    ///
+    /// A new `MagicTypeId` instance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::{Duration, UNIX_EPOCH};
+    /// use mti::prelude::*;
+    ///
+    /// let source = || {
+    ///     SeededIdSource::new(42).with_start_time(UNIX_EPOCH + Duration::from_secs(1_700_000_000))
+    /// };
+    ///
+    /// let id = "user".create_type_id_with_source::<V7, _>(&source());
+    /// assert_eq!(id, "user".create_type_id_with_source::<V7, _>(&source()));
+    /// assert!(id.to_string().starts_with("user_01hf"));
+    /// ```
+    fn create_type_id_with_source<V: FromIdSource, S: IdSource + ?Sized>(
+        &self,
+        source: &S,
+    ) -> MagicTypeId;
+
+    /// Attempts to create a `MagicTypeId` with the string as prefix and a new UUID of the specified
+    /// version, generated from `source`.
+    ///
+    /// This method does not sanitize the input, so it will fail if the prefix is invalid.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `V`: A UUID version that implements `FromIdSource`.
+    /// * `S`: The type of the source.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either the new `MagicTypeId` or a `MagicTypeIdError`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the prefix is invalid. Nothing is read from the
+    /// source in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mti::prelude::*;
+    ///
+    /// let source = SeededIdSource::new(42);
+    ///
+    /// let id = "user".try_create_type_id_with_source::<V4, _>(&source).unwrap();
+    /// assert!(id.to_string().starts_with("user_"));
+    ///
+    /// assert!("Invalid Prefix!".try_create_type_id_with_source::<V4, _>(&source).is_err());
+    /// ```
+    fn try_create_type_id_with_source<V: FromIdSource, S: IdSource + ?Sized>(
+        &self,
+        source: &S,
+    ) -> Result<MagicTypeId, MagicTypeIdError>;
+
     /// Creates a `MagicTypeId` with a V3 UUID (MD5-based name hash).
     ///
     /// This method sanitizes the prefix and creates a deterministic type ID
@@ -469,7 +641,7 @@ impl MagicTypeIdExt for str {
     fn prefix_str(&self) -> Result<String, MagicTypeIdError> {
         #[cfg(feature = "instrument")]
         trace!("Extracting prefix string from TypeID");
-        let result = self.prefix().map(|p| p.to_string());
+        let result = borrowed_prefix(self).map(ToOwned::to_owned);
 
         #[cfg(feature = "instrument")]
         match &result {
@@ -484,7 +656,7 @@ impl MagicTypeIdExt for str {
     fn suffix_str(&self) -> Result<String, MagicTypeIdError> {
         #[cfg(feature = "instrument")]
         trace!("Extracting suffix string from TypeID");
-        let result = self.suffix().map(|s| s.to_string());
+        let result = borrowed_suffix(self).map(|(suffix, _)| suffix.to_owned());
 
         #[cfg(feature = "instrument")]
         match &result {
@@ -515,29 +687,24 @@ impl MagicTypeIdExt for str {
         #[cfg(feature = "instrument")]
         trace!("Extracting TypeIdPrefix from TypeID");
 
-        if self.is_empty() {
-            #[cfg(feature = "instrument")]
-            debug!("Input is empty, returning default TypeIdPrefix");
-            return Ok(TypeIdPrefix::default());
-        }
-
-        if let Some((prefix, _)) = self.rsplit_once('_') {
-            #[cfg(feature = "instrument")]
-            trace!("Found prefix part: '{}'", prefix);
-            let result = TypeIdPrefix::try_from(prefix).map_err(MagicTypeIdError::Prefix);
-
-            #[cfg(feature = "instrument")]
-            match &result {
-                Ok(prefix) => debug!("Successfully created TypeIdPrefix: '{}'", prefix),
-                Err(err) => warn!("Failed to create TypeIdPrefix: {}", err),
+        let result = borrowed_prefix(self).map(|prefix| {
+            if prefix.is_empty() {
+                #[cfg(feature = "instrument")]
+                debug!("No prefix found, returning default TypeIdPrefix");
+                TypeIdPrefix::default()
+            } else {
+                // `borrowed_prefix` has already validated the prefix.
+                TypeIdPrefix::from_str(prefix).unwrap_or_default()
             }
+        });
 
-            result
-        } else {
-            #[cfg(feature = "instrument")]
-            debug!("No prefix separator found, returning default TypeIdPrefix");
-            Ok(TypeIdPrefix::default())
+        #[cfg(feature = "instrument")]
+        match &result {
+            Ok(prefix) => debug!("Successfully created TypeIdPrefix: '{}'", prefix),
+            Err(err) => warn!("Failed to create TypeIdPrefix: {}", err),
         }
+
+        result
     }
 
     #[cfg_attr(feature = "instrument", instrument(level = "debug", skip(self), fields(input = %self)))]
@@ -545,19 +712,7 @@ impl MagicTypeIdExt for str {
         #[cfg(feature = "instrument")]
         trace!("Extracting TypeIdSuffix from TypeID");
 
-        if self.is_empty() {
-            #[cfg(feature = "instrument")]
-            warn!("Input is empty, returning InvalidSuffix error");
-            return Err(MagicTypeIdError::Suffix(DecodeError::InvalidSuffix(
-                InvalidSuffixReason::InvalidLength,
-            )));
-        }
-
-        let suffix_str = self.rsplit_once('_').map_or(self, |(_, suffix)| suffix);
-        #[cfg(feature = "instrument")]
-        trace!("Found suffix part: '{}'", suffix_str);
-
-        let result = TypeIdSuffix::from_str(suffix_str).map_err(MagicTypeIdError::Suffix);
+        let result = borrowed_suffix(self).map(|(_, suffix)| suffix);
 
         #[cfg(feature = "instrument")]
         match &result {
@@ -584,6 +739,22 @@ impl MagicTypeIdExt for str {
         result
     }
 
+    #[cfg_attr(feature = "instrument", instrument(level = "debug", skip(self), fields(input = %self)))]
+    fn timestamp(&self) -> Result<Option<SystemTime>, MagicTypeIdError> {
+        #[cfg(feature = "instrument")]
+        trace!("Extracting timestamp from TypeID");
+
+        let result = self.suffix().map(|suffix| suffix.timestamp());
+
+        #[cfg(feature = "instrument")]
+        match &result {
+            Ok(timestamp) => debug!("Extracted timestamp: {:?}", timestamp),
+            Err(err) => warn!("Failed to extract timestamp: {}", err),
+        }
+
+        result
+    }
+
     #[cfg_attr(feature = "instrument", instrument(level = "debug", skip(self), fields(input = %self, uuid_version = std::any::type_name::<V>())))]
     fn create_type_id<V: UuidVersion + Default>(&self) -> MagicTypeId {
         #[cfg(feature = "instrument")]
@@ -648,6 +819,82 @@ impl MagicTypeIdExt for str {
         Ok(MagicTypeId::new(prefix, suffix))
     }
 
+    #[cfg_attr(feature = "instrument", instrument(level = "debug", skip(self, generator), fields(input = %self)))]
+    fn create_type_id_monotonic<S: IdSource>(
+        &self,
+        generator: &MonotonicGenerator<S>,
+    ) -> MagicTypeId {
+        #[cfg(feature = "instrument")]
+        trace!("Creating MagicTypeId with sanitized prefix from a monotonic generator");
+
+        let prefix = self.create_prefix_sanitized();
+        #[cfg(feature = "instrument")]
+        debug!("Sanitized prefix: '{}'", prefix);
+
+        let suffix = generator.next_suffix();
+        #[cfg(feature = "instrument")]
+        debug!("Created monotonic TypeIdSuffix: '{}'", suffix);
+
+        MagicTypeId::new(prefix, suffix)
+    }
+
+    #[cfg_attr(feature = "instrument", instrument(level = "debug", skip(self, generator), fields(input = %self)))]
+    fn try_create_type_id_monotonic<S: IdSource>(
+        &self,
+        generator: &MonotonicGenerator<S>,
+    ) -> Result<MagicTypeId, MagicTypeIdError> {
+        #[cfg(feature = "instrument")]
+        trace!("Attempting to create MagicTypeId with validated prefix from a monotonic generator");
+
+        let prefix = TypeIdPrefix::try_from(self)?;
+        #[cfg(feature = "instrument")]
+        debug!("Successfully validated prefix: '{}'", prefix);
+
+        let suffix = generator.next_suffix();
+        #[cfg(feature = "instrument")]
+        debug!("Created monotonic TypeIdSuffix: '{}'", suffix);
+
+        Ok(MagicTypeId::new(prefix, suffix))
+    }
+
+    #[cfg_attr(feature = "instrument", instrument(level = "debug", skip(self, source), fields(input = %self, uuid_version = std::any::type_name::<V>())))]
+    fn create_type_id_with_source<V: FromIdSource, S: IdSource + ?Sized>(
+        &self,
+        source: &S,
+    ) -> MagicTypeId {
+        #[cfg(feature = "instrument")]
+        trace!("Creating MagicTypeId with sanitized prefix from an IdSource");
+
+        let prefix = self.create_prefix_sanitized();
+        #[cfg(feature = "instrument")]
+        debug!("Sanitized prefix: '{}'", prefix);
+
+        let suffix = TypeIdSuffix::new_with_source::<V, S>(source);
+        #[cfg(feature = "instrument")]
+        debug!("Created new TypeIdSuffix: '{}'", suffix);
+
+        MagicTypeId::new(prefix, suffix)
+    }
+
+    #[cfg_attr(feature = "instrument", instrument(level = "debug", skip(self, source), fields(input = %self, uuid_version = std::any::type_name::<V>())))]
+    fn try_create_type_id_with_source<V: FromIdSource, S: IdSource + ?Sized>(
+        &self,
+        source: &S,
+    ) -> Result<MagicTypeId, MagicTypeIdError> {
+        #[cfg(feature = "instrument")]
+        trace!("Attempting to create MagicTypeId with validated prefix from an IdSource");
+
+        let prefix = TypeIdPrefix::try_from(self)?;
+        #[cfg(feature = "instrument")]
+        debug!("Successfully validated prefix: '{}'", prefix);
+
+        let suffix = TypeIdSuffix::new_with_source::<V, S>(source);
+        #[cfg(feature = "instrument")]
+        debug!("Created new TypeIdSuffix: '{}'", suffix);
+
+        Ok(MagicTypeId::new(prefix, suffix))
+    }
+
     #[cfg_attr(feature = "instrument", instrument(level = "debug", skip(self, name), fields(input = %self, namespace = %namespace)))]
     fn create_type_id_v3(&self, namespace: NamespaceId, name: &[u8]) -> MagicTypeId {
         #[cfg(feature = "instrument")]
@@ -721,6 +968,38 @@ impl MagicTypeIdExt for str {
     }
 }
 
+/// Extracts and validates the prefix of a `TypeID` string without copying it.
+///
+/// Returns an empty prefix if `input` has no prefix separator. Only the prefix is validated.
+fn borrowed_prefix(input: &str) -> Result<&str, MagicTypeIdError> {
+    match MagicTypeIdRef::split(input) {
+        (Some(prefix), _) => {
+            #[cfg(feature = "instrument")]
+            trace!("Found prefix part: '{}'", prefix);
+            TypeIdPrefix::check(prefix)?;
+            Ok(prefix)
+        }
+        (None, _) => Ok(""),
+    }
+}
+
+/// Extracts and validates the suffix of a `TypeID` string, returning both its borrowed text and
+/// its decoded form. Only the suffix is validated.
+fn borrowed_suffix(input: &str) -> Result<(&str, TypeIdSuffix), MagicTypeIdError> {
+    if input.is_empty() {
+        #[cfg(feature = "instrument")]
+        warn!("Input is empty, returning InvalidSuffix error");
+        return Err(MagicTypeIdError::Suffix(DecodeError::InvalidSuffix(
+            InvalidSuffixReason::InvalidLength,
+        )));
+    }
+
+    let (_, suffix) = MagicTypeIdRef::split(input);
+    #[cfg(feature = "instrument")]
+    trace!("Found suffix part: '{}'", suffix);
+    Ok((suffix, TypeIdSuffix::from_str(suffix)?))
+}
+
 #[cfg(test)]
 mod ext_tests {
     #[test]
@@ -0,0 +1,258 @@
+//! Zero-copy, borrowed identifiers.
+//!
+//! This module provides [`MagicTypeIdRef`], a view of a `TypeID` string that is validated in place.
+//! It exposes the same components as [`MagicTypeId`] without copying the input, which makes it
+//! well suited to checking identifiers embedded in request bodies or log lines.
+
+use std::cmp::Ordering;
+use std::fmt::{self, Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+use typeid_prefix::prelude::*;
+use typeid_suffix::prelude::*;
+
+use crate::errors::MagicTypeIdError;
+use crate::magic_type_id::MagicTypeId;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[cfg(feature = "instrument")]
+use tracing::{debug, instrument};
+
+/// A borrowed, validated `TypeID` string.
+///
+/// `MagicTypeIdRef` checks its input with exactly the same rules as [`MagicTypeId::from_str`],
+/// but keeps borrowing it instead of allocating a prefix and a copy of the string. Use
+/// [`to_owned`](MagicTypeIdRef::to_owned) to turn it into a `MagicTypeId` once you need to keep it.
+///
+/// With the `serde` feature, it can be deserialized by borrowing from the input through
+/// `#[serde(borrow)]`.
+///
+/// # Examples
+///
+/// ```
+/// use mti::prelude::*;
+///
+/// let line = "user_01h455vb4pex5vsknk084sn02q logged in";
+/// let (raw_id, _) = line.split_once(' ').unwrap();
+///
+/// let id = MagicTypeIdRef::parse(raw_id).unwrap();
+/// assert_eq!(id.prefix(), "user");
+/// assert_eq!(id.suffix_str(), "01h455vb4pex5vsknk084sn02q");
+///
+/// let owned: MagicTypeId = id.to_owned();
+/// assert_eq!(owned.as_str(), raw_id);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct MagicTypeIdRef<'a> {
+    input: &'a str,
+    prefix: &'a str,
+    suffix: &'a str,
+    uuid: Uuid,
+}
+
+impl<'a> MagicTypeIdRef<'a> {
+    /// Parses and validates `input` without copying it.
+    ///
+    /// The input must follow the same format as for `MagicTypeId`: "`prefix_suffix`", or just
+    /// "suffix" if there is no prefix.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `MagicTypeIdError` if the prefix or the suffix is invalid, exactly as
+    /// [`MagicTypeId::from_str`] would.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mti::prelude::*;
+    ///
+    /// assert!(MagicTypeIdRef::parse("user_01h455vb4pex5vsknk084sn02q").is_ok());
+    /// assert!(MagicTypeIdRef::parse("User_01h455vb4pex5vsknk084sn02q").is_err());
+    /// ```
+    #[cfg_attr(feature = "instrument", instrument(level = "debug", fields(input = %input)))]
+    pub fn parse(input: &'a str) -> Result<Self, MagicTypeIdError> {
+        let (prefix, suffix) = match Self::split(input) {
+            (Some(""), _) => {
+                #[cfg(feature = "instrument")]
+                debug!("Empty prefix found, returning error");
+                return Err(MagicTypeIdError::Prefix(
+                    ValidationError::InvalidStartCharacter,
+                ));
+            }
+            (Some(prefix), suffix) => {
+                TypeIdPrefix::check(prefix)?;
+                (prefix, suffix)
+            }
+            (None, suffix) => ("", suffix),
+        };
+        let uuid = TypeIdSuffix::from_str(suffix)?.to_uuid();
+
+        Ok(Self {
+            input,
+            prefix,
+            suffix,
+            uuid,
+        })
+    }
+
+    /// Splits `input` on its last underscore into an optional prefix and a suffix, without
+    /// validating either part.
+    pub(crate) fn split(input: &'a str) -> (Option<&'a str>, &'a str) {
+        input
+            .rsplit_once('_')
+            .map_or((None, input), |(prefix, suffix)| (Some(prefix), suffix))
+    }
+
+    /// Returns the prefix, or an empty string if the identifier has no prefix.
+    #[must_use]
+    pub const fn prefix(&self) -> &'a str {
+        self.prefix
+    }
+
+    /// Returns the suffix.
+    #[must_use]
+    pub fn suffix(&self) -> TypeIdSuffix {
+        TypeIdSuffix::from(self.uuid)
+    }
+
+    /// Returns the encoded suffix as it appears in the input.
+    #[must_use]
+    pub const fn suffix_str(&self) -> &'a str {
+        self.suffix
+    }
+
+    /// Returns the UUID encoded by the suffix.
+    #[must_use]
+    pub const fn uuid(&self) -> Uuid {
+        self.uuid
+    }
+
+    /// Returns the whole identifier as it appears in the input.
+    #[must_use]
+    pub const fn as_str(&self) -> &'a str {
+        self.input
+    }
+
+    /// Copies the identifier into an owned `MagicTypeId`.
+    #[must_use]
+    #[allow(clippy::should_implement_trait)]
+    pub fn to_owned(&self) -> MagicTypeId {
+        let prefix = if self.prefix.is_empty() {
+            TypeIdPrefix::default()
+        } else {
+            // The prefix was validated by `parse`.
+            TypeIdPrefix::from_str(self.prefix).unwrap_or_default()
+        };
+        MagicTypeId::new(prefix, self.suffix())
+    }
+}
+
+impl<'a> TryFrom<&'a str> for MagicTypeIdRef<'a> {
+    type Error = MagicTypeIdError;
+
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        Self::parse(value)
+    }
+}
+
+impl From<MagicTypeIdRef<'_>> for MagicTypeId {
+    fn from(value: MagicTypeIdRef<'_>) -> Self {
+        value.to_owned()
+    }
+}
+
+impl Display for MagicTypeIdRef<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.input)
+    }
+}
+
+impl AsRef<str> for MagicTypeIdRef<'_> {
+    fn as_ref(&self) -> &str {
+        self.input
+    }
+}
+
+impl PartialEq for MagicTypeIdRef<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.input == other.input
+    }
+}
+
+impl Eq for MagicTypeIdRef<'_> {}
+
+impl Ord for MagicTypeIdRef<'_> {
+    /// Orders by suffix, then by prefix, exactly like `MagicTypeId`.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.uuid
+            .cmp(&other.uuid)
+            .then_with(|| self.prefix.cmp(other.prefix))
+    }
+}
+
+impl PartialOrd for MagicTypeIdRef<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Hash for MagicTypeIdRef<'_> {
+    /// Hashes the identifier string, exactly like `MagicTypeId`.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.input.hash(state);
+    }
+}
+
+impl PartialEq<MagicTypeId> for MagicTypeIdRef<'_> {
+    fn eq(&self, other: &MagicTypeId) -> bool {
+        self.input == other.as_str()
+    }
+}
+
+impl PartialEq<MagicTypeIdRef<'_>> for MagicTypeId {
+    fn eq(&self, other: &MagicTypeIdRef<'_>) -> bool {
+        self.as_str() == other.input
+    }
+}
+
+impl PartialEq<str> for MagicTypeIdRef<'_> {
+    fn eq(&self, other: &str) -> bool {
+        self.input == other
+    }
+}
+
+impl PartialEq<&str> for MagicTypeIdRef<'_> {
+    fn eq(&self, other: &&str) -> bool {
+        self.input == *other
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for MagicTypeIdRef<'_> {
+    /// Serializes the identifier as a string, in binary formats as well.
+    ///
+    /// Unlike `MagicTypeId`, a borrowed identifier does not use the compact binary form, because
+    /// it could not be deserialized from it without allocating. `MagicTypeId` reads either form.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.input)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de: 'a, 'a> Deserialize<'de> for MagicTypeIdRef<'a> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // Borrow the string from the input; formats that cannot lend it (for example, strings
+        // containing escape sequences) report an error instead of allocating
+        let input = <&'de str>::deserialize(deserializer)?;
+        Self::parse(input).map_err(serde::de::Error::custom)
+    }
+}
@@ -0,0 +1,275 @@
+//! Configurable validation policies for parsing `MagicTypeId`s.
+//!
+//! [`MagicTypeId::from_str`](std::str::FromStr::from_str) accepts any identifier that is valid
+//! under the `TypeID` specification. This module provides [`ParseOptions`], which adds
+//! application-level rules on top of it, such as which prefixes and UUID versions are expected.
+//! Use it with [`MagicTypeId::parse_with`].
+
+use typeid_suffix::prelude::*;
+
+use crate::errors::MagicTypeIdError;
+use crate::magic_type_id::MagicTypeId;
+
+#[cfg(feature = "instrument")]
+use tracing::debug;
+
+/// A set of rules that [`MagicTypeId::parse_with`] applies after parsing an identifier.
+///
+/// `ParseOptions::new()` accepts everything [`MagicTypeId::from_str`](std::str::FromStr::from_str)
+/// accepts. Each builder method tightens one rule, and every rule has its own error variant:
+///
+/// | Rule | Builder method | Error |
+/// |------|----------------|-------|
+/// | Exact prefix | [`require_prefix`](Self::require_prefix) | [`MagicTypeIdError::PrefixMismatch`] |
+/// | Prefix set | [`allowed_prefixes`](Self::allowed_prefixes) | [`MagicTypeIdError::PrefixNotAllowed`] |
+/// | Empty prefix | [`allow_empty_prefix`](Self::allow_empty_prefix) | [`MagicTypeIdError::EmptyPrefixNotAllowed`] |
+/// | Nil UUID | [`allow_nil`](Self::allow_nil) | [`MagicTypeIdError::NilNotAllowed`] |
+/// | Max UUID | [`allow_max`](Self::allow_max) | [`MagicTypeIdError::MaxNotAllowed`] |
+/// | RFC variant | [`require_rfc_variant`](Self::require_rfc_variant) | [`MagicTypeIdError::VariantNotAllowed`] |
+/// | UUID versions | [`allowed_versions`](Self::allowed_versions) | [`MagicTypeIdError::VersionNotAllowed`] |
+///
+/// The rules are checked in the order of this table, after the identifier itself has been
+/// parsed, so a malformed identifier still fails with a [`MagicTypeIdError::Prefix`] or
+/// [`MagicTypeIdError::Suffix`] error.
+///
+/// # Examples
+///
+/// ```
+/// use mti::prelude::*;
+///
+/// let options = ParseOptions::new()
+///     .allowed_prefixes(["user", "admin"])
+///     .allowed_versions([SuffixVersion::V7])
+///     .require_rfc_variant(true);
+///
+/// assert!(MagicTypeId::parse_with("user_01h455vb4pex5vsknk084sn02q", &options).is_ok());
+/// assert!(matches!(
+///     MagicTypeId::parse_with("order_01h455vb4pex5vsknk084sn02q", &options),
+///     Err(MagicTypeIdError::PrefixNotAllowed { .. })
+/// ));
+/// ```
+// Each flag toggles an independent rule, so they do not form a state machine
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseOptions {
+    prefixes: Option<Vec<String>>,
+    versions: Option<Vec<SuffixVersion>>,
+    require_rfc_variant: bool,
+    allow_nil: bool,
+    allow_max: bool,
+    allow_empty_prefix: bool,
+}
+
+impl ParseOptions {
+    /// Creates options that accept every identifier valid under the `TypeID` specification.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            prefixes: None,
+            versions: None,
+            require_rfc_variant: false,
+            allow_nil: true,
+            allow_max: true,
+            allow_empty_prefix: true,
+        }
+    }
+
+    /// Creates options that only accept identifiers with a non-empty prefix and a UUID of the
+    /// RFC 9562 variant, rejecting the Nil and Max UUIDs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mti::prelude::*;
+    ///
+    /// let strict = ParseOptions::strict();
+    /// assert!(MagicTypeId::parse_with("user_01h455vb4pex5vsknk084sn02q", &strict).is_ok());
+    /// assert_eq!(
+    ///     MagicTypeId::parse_with("01h455vb4pex5vsknk084sn02q", &strict),
+    ///     Err(MagicTypeIdError::EmptyPrefixNotAllowed)
+    /// );
+    /// assert_eq!(
+    ///     MagicTypeId::parse_with("user_00000000000000000000000000", &strict),
+    ///     Err(MagicTypeIdError::NilNotAllowed)
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn strict() -> Self {
+        Self {
+            prefixes: None,
+            versions: None,
+            require_rfc_variant: true,
+            allow_nil: false,
+            allow_max: false,
+            allow_empty_prefix: false,
+        }
+    }
+
+    /// Only accepts identifiers whose prefix is exactly `prefix`.
+    ///
+    /// This replaces any prefixes set before.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mti::prelude::*;
+    ///
+    /// let options = ParseOptions::new().require_prefix("user");
+    /// assert_eq!(
+    ///     MagicTypeId::parse_with("order_01h455vb4pex5vsknk084sn02q", &options),
+    ///     Err(MagicTypeIdError::PrefixMismatch {
+    ///         expected: "user".to_string(),
+    ///         found: "order".to_string(),
+    ///     })
+    /// );
+    /// ```
+    #[must_use]
+    pub fn require_prefix(self, prefix: impl Into<String>) -> Self {
+        Self {
+            prefixes: Some(vec![prefix.into()]),
+            ..self
+        }
+    }
+
+    /// Only accepts identifiers whose prefix is one of `prefixes`.
+    ///
+    /// This replaces any prefixes set before. A set with a single prefix behaves like
+    /// [`require_prefix`](Self::require_prefix). To accept identifiers without a prefix, include
+    /// the empty string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mti::prelude::*;
+    ///
+    /// let options = ParseOptions::new().allowed_prefixes(["user", "admin"]);
+    /// assert!(MagicTypeId::parse_with("admin_01h455vb4pex5vsknk084sn02q", &options).is_ok());
+    /// assert_eq!(
+    ///     MagicTypeId::parse_with("order_01h455vb4pex5vsknk084sn02q", &options),
+    ///     Err(MagicTypeIdError::PrefixNotAllowed {
+    ///         allowed: vec!["user".to_string(), "admin".to_string()],
+    ///         found: "order".to_string(),
+    ///     })
+    /// );
+    /// ```
+    #[must_use]
+    pub fn allowed_prefixes<I, S>(self, prefixes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            prefixes: Some(prefixes.into_iter().map(Into::into).collect()),
+            ..self
+        }
+    }
+
+    /// Only accepts identifiers whose UUID has one of `versions`.
+    ///
+    /// This replaces any versions set before. The Nil and Max UUIDs are subject to this rule
+    /// too, as [`SuffixVersion::Nil`] and [`SuffixVersion::Max`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mti::prelude::*;
+    ///
+    /// let options = ParseOptions::new().allowed_versions([V6::VERSION, V7::VERSION]);
+    /// let v4_id = "user".create_type_id::<V4>();
+    /// assert_eq!(
+    ///     MagicTypeId::parse_with(&v4_id, &options),
+    ///     Err(MagicTypeIdError::VersionNotAllowed { found: SuffixVersion::V4 })
+    /// );
+    /// ```
+    #[must_use]
+    pub fn allowed_versions(self, versions: impl IntoIterator<Item = SuffixVersion>) -> Self {
+        Self {
+            versions: Some(versions.into_iter().collect()),
+            ..self
+        }
+    }
+
+    /// Sets whether the UUID must be of the variant defined by RFC 9562 (and RFC 4122).
+    ///
+    /// The Nil and Max UUIDs are exempt from this rule, since they are controlled by
+    /// [`allow_nil`](Self::allow_nil) and [`allow_max`](Self::allow_max). Defaults to `false`.
+    #[must_use]
+    pub const fn require_rfc_variant(mut self, require: bool) -> Self {
+        self.require_rfc_variant = require;
+        self
+    }
+
+    /// Sets whether the Nil UUID (all zeros) is accepted. Defaults to `true`.
+    #[must_use]
+    pub const fn allow_nil(mut self, allow: bool) -> Self {
+        self.allow_nil = allow;
+        self
+    }
+
+    /// Sets whether the Max UUID (all ones) is accepted. Defaults to `true`.
+    #[must_use]
+    pub const fn allow_max(mut self, allow: bool) -> Self {
+        self.allow_max = allow;
+        self
+    }
+
+    /// Sets whether identifiers without a prefix are accepted. Defaults to `true`.
+    #[must_use]
+    pub const fn allow_empty_prefix(mut self, allow: bool) -> Self {
+        self.allow_empty_prefix = allow;
+        self
+    }
+
+    /// Checks a parsed identifier against these options, returning it unchanged if it passes.
+    pub(crate) fn validate(&self, id: MagicTypeId) -> Result<MagicTypeId, MagicTypeIdError> {
+        let prefix = id.prefix().as_str();
+        if prefix.is_empty() && !self.allow_empty_prefix {
+            return Err(MagicTypeIdError::EmptyPrefixNotAllowed);
+        }
+        if let Some(prefixes) = &self.prefixes {
+            if !prefixes.iter().any(|allowed| allowed == prefix) {
+                #[cfg(feature = "instrument")]
+                debug!("Prefix '{}' is not one of {:?}", prefix, prefixes);
+                return Err(match prefixes.as_slice() {
+                    [expected] => MagicTypeIdError::PrefixMismatch {
+                        expected: expected.clone(),
+                        found: prefix.to_string(),
+                    },
+                    _ => MagicTypeIdError::PrefixNotAllowed {
+                        allowed: prefixes.clone(),
+                        found: prefix.to_string(),
+                    },
+                });
+            }
+        }
+
+        let version = id.version();
+        if version == SuffixVersion::Nil && !self.allow_nil {
+            return Err(MagicTypeIdError::NilNotAllowed);
+        }
+        if version == SuffixVersion::Max && !self.allow_max {
+            return Err(MagicTypeIdError::MaxNotAllowed);
+        }
+        if self.require_rfc_variant
+            && !matches!(version, SuffixVersion::Nil | SuffixVersion::Max)
+            && id.variant() != Variant::RFC4122
+        {
+            return Err(MagicTypeIdError::VariantNotAllowed);
+        }
+        if let Some(versions) = &self.versions {
+            if !versions.contains(&version) {
+                #[cfg(feature = "instrument")]
+                debug!("UUID version {} is not one of {:?}", version, versions);
+                return Err(MagicTypeIdError::VersionNotAllowed { found: version });
+            }
+        }
+        Ok(id)
+    }
+}
+
+impl Default for ParseOptions {
+    /// Returns [`ParseOptions::new`].
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,352 @@
+//! Registries of the prefixes an application accepts.
+//!
+//! This module provides [`PrefixRegistry`], which maps every known prefix to an [`EntityKind`]
+//! describing the entity it identifies. Parsing through a registry rejects identifiers with
+//! unknown prefixes or unexpected UUID versions, and returns the matching kind along with the
+//! identifier. Deprecated aliases of a prefix are accepted and reported, so that old identifiers
+//! keep working while they are migrated.
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use typeid_prefix::prelude::*;
+use typeid_suffix::prelude::*;
+
+use crate::errors::{MagicTypeIdError, RegistryError};
+use crate::magic_type_id::MagicTypeId;
+use crate::magic_type_id_ref::MagicTypeIdRef;
+
+#[cfg(feature = "instrument")]
+use tracing::{debug, instrument};
+
+/// Describes one kind of entity: its prefix, its name, and the identifiers it accepts.
+///
+/// `EntityKind` is built with `const fn`s, so a registry's kinds can be listed in a `static`.
+///
+/// # Examples
+///
+/// ```
+/// use mti::prelude::*;
+///
+/// static USER: EntityKind = EntityKind::new("user", "User")
+///     .with_versions(&[SuffixVersion::V7])
+///     .with_aliases(&["usr"]);
+///
+/// assert_eq!(USER.prefix(), "user");
+/// assert!(USER.allows_version(SuffixVersion::V7));
+/// assert!(!USER.allows_version(SuffixVersion::V4));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EntityKind {
+    prefix: &'static str,
+    entity: &'static str,
+    versions: &'static [SuffixVersion],
+    aliases: &'static [&'static str],
+}
+
+impl EntityKind {
+    /// Creates a kind named `entity` whose identifiers carry `prefix` and any UUID version.
+    #[must_use]
+    pub const fn new(prefix: &'static str, entity: &'static str) -> Self {
+        Self {
+            prefix,
+            entity,
+            versions: &[],
+            aliases: &[],
+        }
+    }
+
+    /// Restricts the UUID versions of this kind's identifiers to `versions`.
+    ///
+    /// An empty list, the default, allows every version.
+    #[must_use]
+    pub const fn with_versions(mut self, versions: &'static [SuffixVersion]) -> Self {
+        self.versions = versions;
+        self
+    }
+
+    /// Sets deprecated prefixes that still identify this kind.
+    #[must_use]
+    pub const fn with_aliases(mut self, aliases: &'static [&'static str]) -> Self {
+        self.aliases = aliases;
+        self
+    }
+
+    /// Returns the canonical prefix of this kind.
+    #[must_use]
+    pub const fn prefix(&self) -> &'static str {
+        self.prefix
+    }
+
+    /// Returns the name of the entity.
+    #[must_use]
+    pub const fn entity(&self) -> &'static str {
+        self.entity
+    }
+
+    /// Returns the allowed UUID versions, or an empty slice if every version is allowed.
+    #[must_use]
+    pub const fn versions(&self) -> &'static [SuffixVersion] {
+        self.versions
+    }
+
+    /// Returns the deprecated aliases of the prefix.
+    #[must_use]
+    pub const fn aliases(&self) -> &'static [&'static str] {
+        self.aliases
+    }
+
+    /// Returns whether identifiers of this kind may hold a UUID of `version`.
+    #[must_use]
+    pub fn allows_version(&self, version: SuffixVersion) -> bool {
+        self.versions.is_empty() || self.versions.contains(&version)
+    }
+}
+
+/// A registered kind together with its validated canonical prefix.
+#[derive(Debug, Clone)]
+struct Registration {
+    kind: EntityKind,
+    prefix: TypeIdPrefix,
+}
+
+/// Maps prefixes, and their deprecated aliases, to the [`EntityKind`]s they identify.
+///
+/// Lookups go through a hash map, so parsing through a registry costs about as much as parsing
+/// a `MagicTypeId` directly. Build the registry once, at startup or from a static list, and
+/// share it.
+///
+/// # Examples
+///
+/// ```
+/// use mti::prelude::*;
+///
+/// static KINDS: &[EntityKind] = &[
+///     EntityKind::new("user", "User").with_aliases(&["usr"]),
+///     EntityKind::new("order", "Order").with_versions(&[SuffixVersion::V7]),
+/// ];
+///
+/// let registry = PrefixRegistry::from_kinds(KINDS.iter().copied()).unwrap();
+///
+/// let order = registry.parse("order_01h455vb4pex5vsknk084sn02q").unwrap();
+/// assert_eq!(order.kind().entity(), "Order");
+/// assert_eq!(order.id().as_str(), "order_01h455vb4pex5vsknk084sn02q");
+///
+/// assert_eq!(
+///     registry.parse("invoice_01h455vb4pex5vsknk084sn02q").unwrap_err(),
+///     MagicTypeIdError::UnknownPrefix { found: "invoice".to_string() }
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PrefixRegistry {
+    registrations: Vec<Registration>,
+    lookup: HashMap<&'static str, Entry>,
+}
+
+/// Where a prefix in the lookup table points.
+#[derive(Debug, Clone, Copy)]
+struct Entry {
+    index: usize,
+    is_alias: bool,
+}
+
+impl PrefixRegistry {
+    /// Creates an empty registry.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a registry containing `kinds`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error that [`register`](Self::register) would return for `kinds`.
+    pub fn from_kinds(kinds: impl IntoIterator<Item = EntityKind>) -> Result<Self, RegistryError> {
+        let mut registry = Self::new();
+        for kind in kinds {
+            registry.register(kind)?;
+        }
+        Ok(registry)
+    }
+
+    /// Adds `kind` to the registry.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RegistryError::InvalidPrefix`] if the prefix or one of the aliases of `kind` is
+    /// not a valid `TypeID` prefix, and [`RegistryError::DuplicatePrefix`] if one of them is
+    /// already registered, as a prefix or as an alias. The registry is unchanged on error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mti::prelude::*;
+    ///
+    /// let mut registry = PrefixRegistry::new();
+    /// registry.register(EntityKind::new("user", "User")).unwrap();
+    ///
+    /// assert_eq!(
+    ///     registry.register(EntityKind::new("member", "Member").with_aliases(&["user"])),
+    ///     Err(RegistryError::DuplicatePrefix { prefix: "user" })
+    /// );
+    /// ```
+    #[cfg_attr(feature = "instrument", instrument(level = "debug", skip(self)))]
+    pub fn register(&mut self, kind: EntityKind) -> Result<(), RegistryError> {
+        let prefix =
+            TypeIdPrefix::from_str(kind.prefix).map_err(|error| RegistryError::InvalidPrefix {
+                prefix: kind.prefix,
+                error,
+            })?;
+
+        for &alias in kind.aliases {
+            TypeIdPrefix::check(alias).map_err(|error| RegistryError::InvalidPrefix {
+                prefix: alias,
+                error,
+            })?;
+        }
+        let names = std::iter::once(kind.prefix).chain(kind.aliases.iter().copied());
+        for (position, name) in names.clone().enumerate() {
+            let repeated = names.clone().take(position).any(|earlier| earlier == name);
+            if repeated || self.lookup.contains_key(name) {
+                return Err(RegistryError::DuplicatePrefix { prefix: name });
+            }
+        }
+
+        let index = self.registrations.len();
+        for (position, name) in names.enumerate() {
+            let entry = Entry {
+                index,
+                is_alias: position > 0,
+            };
+            let _ = self.lookup.insert(name, entry);
+        }
+        self.registrations.push(Registration { kind, prefix });
+
+        #[cfg(feature = "instrument")]
+        debug!("Registered prefix '{}' for {}", kind.prefix, kind.entity);
+        Ok(())
+    }
+
+    /// Returns the kind identified by `prefix`, which may be a deprecated alias.
+    #[must_use]
+    pub fn get(&self, prefix: &str) -> Option<&EntityKind> {
+        self.lookup
+            .get(prefix)
+            .map(|entry| &self.registrations[entry.index].kind)
+    }
+
+    /// Returns whether `prefix` is registered, as a canonical prefix or as an alias.
+    #[must_use]
+    pub fn contains(&self, prefix: &str) -> bool {
+        self.lookup.contains_key(prefix)
+    }
+
+    /// Returns the registered kinds, in registration order.
+    pub fn kinds(&self) -> impl Iterator<Item = &EntityKind> {
+        self.registrations
+            .iter()
+            .map(|registration| &registration.kind)
+    }
+
+    /// Returns the number of registered kinds.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.registrations.len()
+    }
+
+    /// Returns whether no kinds are registered.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.registrations.is_empty()
+    }
+
+    /// Parses `input` and checks it against the registered kinds.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`MagicTypeId::from_str`](FromStr::from_str) if `input` is not a
+    /// valid identifier, [`MagicTypeIdError::UnknownPrefix`] if its prefix is not registered,
+    /// and [`MagicTypeIdError::VersionNotAllowed`] if its kind does not allow its UUID version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mti::prelude::*;
+    ///
+    /// let registry = PrefixRegistry::from_kinds([
+    ///     EntityKind::new("user", "User").with_aliases(&["usr"]),
+    /// ]).unwrap();
+    ///
+    /// let legacy = registry.parse("usr_01h455vb4pex5vsknk084sn02q").unwrap();
+    /// assert!(legacy.is_deprecated_alias());
+    /// assert_eq!(legacy.into_canonical().as_str(), "user_01h455vb4pex5vsknk084sn02q");
+    /// ```
+    #[cfg_attr(feature = "instrument", instrument(level = "debug", skip(self)))]
+    pub fn parse(&self, input: &str) -> Result<RegisteredId<'_>, MagicTypeIdError> {
+        let parsed = MagicTypeIdRef::parse(input)?;
+        let entry = self.lookup.get(parsed.prefix()).ok_or_else(|| {
+            #[cfg(feature = "instrument")]
+            debug!("Unknown prefix '{}'", parsed.prefix());
+            MagicTypeIdError::UnknownPrefix {
+                found: parsed.prefix().to_string(),
+            }
+        })?;
+        let registration = &self.registrations[entry.index];
+
+        let version = parsed.suffix().version();
+        if !registration.kind.allows_version(version) {
+            return Err(MagicTypeIdError::VersionNotAllowed { found: version });
+        }
+
+        Ok(RegisteredId {
+            id: parsed.to_owned(),
+            registration,
+            is_alias: entry.is_alias,
+        })
+    }
+}
+
+/// An identifier parsed by a [`PrefixRegistry`], together with its registered kind.
+#[derive(Debug, Clone)]
+pub struct RegisteredId<'r> {
+    id: MagicTypeId,
+    registration: &'r Registration,
+    is_alias: bool,
+}
+
+impl<'r> RegisteredId<'r> {
+    /// Returns the identifier, exactly as it was parsed.
+    #[must_use]
+    pub const fn id(&self) -> &MagicTypeId {
+        &self.id
+    }
+
+    /// Returns the kind of entity that the identifier refers to.
+    #[must_use]
+    pub const fn kind(&self) -> &'r EntityKind {
+        &self.registration.kind
+    }
+
+    /// Returns whether the identifier carries a deprecated alias instead of the canonical prefix.
+    #[must_use]
+    pub const fn is_deprecated_alias(&self) -> bool {
+        self.is_alias
+    }
+
+    /// Returns the identifier, exactly as it was parsed.
+    #[must_use]
+    pub fn into_id(self) -> MagicTypeId {
+        self.id
+    }
+
+    /// Returns the identifier with its kind's canonical prefix, replacing a deprecated alias.
+    #[must_use]
+    pub fn into_canonical(self) -> MagicTypeId {
+        if self.is_alias {
+            MagicTypeId::new(self.registration.prefix.clone(), self.id.suffix().clone())
+        } else {
+            self.id
+        }
+    }
+}
@@ -0,0 +1,172 @@
+//! Serde helpers for fields that hold a `MagicTypeId`.
+//!
+//! `MagicTypeId` deserializes from any valid `TypeID`, so a `customer_…` value in a `user_id`
+//! field is accepted. This module provides [`Prefixed`], which is used with
+//! `#[serde(with = "…")]` to reject values whose prefix differs from the prefix of a
+//! [`PrefixMarker`]. It works on `MagicTypeId`, `Option` and `Vec` fields alike.
+//!
+//! Fields whose type should carry the prefix everywhere, not just in serialized data, can use
+//! [`TypedId`](crate::prelude::TypedId) instead.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::magic_type_id::MagicTypeId;
+use crate::typed_id::PrefixMarker;
+
+/// A serde helper that requires a field's identifiers to carry the prefix of the marker `P`.
+///
+/// Use it as `#[serde(with = "mti::serde::Prefixed::<P>")]` on a field of type
+/// `MagicTypeId`, `Option<MagicTypeId>` or `Vec<MagicTypeId>`, or any nesting of these (see
+/// [`PrefixedField`]). Serialization is unchanged. Deserialization fails with the message of
+/// [`MagicTypeIdError::PrefixMismatch`](crate::prelude::MagicTypeIdError::PrefixMismatch) if an
+/// identifier has another prefix.
+///
+/// As with any `with` helper, an `Option` field also needs `#[serde(default)]` to be optional
+/// in the input.
+///
+/// # Examples
+///
+/// ```
+/// use mti::prelude::*;
+/// use serde::{Deserialize, Serialize};
+///
+/// struct User;
+/// impl PrefixMarker for User {
+///     const PREFIX: &'static str = "user";
+/// }
+///
+/// #[derive(Debug, Serialize, Deserialize)]
+/// struct Order {
+///     #[serde(with = "mti::serde::Prefixed::<User>")]
+///     user_id: MagicTypeId,
+///     #[serde(default, with = "mti::serde::Prefixed::<User>")]
+///     approved_by: Option<MagicTypeId>,
+///     #[serde(with = "mti::serde::Prefixed::<User>")]
+///     watchers: Vec<MagicTypeId>,
+/// }
+///
+/// let order: Order = serde_json::from_str(
+///     r#"{"user_id": "user_01h455vb4pex5vsknk084sn02q", "watchers": []}"#,
+/// ).unwrap();
+/// assert_eq!(order.user_id.prefix().as_str(), "user");
+/// assert!(order.approved_by.is_none());
+///
+/// let error = serde_json::from_str::<Order>(
+///     r#"{"user_id": "customer_01h455vb4pex5vsknk084sn02q", "watchers": []}"#,
+/// ).unwrap_err();
+/// assert!(error.to_string().starts_with("Prefix error: expected prefix 'user', found 'customer'"));
+/// ```
+pub struct Prefixed<P: PrefixMarker> {
+    marker: PhantomData<fn() -> P>,
+}
+
+impl<P: PrefixMarker> Prefixed<P> {
+    /// Serializes `value` as it would be without this helper.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the serializer.
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: PrefixedField + Serialize,
+        S: Serializer,
+    {
+        value.serialize(serializer)
+    }
+
+    /// Deserializes a field, checking that every identifier in it carries
+    /// [`P::PREFIX`](PrefixMarker::PREFIX).
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the deserializer, or a custom error if an identifier is invalid or
+    /// carries another prefix.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: PrefixedField,
+        D: Deserializer<'de>,
+    {
+        T::deserialize_prefixed::<P, D>(deserializer)
+    }
+}
+
+impl<P: PrefixMarker> fmt::Debug for Prefixed<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Prefixed")
+            .field("prefix", &P::PREFIX)
+            .finish()
+    }
+}
+
+/// A field type whose identifiers [`Prefixed`] can check while deserializing.
+///
+/// This trait is implemented for `MagicTypeId`, and for `Option<T>` and `Vec<T>` of any type
+/// implementing it.
+pub trait PrefixedField: Sized {
+    /// Deserializes `Self`, checking that every identifier carries
+    /// [`P::PREFIX`](PrefixMarker::PREFIX).
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the deserializer, or a custom error if an identifier is invalid or
+    /// carries another prefix.
+    fn deserialize_prefixed<'de, P, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        P: PrefixMarker,
+        D: Deserializer<'de>;
+}
+
+impl PrefixedField for MagicTypeId {
+    fn deserialize_prefixed<'de, P, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        P: PrefixMarker,
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)?
+            .ensure_prefix(P::PREFIX)
+            .map_err(::serde::de::Error::custom)
+    }
+}
+
+impl<T: PrefixedField> PrefixedField for Option<T> {
+    fn deserialize_prefixed<'de, P, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        P: PrefixMarker,
+        D: Deserializer<'de>,
+    {
+        let value = Option::<Checked<T, P>>::deserialize(deserializer)?;
+        Ok(value.map(|checked| checked.value))
+    }
+}
+
+impl<T: PrefixedField> PrefixedField for Vec<T> {
+    fn deserialize_prefixed<'de, P, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        P: PrefixMarker,
+        D: Deserializer<'de>,
+    {
+        let values = Vec::<Checked<T, P>>::deserialize(deserializer)?;
+        Ok(values.into_iter().map(|checked| checked.value).collect())
+    }
+}
+
+/// A value deserialized through [`PrefixedField`], so that containers can check their elements.
+struct Checked<T, P> {
+    value: T,
+    marker: PhantomData<fn() -> P>,
+}
+
+impl<'de, T: PrefixedField, P: PrefixMarker> Deserialize<'de> for Checked<T, P> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Self {
+            value: T::deserialize_prefixed::<P, D>(deserializer)?,
+            marker: PhantomData,
+        })
+    }
+}
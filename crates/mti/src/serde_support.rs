@@ -0,0 +1,101 @@
+//! Shared serialization of identifiers in binary formats.
+//!
+//! Human-readable formats represent an identifier by its string form. Binary formats use a
+//! compact form instead: a single byte string holding the prefix followed by the 16 bytes of
+//! the UUID, which saves the separator and the ten extra characters of the base32 suffix.
+//! Deserialization in binary formats accepts both forms.
+//!
+//! The two forms cannot be confused: the part of a string form that would be read as the
+//! prefix of a compact form always contains the first character of the suffix, which is a
+//! digit and therefore never valid in a prefix.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::de::{self, Deserializer, Unexpected, Visitor};
+use serde::Serializer;
+use typeid_prefix::prelude::*;
+use typeid_suffix::prelude::*;
+
+use crate::errors::MagicTypeIdError;
+
+/// The number of bytes of the UUID at the end of the compact form.
+const UUID_LEN: usize = 16;
+
+/// The maximum length of the compact form: the longest valid prefix and a UUID.
+const MAX_COMPACT_LEN: usize = 63 + UUID_LEN;
+
+/// Serializes an identifier in its compact form, the bytes of `prefix` followed by `uuid`.
+pub fn serialize_compact<S>(serializer: S, prefix: &str, uuid: Uuid) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let mut buf = [0; MAX_COMPACT_LEN];
+    let prefix_len = prefix.len().min(MAX_COMPACT_LEN - UUID_LEN);
+    buf[..prefix_len].copy_from_slice(&prefix.as_bytes()[..prefix_len]);
+    buf[prefix_len..prefix_len + UUID_LEN].copy_from_slice(uuid.as_bytes());
+    serializer.serialize_bytes(&buf[..prefix_len + UUID_LEN])
+}
+
+/// Deserializes an identifier from its compact form or its string form, in a binary format.
+///
+/// `from_parts` builds the identifier from the prefix, which is either empty or valid, and the
+/// UUID of a compact form.
+pub fn deserialize_compact<'de, T, D>(
+    deserializer: D,
+    from_parts: fn(&str, Uuid) -> Result<T, MagicTypeIdError>,
+) -> Result<T, D::Error>
+where
+    T: FromStr<Err = MagicTypeIdError>,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_bytes(CompactVisitor { from_parts })
+}
+
+/// Splits a compact form into its prefix and UUID, if `bytes` is one.
+fn split_compact(bytes: &[u8]) -> Option<(&str, Uuid)> {
+    let split = bytes.len().checked_sub(UUID_LEN)?;
+    let (prefix, uuid) = bytes.split_at(split);
+    let prefix = std::str::from_utf8(prefix).ok()?;
+    if !prefix.is_empty() && TypeIdPrefix::check(prefix).is_err() {
+        return None;
+    }
+    Uuid::from_slice(uuid).ok().map(|uuid| (prefix, uuid))
+}
+
+/// Visits the compact form of an identifier, or its string form.
+struct CompactVisitor<T> {
+    from_parts: fn(&str, Uuid) -> Result<T, MagicTypeIdError>,
+}
+
+impl<T> Visitor<'_> for CompactVisitor<T>
+where
+    T: FromStr<Err = MagicTypeIdError>,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a TypeID string, or a prefix followed by 16 UUID bytes")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        T::from_str(v).map_err(E::custom)
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        if let Some((prefix, uuid)) = split_compact(v) {
+            return (self.from_parts)(prefix, uuid).map_err(E::custom);
+        }
+        // Formats that do not distinguish strings from bytes hand over the string form as bytes
+        match std::str::from_utf8(v) {
+            Ok(s) => self.visit_str(s),
+            Err(_) => Err(E::invalid_value(Unexpected::Bytes(v), &self)),
+        }
+    }
+}
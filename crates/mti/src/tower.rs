@@ -0,0 +1,278 @@
+//! A `tower` middleware that gives every request a `TypeID` correlation identifier.
+//!
+//! [`RequestIdLayer`] reads an identifier from a request header, `x-request-id` by default.
+//! An incoming identifier is kept if it parses as a [`MagicTypeId`] and passes the layer's
+//! [`ParseOptions`]; otherwise the layer mints a new `UUIDv7` identifier with its configured
+//! prefix, `req` by default, and writes it to the request header in place of the old value.
+//!
+//! The identifier is then:
+//!
+//! - stored in the request extensions as a [`RequestId`], for handlers and inner middleware,
+//! - set on the same header of the response, so clients can quote it, and
+//! - with the `instrument` feature, recorded in the `request_id` field of the current
+//!   `tracing` span. The span must declare the field, for example with
+//!   `request_id = tracing::field::Empty`, and be entered around the service call.
+//!
+//! # Examples
+//!
+//! ```
+//! use http::{Request, Response};
+//! use mti::prelude::*;
+//! use mti::tower::{RequestId, RequestIdLayer};
+//! use tower::{service_fn, Layer};
+//!
+//! let layer = RequestIdLayer::default().parse_options(ParseOptions::strict());
+//! let service = layer.layer(service_fn(|request: Request<()>| async move {
+//!     let id = request.extensions().get::<RequestId>().cloned();
+//!     Ok::<_, std::convert::Infallible>(Response::new(id.map(RequestId::into_inner)))
+//! }));
+//! # let _ = service;
+//! ```
+
+use std::fmt;
+use std::future::Future;
+use std::ops::Deref;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use ::http::header::{HeaderName, HeaderValue};
+use ::http::{Request, Response};
+use ::tower::{Layer, Service};
+use pin_project_lite::pin_project;
+use typeid_prefix::prelude::*;
+use typeid_suffix::prelude::*;
+
+use crate::magic_type_id::MagicTypeId;
+use crate::parse_options::ParseOptions;
+
+#[cfg(feature = "instrument")]
+use tracing::debug;
+
+/// The header [`RequestIdLayer`] reads and writes unless configured otherwise.
+pub const X_REQUEST_ID: HeaderName = HeaderName::from_static("x-request-id");
+
+/// The correlation identifier of a request, stored in its extensions by [`RequestIdService`].
+///
+/// # Examples
+///
+/// ```
+/// use std::str::FromStr;
+/// use mti::prelude::*;
+/// use mti::tower::RequestId;
+///
+/// let id = MagicTypeId::from_str("req_01h455vb4pex5vsknk084sn02q").unwrap();
+/// let request_id = RequestId::from(id.clone());
+/// assert_eq!(request_id.prefix().as_str(), "req");
+/// assert_eq!(request_id.into_inner(), id);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RequestId(MagicTypeId);
+
+impl RequestId {
+    /// Returns the identifier.
+    #[must_use]
+    pub const fn id(&self) -> &MagicTypeId {
+        &self.0
+    }
+
+    /// Consumes the `RequestId`, returning the identifier.
+    #[must_use]
+    pub fn into_inner(self) -> MagicTypeId {
+        self.0
+    }
+}
+
+impl From<MagicTypeId> for RequestId {
+    fn from(id: MagicTypeId) -> Self {
+        Self(id)
+    }
+}
+
+impl From<RequestId> for MagicTypeId {
+    fn from(request_id: RequestId) -> Self {
+        request_id.0
+    }
+}
+
+impl Deref for RequestId {
+    type Target = MagicTypeId;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl fmt::Display for RequestId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+/// A [`Layer`] that wraps services in a [`RequestIdService`].
+///
+/// # Examples
+///
+/// ```
+/// use http::HeaderName;
+/// use mti::prelude::*;
+/// use mti::tower::RequestIdLayer;
+///
+/// let layer = RequestIdLayer::new(TypeIdPrefix::try_from("corr").unwrap())
+///     .header(HeaderName::from_static("x-correlation-id"))
+///     .parse_options(ParseOptions::new().allowed_prefixes(["corr", "req"]));
+/// assert_eq!(layer.prefix().as_str(), "corr");
+/// ```
+#[derive(Debug, Clone)]
+pub struct RequestIdLayer {
+    header: HeaderName,
+    prefix: TypeIdPrefix,
+    options: ParseOptions,
+}
+
+impl RequestIdLayer {
+    /// Creates a layer that mints identifiers with `prefix` and uses the [`X_REQUEST_ID`]
+    /// header. Any valid incoming identifier is accepted.
+    #[must_use]
+    pub const fn new(prefix: TypeIdPrefix) -> Self {
+        Self {
+            header: X_REQUEST_ID,
+            prefix,
+            options: ParseOptions::new(),
+        }
+    }
+
+    /// Reads and writes the identifier in `header` instead of [`X_REQUEST_ID`].
+    #[must_use]
+    pub fn header(self, header: HeaderName) -> Self {
+        Self { header, ..self }
+    }
+
+    /// Only keeps incoming identifiers that pass `options`. Others are replaced with a new one.
+    #[must_use]
+    pub fn parse_options(self, options: ParseOptions) -> Self {
+        Self { options, ..self }
+    }
+
+    /// Returns the prefix of the identifiers this layer mints.
+    #[must_use]
+    pub const fn prefix(&self) -> &TypeIdPrefix {
+        &self.prefix
+    }
+
+    /// Returns the header this layer reads and writes.
+    #[must_use]
+    pub const fn header_name(&self) -> &HeaderName {
+        &self.header
+    }
+}
+
+impl Default for RequestIdLayer {
+    /// Returns a layer that mints `req` identifiers and uses the [`X_REQUEST_ID`] header.
+    fn default() -> Self {
+        Self::new("req".create_prefix_sanitized())
+    }
+}
+
+impl<S> Layer<S> for RequestIdLayer {
+    type Service = RequestIdService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RequestIdService {
+            inner,
+            config: Arc::new(self.clone()),
+        }
+    }
+}
+
+/// A [`Service`] that assigns every request a [`RequestId`]. See the [module](self)
+/// documentation for details.
+#[derive(Debug, Clone)]
+pub struct RequestIdService<S> {
+    inner: S,
+    config: Arc<RequestIdLayer>,
+}
+
+impl<S> RequestIdService<S> {
+    /// Returns the identifier of `request`, minting one if it has no acceptable identifier.
+    fn request_id<B>(&self, request: &mut Request<B>) -> MagicTypeId {
+        let header = &self.config.header;
+        let incoming = request
+            .headers()
+            .get(header)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| MagicTypeId::parse_with(value, &self.config.options));
+        match incoming {
+            Some(Ok(id)) => return id,
+            #[cfg(feature = "instrument")]
+            Some(Err(error)) => debug!("Replacing invalid request id: {}", error),
+            _ => {}
+        }
+        let id = MagicTypeId::new(self.config.prefix.clone(), TypeIdSuffix::new::<V7>());
+        if let Ok(value) = HeaderValue::from_str(id.as_str()) {
+            let _ = request.headers_mut().insert(header.clone(), value);
+        }
+        id
+    }
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for RequestIdService<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = ResponseFuture<S::Future>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: Request<ReqBody>) -> Self::Future {
+        let id = self.request_id(&mut request);
+        #[cfg(feature = "instrument")]
+        let _ = tracing::Span::current().record("request_id", tracing::field::display(&id));
+        let value = HeaderValue::from_str(id.as_str()).ok();
+        let _ = request.extensions_mut().insert(RequestId(id));
+        ResponseFuture {
+            inner: self.inner.call(request),
+            header: self.config.header.clone(),
+            value,
+        }
+    }
+}
+
+pin_project! {
+    /// The response future of [`RequestIdService`], which sets the identifier on the response.
+    pub struct ResponseFuture<F> {
+        #[pin]
+        inner: F,
+        header: HeaderName,
+        value: Option<HeaderValue>,
+    }
+}
+
+impl<F> fmt::Debug for ResponseFuture<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ResponseFuture")
+            .field("header", &self.header)
+            .field("value", &self.value)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<F, B, E> Future for ResponseFuture<F>
+where
+    F: Future<Output = Result<Response<B>, E>>,
+{
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let mut response = std::task::ready!(this.inner.poll(cx))?;
+        if let Some(value) = this.value.take() {
+            let _ = response.headers_mut().insert(this.header.clone(), value);
+        }
+        Poll::Ready(Ok(response))
+    }
+}
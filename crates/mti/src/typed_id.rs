@@ -0,0 +1,556 @@
+//! Compile-time typed identifiers.
+//!
+//! This module provides [`TypedId`], a `MagicTypeId` whose prefix is fixed by a marker type
+//! implementing [`PrefixMarker`]. Identifiers of different entity types become distinct Rust
+//! types, so mixing up a `user_…` and an `order_…` identifier is caught by the compiler instead
+//! of at runtime.
+
+use std::any::type_name;
+use std::cmp::Ordering;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::ops::Deref;
+use std::str::FromStr;
+
+use typeid_prefix::TypeIdPrefix;
+use typeid_suffix::prelude::*;
+
+use crate::errors::MagicTypeIdError;
+use crate::magic_type_id::MagicTypeId;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[cfg(feature = "instrument")]
+use tracing::instrument;
+
+/// A marker type that fixes the prefix of a [`TypedId`].
+///
+/// Implement this trait on a zero-sized type for every kind of entity in your domain.
+/// The associated [`PREFIX`](PrefixMarker::PREFIX) must be a valid, non-empty `TypeID` prefix.
+///
+/// # Examples
+///
+/// ```
+/// use mti::prelude::*;
+///
+/// struct User;
+///
+/// impl PrefixMarker for User {
+///     const PREFIX: &'static str = "user";
+/// }
+///
+/// type UserId = TypedId<User>;
+///
+/// let id = UserId::new::<V7>();
+/// assert!(id.as_str().starts_with("user_"));
+/// ```
+pub trait PrefixMarker {
+    /// The prefix carried by every identifier of this type.
+    const PREFIX: &'static str;
+}
+
+/// A `MagicTypeId` whose prefix is fixed at compile time by the marker type `P`.
+///
+/// `TypedId` parses, formats, orders, hashes and (with the `serde` feature) serializes exactly
+/// like [`MagicTypeId`], but rejects any identifier whose prefix differs from
+/// [`P::PREFIX`](PrefixMarker::PREFIX). It converts losslessly into a `MagicTypeId`, and back
+/// through a checked [`TryFrom`].
+///
+/// # Examples
+///
+/// ```
+/// use std::str::FromStr;
+/// use mti::prelude::*;
+///
+/// struct User;
+/// impl PrefixMarker for User {
+///     const PREFIX: &'static str = "user";
+/// }
+///
+/// struct Order;
+/// impl PrefixMarker for Order {
+///     const PREFIX: &'static str = "order";
+/// }
+///
+/// let user_id = TypedId::<User>::from_str("user_01h455vb4pex5vsknk084sn02q").unwrap();
+/// assert_eq!(user_id.prefix().as_str(), "user");
+///
+/// // An identifier of another entity type is rejected.
+/// assert!(TypedId::<User>::from_str("order_01h455vb4pex5vsknk084sn02q").is_err());
+///
+/// // Distinct marker types produce distinct Rust types.
+/// fn process_user(id: &TypedId<User>) -> &str { id.as_str() }
+/// let order_id = TypedId::<Order>::new::<V7>();
+/// // process_user(&order_id); // This would not compile!
+/// assert!(process_user(&user_id).starts_with("user_"));
+/// ```
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow)
+)]
+#[cfg_attr(feature = "diesel", diesel(sql_type = diesel::sql_types::Uuid))]
+#[cfg_attr(feature = "diesel", diesel(sql_type = diesel::sql_types::Binary))]
+#[cfg_attr(feature = "diesel", diesel(sql_type = diesel::sql_types::Text))]
+pub struct TypedId<P: PrefixMarker> {
+    id: MagicTypeId,
+    marker: PhantomData<fn() -> P>,
+}
+
+impl<P: PrefixMarker> TypedId<P> {
+    /// Creates a new `TypedId` with a freshly generated UUID of the specified version.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `V`: A type that implements `UuidVersion` and `Default`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`P::PREFIX`](PrefixMarker::PREFIX) is not a valid `TypeID` prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mti::prelude::*;
+    ///
+    /// struct User;
+    /// impl PrefixMarker for User {
+    ///     const PREFIX: &'static str = "user";
+    /// }
+    ///
+    /// let id = TypedId::<User>::new::<V7>();
+    /// assert_eq!(id.prefix().as_str(), "user");
+    /// ```
+    #[must_use]
+    pub fn new<V>() -> Self
+    where
+        V: UuidVersion + Default,
+    {
+        Self::from_suffix(TypeIdSuffix::new::<V>())
+    }
+
+    /// Creates a `TypedId` from an existing suffix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`P::PREFIX`](PrefixMarker::PREFIX) is not a valid `TypeID` prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mti::prelude::*;
+    ///
+    /// struct User;
+    /// impl PrefixMarker for User {
+    ///     const PREFIX: &'static str = "user";
+    /// }
+    ///
+    /// let id = TypedId::<User>::from_suffix(TypeIdSuffix::new::<Nil>());
+    /// assert_eq!(id.as_str(), "user_00000000000000000000000000");
+    /// ```
+    #[must_use]
+    pub fn from_suffix(suffix: TypeIdSuffix) -> Self {
+        Self {
+            id: MagicTypeId::new(Self::marker_prefix(), suffix),
+            marker: PhantomData,
+        }
+    }
+
+    /// Creates a `TypedId` from an existing UUID.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`P::PREFIX`](PrefixMarker::PREFIX) is not a valid `TypeID` prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mti::prelude::*;
+    ///
+    /// struct User;
+    /// impl PrefixMarker for User {
+    ///     const PREFIX: &'static str = "user";
+    /// }
+    ///
+    /// let uuid = Uuid::new_v4();
+    /// let id = TypedId::<User>::from_uuid(uuid);
+    /// assert_eq!(id.suffix().to_uuid(), uuid);
+    /// ```
+    #[must_use]
+    pub fn from_uuid(uuid: Uuid) -> Self {
+        Self::from_suffix(TypeIdSuffix::from(uuid))
+    }
+
+    /// Returns a reference to the prefix, which always equals [`P::PREFIX`](PrefixMarker::PREFIX).
+    #[must_use]
+    pub const fn prefix(&self) -> &TypeIdPrefix {
+        self.id.prefix()
+    }
+
+    /// Returns a reference to the suffix.
+    #[must_use]
+    pub const fn suffix(&self) -> &TypeIdSuffix {
+        self.id.suffix()
+    }
+
+    /// Returns the string representation of the identifier.
+    #[must_use]
+    pub const fn as_str(&self) -> &str {
+        self.id.as_str()
+    }
+
+    /// Returns a reference to the underlying `MagicTypeId`.
+    #[must_use]
+    pub const fn as_magic_type_id(&self) -> &MagicTypeId {
+        &self.id
+    }
+
+    /// Consumes the `TypedId` and returns the underlying `MagicTypeId`.
+    #[must_use]
+    pub fn into_inner(self) -> MagicTypeId {
+        self.id
+    }
+
+    fn marker_prefix() -> TypeIdPrefix {
+        TypeIdPrefix::from_str(P::PREFIX).unwrap_or_else(|err| {
+            panic!(
+                "PrefixMarker `{}` declares an invalid prefix '{}': {err}",
+                type_name::<P>(),
+                P::PREFIX
+            )
+        })
+    }
+}
+
+impl<P: PrefixMarker> TryFrom<MagicTypeId> for TypedId<P> {
+    type Error = MagicTypeIdError;
+
+    /// Converts a `MagicTypeId` into a `TypedId`, checking its prefix.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MagicTypeIdError::PrefixMismatch`] if the prefix of `id` differs from
+    /// [`P::PREFIX`](PrefixMarker::PREFIX).
+    #[cfg_attr(feature = "instrument", instrument(level = "debug", fields(id = %id, expected = P::PREFIX)))]
+    fn try_from(id: MagicTypeId) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: id.ensure_prefix(P::PREFIX)?,
+            marker: PhantomData,
+        })
+    }
+}
+
+impl<P: PrefixMarker> From<TypedId<P>> for MagicTypeId {
+    fn from(value: TypedId<P>) -> Self {
+        value.id
+    }
+}
+
+impl<P: PrefixMarker> FromStr for TypedId<P> {
+    type Err = MagicTypeIdError;
+
+    /// Parses a string into a `TypedId`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `MagicTypeIdError` if the string is not a valid `MagicTypeId`, or
+    /// [`MagicTypeIdError::PrefixMismatch`] if its prefix differs from
+    /// [`P::PREFIX`](PrefixMarker::PREFIX).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        MagicTypeId::from_str(s).and_then(Self::try_from)
+    }
+}
+
+impl<P: PrefixMarker> Clone for TypedId<P> {
+    fn clone(&self) -> Self {
+        Self {
+            id: self.id.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<P: PrefixMarker> Debug for TypedId<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("TypedId").field(&self.id).finish()
+    }
+}
+
+impl<P: PrefixMarker> Display for TypedId<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.id, f)
+    }
+}
+
+impl<P: PrefixMarker> PartialEq for TypedId<P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl<P: PrefixMarker> Eq for TypedId<P> {}
+
+impl<P: PrefixMarker> Ord for TypedId<P> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.id.cmp(&other.id)
+    }
+}
+
+impl<P: PrefixMarker> PartialOrd for TypedId<P> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<P: PrefixMarker> Hash for TypedId<P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl<P: PrefixMarker> Deref for TypedId<P> {
+    type Target = MagicTypeId;
+
+    fn deref(&self) -> &Self::Target {
+        &self.id
+    }
+}
+
+impl<P: PrefixMarker> AsRef<MagicTypeId> for TypedId<P> {
+    fn as_ref(&self) -> &MagicTypeId {
+        &self.id
+    }
+}
+
+impl<P: PrefixMarker> AsRef<str> for TypedId<P> {
+    fn as_ref(&self) -> &str {
+        self.id.as_str()
+    }
+}
+
+impl<P: PrefixMarker> PartialEq<MagicTypeId> for TypedId<P> {
+    fn eq(&self, other: &MagicTypeId) -> bool {
+        &self.id == other
+    }
+}
+
+impl<P: PrefixMarker> PartialEq<TypedId<P>> for MagicTypeId {
+    fn eq(&self, other: &TypedId<P>) -> bool {
+        self == &other.id
+    }
+}
+
+impl<P: PrefixMarker> PartialEq<str> for TypedId<P> {
+    fn eq(&self, other: &str) -> bool {
+        self.id.as_str() == other
+    }
+}
+
+impl<P: PrefixMarker> PartialEq<&str> for TypedId<P> {
+    fn eq(&self, other: &&str) -> bool {
+        self.id.as_str() == *other
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<P: PrefixMarker> Serialize for TypedId<P> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.id.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, P: PrefixMarker> Deserialize<'de> for TypedId<P> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // Deserialize as a MagicTypeId, then enforce the marker's prefix
+        let id = MagicTypeId::deserialize(deserializer)?;
+        Self::try_from(id).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "sqlx")]
+impl<P: PrefixMarker, DB: sqlx::Database> sqlx::Type<DB> for TypedId<P>
+where
+    Uuid: sqlx::Type<DB>,
+    str: sqlx::Type<DB>,
+{
+    /// A `TypedId` is stored as the UUID of its suffix, since its prefix is fixed by `P`.
+    fn type_info() -> DB::TypeInfo {
+        <Uuid as sqlx::Type<DB>>::type_info()
+    }
+
+    /// Accepts UUID columns, and text columns holding a whole `MagicTypeId`.
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <Uuid as sqlx::Type<DB>>::compatible(ty) || <str as sqlx::Type<DB>>::compatible(ty)
+    }
+}
+
+#[cfg(feature = "sqlx")]
+impl<'q, P: PrefixMarker, DB: sqlx::Database> sqlx::Encode<'q, DB> for TypedId<P>
+where
+    TypeIdSuffix: sqlx::Encode<'q, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as sqlx::Database>::ArgumentBuffer<'q>,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        <TypeIdSuffix as sqlx::Encode<'q, DB>>::encode_by_ref(self.suffix(), buf)
+    }
+}
+
+#[cfg(feature = "sqlx")]
+impl<'r, P: PrefixMarker, DB: sqlx::Database> sqlx::Decode<'r, DB> for TypedId<P>
+where
+    Uuid: sqlx::Type<DB> + sqlx::Decode<'r, DB>,
+    str: sqlx::Type<DB>,
+    &'r str: sqlx::Decode<'r, DB>,
+{
+    /// Decodes a `TypedId` from a UUID, adding [`P::PREFIX`](PrefixMarker::PREFIX), or from text
+    /// holding a whole `MagicTypeId`, checking that its prefix is `P::PREFIX`.
+    fn decode(
+        value: <DB as sqlx::Database>::ValueRef<'r>,
+    ) -> Result<Self, sqlx::error::BoxDynError> {
+        let is_text = {
+            let ty = sqlx::ValueRef::type_info(&value);
+            // Some drivers also decode a `Uuid` from text, so compare with the UUID type exactly
+            *ty != <Uuid as sqlx::Type<DB>>::type_info() && <str as sqlx::Type<DB>>::compatible(&ty)
+        };
+        if is_text {
+            let s = <&str as sqlx::Decode<'r, DB>>::decode(value)?;
+            return Ok(Self::from_str(s)?);
+        }
+        <Uuid as sqlx::Decode<'r, DB>>::decode(value).map(Self::from_uuid)
+    }
+}
+
+/// A `TypedId` is stored as the UUID of its suffix, since its prefix is fixed by `P`.
+#[cfg(feature = "diesel")]
+impl<P: PrefixMarker, DB: diesel::backend::Backend>
+    diesel::serialize::ToSql<diesel::sql_types::Uuid, DB> for TypedId<P>
+where
+    TypeIdSuffix: diesel::serialize::ToSql<diesel::sql_types::Uuid, DB>,
+{
+    fn to_sql<'b>(
+        &'b self,
+        out: &mut diesel::serialize::Output<'b, '_, DB>,
+    ) -> diesel::serialize::Result {
+        <TypeIdSuffix as diesel::serialize::ToSql<diesel::sql_types::Uuid, DB>>::to_sql(
+            self.suffix(),
+            out,
+        )
+    }
+}
+
+/// Loads a `TypedId` from a UUID, adding [`P::PREFIX`](PrefixMarker::PREFIX).
+#[cfg(feature = "diesel")]
+impl<P: PrefixMarker, DB: diesel::backend::Backend>
+    diesel::deserialize::FromSql<diesel::sql_types::Uuid, DB> for TypedId<P>
+where
+    TypeIdSuffix: diesel::deserialize::FromSql<diesel::sql_types::Uuid, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+        <TypeIdSuffix as diesel::deserialize::FromSql<diesel::sql_types::Uuid, DB>>::from_sql(bytes)
+            .map(Self::from_suffix)
+    }
+}
+
+/// A `TypedId` is stored as the 16 bytes of the UUID of its suffix in backends without a UUID
+/// type.
+#[cfg(feature = "diesel")]
+impl<P: PrefixMarker, DB: diesel::backend::Backend>
+    diesel::serialize::ToSql<diesel::sql_types::Binary, DB> for TypedId<P>
+where
+    TypeIdSuffix: diesel::serialize::ToSql<diesel::sql_types::Binary, DB>,
+{
+    fn to_sql<'b>(
+        &'b self,
+        out: &mut diesel::serialize::Output<'b, '_, DB>,
+    ) -> diesel::serialize::Result {
+        <TypeIdSuffix as diesel::serialize::ToSql<diesel::sql_types::Binary, DB>>::to_sql(
+            self.suffix(),
+            out,
+        )
+    }
+}
+
+/// Loads a `TypedId` from the 16 bytes of a UUID, adding [`P::PREFIX`](PrefixMarker::PREFIX).
+#[cfg(feature = "diesel")]
+impl<P: PrefixMarker, DB: diesel::backend::Backend>
+    diesel::deserialize::FromSql<diesel::sql_types::Binary, DB> for TypedId<P>
+where
+    TypeIdSuffix: diesel::deserialize::FromSql<diesel::sql_types::Binary, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+        <TypeIdSuffix as diesel::deserialize::FromSql<diesel::sql_types::Binary, DB>>::from_sql(
+            bytes,
+        )
+        .map(Self::from_suffix)
+    }
+}
+
+/// A `TypedId` can also be stored whole, as text.
+#[cfg(feature = "diesel")]
+impl<P: PrefixMarker, DB: diesel::backend::Backend>
+    diesel::serialize::ToSql<diesel::sql_types::Text, DB> for TypedId<P>
+where
+    MagicTypeId: diesel::serialize::ToSql<diesel::sql_types::Text, DB>,
+{
+    fn to_sql<'b>(
+        &'b self,
+        out: &mut diesel::serialize::Output<'b, '_, DB>,
+    ) -> diesel::serialize::Result {
+        <MagicTypeId as diesel::serialize::ToSql<diesel::sql_types::Text, DB>>::to_sql(
+            &self.id, out,
+        )
+    }
+}
+
+/// Loads a `TypedId` from text holding a whole `MagicTypeId`, failing if its prefix is not
+/// [`P::PREFIX`](PrefixMarker::PREFIX).
+#[cfg(feature = "diesel")]
+impl<P: PrefixMarker, DB: diesel::backend::Backend>
+    diesel::deserialize::FromSql<diesel::sql_types::Text, DB> for TypedId<P>
+where
+    MagicTypeId: diesel::deserialize::FromSql<diesel::sql_types::Text, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+        let id =
+            <MagicTypeId as diesel::deserialize::FromSql<diesel::sql_types::Text, DB>>::from_sql(
+                bytes,
+            )?;
+        Ok(Self::try_from(id)?)
+    }
+}
+
+/// A `TypedId` is stored as a 16-byte blob holding the UUID of its suffix, since its prefix is
+/// fixed by `P`.
+///
+/// To store the whole identifier as text instead, bind
+/// [`as_magic_type_id()`](TypedId::as_magic_type_id); reading accepts either.
+#[cfg(feature = "rusqlite")]
+impl<P: PrefixMarker> rusqlite::types::ToSql for TypedId<P> {
+    fn to_sql(&self) -> rusqlite::Result<rusqlite::types::ToSqlOutput<'_>> {
+        rusqlite::types::ToSql::to_sql(self.suffix())
+    }
+}
+
+#[cfg(feature = "rusqlite")]
+impl<P: PrefixMarker> rusqlite::types::FromSql for TypedId<P> {
+    /// Reads a `TypedId` from a 16-byte blob, adding [`P::PREFIX`](PrefixMarker::PREFIX), or from
+    /// text holding a whole `MagicTypeId`, failing with the [`MagicTypeIdError`] if its prefix is
+    /// not `P::PREFIX`.
+    fn column_result(value: rusqlite::types::ValueRef<'_>) -> rusqlite::types::FromSqlResult<Self> {
+        match value {
+            rusqlite::types::ValueRef::Text(_) => Self::from_str(value.as_str()?)
+                .map_err(|e| rusqlite::types::FromSqlError::Other(Box::new(e))),
+            _ => TypeIdSuffix::column_result(value).map(Self::from_suffix),
+        }
+    }
+}
@@ -0,0 +1,182 @@
+//! Tests for the `axum` extractors.
+//!
+//! This module sends requests through an in-process router with
+//! `tower::ServiceExt::oneshot`, and verifies that identifiers are parsed
+//! from path and query parameters and that invalid or missing ones are
+//! rejected with a structured `400 Bad Request`.
+//!
+//! These tests only run when the "axum" feature is enabled.
+
+#![cfg(feature = "axum")]
+
+use axum::body::{to_bytes, Body};
+use axum::http::{Request, StatusCode};
+use axum::routing::get;
+use axum::Router;
+use mti::axum::{FromIdParams, IdParams, IdPath, IdQuery, IdRejection};
+use mti::prelude::*;
+use serde_json::Value;
+use tower::ServiceExt;
+
+struct User;
+impl PrefixMarker for User {
+    const PREFIX: &'static str = "user";
+}
+
+struct Order;
+impl PrefixMarker for Order {
+    const PREFIX: &'static str = "order";
+}
+
+type UserId = TypedId<User>;
+type OrderId = TypedId<Order>;
+
+struct Filter {
+    owner: UserId,
+    after: Option<MagicTypeId>,
+}
+
+impl FromIdParams for Filter {
+    fn from_id_params(params: &IdParams<'_>) -> Result<Self, IdRejection> {
+        Ok(Self {
+            owner: params.get("owner")?,
+            after: params.get_optional("after")?,
+        })
+    }
+}
+
+const USER: &str = "user_01h455vb4pex5vsknk084sn02q";
+const ORDER: &str = "order_01h455vb4pex5vsknk084sn02q";
+
+fn app() -> Router {
+    Router::new()
+        .route(
+            "/users/:user_id",
+            get(|IdPath(id): IdPath<UserId>| async move { id.to_string() }),
+        )
+        .route(
+            "/users/:user_id/orders/:order_id",
+            get(
+                |IdPath((user, order)): IdPath<(UserId, OrderId)>| async move {
+                    format!("{order} of {user}")
+                },
+            ),
+        )
+        .route(
+            "/orders",
+            get(|IdQuery(filter): IdQuery<Filter>| async move {
+                match filter.after {
+                    Some(after) => format!("{} after {after}", filter.owner),
+                    None => filter.owner.to_string(),
+                }
+            }),
+        )
+        .route(
+            "/mismatched/:a/:b",
+            get(|IdPath(id): IdPath<MagicTypeId>| async move { id.to_string() }),
+        )
+}
+
+async fn send(uri: &str) -> (StatusCode, String) {
+    let request = Request::builder().uri(uri).body(Body::empty()).unwrap();
+    let response = app().oneshot(request).await.unwrap();
+    let status = response.status();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, String::from_utf8(body.to_vec()).unwrap())
+}
+
+#[tokio::test]
+async fn test_path_parameters() {
+    let (status, body) = send(&format!("/users/{USER}")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, USER);
+
+    let (status, body) = send(&format!("/users/{USER}/orders/{ORDER}")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, format!("{ORDER} of {USER}"));
+}
+
+#[tokio::test]
+async fn test_path_prefix_mismatch_is_bad_request() {
+    let (status, body) = send(&format!("/users/{USER}/orders/{USER}")).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let body: Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(body["error"], "invalid_id");
+    assert_eq!(body["location"], "path");
+    assert_eq!(body["field"], "order_id");
+    assert_eq!(body["rule"], "prefix_mismatch");
+    assert_eq!(body["component"], "prefix");
+    assert_eq!(
+        body["message"],
+        "Prefix error: expected prefix 'order', found 'user'"
+    );
+}
+
+#[tokio::test]
+async fn test_path_invalid_suffix_reports_position() {
+    let (status, body) = send("/users/user_01h455vb4pex5vsknk084sn0uq").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let body: Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(body["field"], "user_id");
+    assert_eq!(body["rule"], "suffix_invalid_character");
+    assert_eq!(body["component"], "suffix");
+    assert_eq!(body["position"], 29);
+}
+
+#[tokio::test]
+async fn test_query_parameters() {
+    let (status, body) = send(&format!("/orders?owner={USER}&page=2")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, USER);
+
+    let (status, body) = send(&format!("/orders?after={ORDER}&owner={USER}")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, format!("{USER} after {ORDER}"));
+}
+
+#[tokio::test]
+async fn test_query_rejections() {
+    let (status, body) = send("/orders").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let body: Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(body["error"], "missing_id");
+    assert_eq!(body["location"], "query");
+    assert_eq!(body["field"], "owner");
+
+    let (status, body) = send(&format!(
+        "/orders?owner={USER}&after=ord3r_01h455vb4pex5vsknk084sn02q"
+    ))
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let body: Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(body["error"], "invalid_id");
+    assert_eq!(body["field"], "after");
+    assert_eq!(body["rule"], "prefix_invalid_character");
+}
+
+#[tokio::test]
+async fn test_wrong_parameter_count_is_server_error() {
+    let (status, _) = send(&format!("/mismatched/{USER}/{ORDER}")).await;
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+}
+
+#[cfg(feature = "macros")]
+#[tokio::test]
+async fn test_derived_identifiers() {
+    #[derive(Debug, Clone, PartialEq, Eq, Hash, MagicId)]
+    #[mti(prefix = "user")]
+    struct AccountId(MagicTypeId);
+
+    let app = Router::new().route(
+        "/accounts/:account_id",
+        get(|IdPath(id): IdPath<AccountId>| async move { id.to_string() }),
+    );
+    let request = Request::builder()
+        .uri(format!("/accounts/{ORDER}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
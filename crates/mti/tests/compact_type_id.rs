@@ -0,0 +1,103 @@
+//! Tests for `CompactTypeId`.
+//!
+//! This module verifies that the compact representation parses, formats,
+//! orders and hashes exactly like `MagicTypeId` and converts losslessly.
+use mti::prelude::*;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+fn hash_of<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn test_parse_and_display_roundtrip() {
+    for input in [
+        "user_01h455vb4pex5vsknk084sn02q",
+        "order_item_7zzzzzzzzzzzzzzzzzzzzzzzzz",
+        "00000000000000000000000000",
+    ] {
+        let id = CompactTypeId::from_str(input).unwrap();
+        assert_eq!(id.to_string(), input);
+    }
+}
+
+#[test]
+fn test_max_length_prefix() {
+    let prefix = "a".repeat(63);
+    let input = format!("{prefix}_01h455vb4pex5vsknk084sn02q");
+    let id = CompactTypeId::from_str(&input).unwrap();
+    assert_eq!(id.prefix(), prefix);
+    assert_eq!(id.to_string(), input);
+}
+
+#[test]
+fn test_parse_errors_match_magic_type_id() {
+    for input in [
+        "_01h455vb4pex5vsknk084sn02q",
+        "User_01h455vb4pex5vsknk084sn02q",
+        "user__01h455vb4pex5vsknk084sn02q",
+        "user_81h455vb4pex5vsknk084sn02q",
+        "user_01h455vb4pex5vsknk084sn02",
+        "user",
+        "",
+    ] {
+        assert_eq!(
+            CompactTypeId::from_str(input).unwrap_err(),
+            MagicTypeId::from_str(input).unwrap_err(),
+            "input: {input:?}"
+        );
+    }
+}
+
+#[test]
+fn test_lossless_conversions() {
+    let magic = "user".create_type_id::<V7>();
+    let compact = CompactTypeId::from(&magic);
+    assert_eq!(compact.prefix(), "user");
+    assert_eq!(compact.suffix(), *magic.suffix());
+    assert_eq!(compact.uuid(), magic.suffix().to_uuid());
+    assert_eq!(compact, magic);
+    assert_eq!(MagicTypeId::from(compact), magic);
+
+    let bare = MagicTypeId::from_str("01h455vb4pex5vsknk084sn02q").unwrap();
+    assert_eq!(CompactTypeId::from(bare.clone()).to_magic_type_id(), bare);
+}
+
+#[test]
+fn test_ordering_matches_magic_type_id() {
+    let inputs = [
+        "user_01h455vb4pex5vsknk084sn02q",
+        "order_01h455vb4pex5vsknk084sn02q",
+        "user_00000000000000000000000000",
+        "account_7zzzzzzzzzzzzzzzzzzzzzzzzz",
+        "01h455vb4pex5vsknk084sn02q",
+    ];
+    let mut magic: Vec<MagicTypeId> = inputs
+        .iter()
+        .map(|s| MagicTypeId::from_str(s).unwrap())
+        .collect();
+    let mut compact: Vec<CompactTypeId> = inputs
+        .iter()
+        .map(|s| CompactTypeId::from_str(s).unwrap())
+        .collect();
+    magic.sort();
+    compact.sort();
+
+    let magic: Vec<String> = magic.iter().map(ToString::to_string).collect();
+    let compact: Vec<String> = compact.iter().map(ToString::to_string).collect();
+    assert_eq!(magic, compact);
+}
+
+#[test]
+fn test_eq_and_hash() {
+    let a = CompactTypeId::from_str("user_01h455vb4pex5vsknk084sn02q").unwrap();
+    let b = CompactTypeId::from_str("user_01h455vb4pex5vsknk084sn02q").unwrap();
+    let c = CompactTypeId::from_str("users_01h455vb4pex5vsknk084sn02q").unwrap();
+    assert_eq!(a, b);
+    assert_eq!(hash_of(&a), hash_of(&b));
+    assert_ne!(a, c);
+}
@@ -0,0 +1,145 @@
+//! Integration tests for position-aware parse errors.
+//!
+//! These tests check that errors from the prefix and suffix crates carry the offending character
+//! and its position, and that `ParseDiagnostic` maps those positions onto the full identifier
+//! and renders them.
+
+use std::str::FromStr;
+
+use mti::prelude::*;
+
+fn diagnose(input: &str) -> ParseDiagnostic {
+    MagicTypeId::from_str(input).unwrap_err().with_input(input)
+}
+
+#[test]
+fn test_prefix_character_positions() {
+    let diagnostic = diagnose("user_Name_01h455vb4pex5vsknk084sn02q");
+    assert_eq!(
+        diagnostic.error(),
+        &MagicTypeIdError::Prefix(ValidationError::ContainsInvalidCharacters {
+            position: 5,
+            character: 'N'
+        })
+    );
+    assert_eq!(diagnostic.component(), IdComponent::Prefix);
+    assert_eq!(diagnostic.span(), 5..6);
+}
+
+#[test]
+fn test_suffix_character_positions_are_shifted() {
+    let diagnostic = diagnose("user_01h455vb4pex5vsknk084sn0uq");
+    assert_eq!(
+        diagnostic.error(),
+        &MagicTypeIdError::Suffix(DecodeError::InvalidSuffix(
+            InvalidSuffixReason::InvalidCharacter {
+                position: 24,
+                character: 'u'
+            }
+        ))
+    );
+    assert_eq!(diagnostic.component(), IdComponent::Suffix);
+    assert_eq!(diagnostic.span(), 29..30);
+
+    // Without a prefix the suffix starts at the beginning of the input
+    assert_eq!(diagnose("01h455vb4pex5vsknk084sn0uq").span(), 24..25);
+}
+
+#[test]
+fn test_multibyte_characters_are_spanned_whole() {
+    let diagnostic = diagnose("caf\u{e9}_01h455vb4pex5vsknk084sn02q");
+    assert_eq!(diagnostic.span(), 3..5);
+    assert_eq!(
+        diagnostic.render().lines().last(),
+        Some("     ^ not a lowercase ASCII letter or underscore")
+    );
+
+    let diagnostic = diagnose("user_01h455vb4pex5vsknk084sn\u{e9}q");
+    assert_eq!(diagnostic.span(), 28..30);
+}
+
+#[test]
+fn test_whole_component_spans() {
+    assert_eq!(diagnose("user_01h455vb4pex5vsknk084sn02").span(), 5..30);
+    assert_eq!(diagnose("user_81h455vb4pex5vsknk084sn02q").span(), 5..6);
+    assert_eq!(diagnose("9user_01h455vb4pex5vsknk084sn02q").span(), 0..1);
+    assert_eq!(diagnose("user9_01h455vb4pex5vsknk084sn02q").span(), 4..5);
+    assert_eq!(diagnose("user__01h455vb4pex5vsknk084sn02q").span(), 4..5);
+
+    // An empty prefix before the separator points at the separator
+    let diagnostic = diagnose("_01h455vb4pex5vsknk084sn02q");
+    assert_eq!(diagnostic.span(), 0..0);
+    assert!(diagnostic
+        .render()
+        .ends_with("\n  ^ must start with a lowercase letter"));
+
+    let long = format!("{}_01h455vb4pex5vsknk084sn02q", "a".repeat(70));
+    assert_eq!(diagnose(&long).span(), 63..70);
+}
+
+#[test]
+fn test_policy_errors_cover_their_component() {
+    let options = ParseOptions::strict().require_prefix("order");
+    let input = "user_00000000000000000000000000";
+
+    let diagnostic = MagicTypeId::parse_with(input, &options)
+        .unwrap_err()
+        .with_input(input);
+    assert_eq!(diagnostic.span(), 0..4);
+    assert_eq!(diagnostic.label(), "prefix not allowed");
+
+    let input = "order_00000000000000000000000000";
+    let diagnostic = MagicTypeId::parse_with(input, &options)
+        .unwrap_err()
+        .with_input(input);
+    assert_eq!(diagnostic.component(), IdComponent::Suffix);
+    assert_eq!(diagnostic.span(), 6..32);
+}
+
+#[test]
+fn test_render() {
+    assert_eq!(
+        diagnose("user_01h455vb4pex5vsknk084sn02").render(),
+        "Suffix error: Invalid `TypeID` suffix: Suffix must be exactly 26 characters long\n  \
+         user_01h455vb4pex5vsknk084sn02\n       \
+         ^^^^^^^^^^^^^^^^^^^^^^^^^ must be 26 characters long"
+    );
+}
+
+#[test]
+fn test_mismatched_input_does_not_panic() {
+    let error = MagicTypeId::from_str("user_01h455vb4pex5vsknk084sn0uq").unwrap_err();
+    let diagnostic = error.clone().with_input("short");
+    assert!(diagnostic.span().end <= "short".len());
+    let _ = diagnostic.render();
+
+    assert_eq!(MagicTypeIdError::from(diagnostic), error);
+}
+
+#[test]
+fn test_display_and_source() {
+    use std::error::Error;
+
+    let diagnostic = diagnose("user_01h455vb4pex5vsknk084sn0uq");
+    assert_eq!(diagnostic.to_string(), diagnostic.error().to_string());
+    assert!(diagnostic.source().is_some());
+}
+
+#[cfg(feature = "miette")]
+#[test]
+fn test_miette_diagnostic() {
+    use miette::Diagnostic;
+
+    let diagnostic = diagnose("user_01h455vb4pex5vsknk084sn0uq");
+    assert_eq!(
+        diagnostic.code().map(|code| code.to_string()),
+        Some("mti::suffix".to_string())
+    );
+    assert!(diagnostic.source_code().is_some());
+
+    let labels: Vec<_> = diagnostic.labels().unwrap().collect();
+    assert_eq!(labels.len(), 1);
+    assert_eq!(labels[0].offset(), 29);
+    assert_eq!(labels[0].len(), 1);
+    assert_eq!(labels[0].label(), Some("not in the base32 alphabet"));
+}
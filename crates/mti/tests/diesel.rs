@@ -0,0 +1,333 @@
+//! Tests for the `diesel` integration.
+//!
+//! This module stores identifiers in an in-memory `SQLite` database through
+//! Diesel, as text and as 16-byte UUID blobs, and verifies that they load
+//! back unchanged, work in filters, and that typed, derived and enum
+//! identifiers reject rows with another prefix.
+//!
+//! These tests only run when the "diesel" feature is enabled.
+
+#![cfg(feature = "diesel")]
+
+use std::str::FromStr;
+
+use diesel::connection::SimpleConnection;
+use diesel::prelude::*;
+use diesel::sql_types::BigInt;
+use diesel::sqlite::SqliteConnection;
+use mti::prelude::*;
+
+mod schema {
+    diesel::table! {
+        users (id) {
+            id -> Text,
+            name -> Text,
+            manager_id -> Nullable<Text>,
+        }
+    }
+
+    diesel::table! {
+        orders (id) {
+            id -> Binary,
+            user_id -> Text,
+        }
+    }
+}
+
+use schema::{orders, users};
+
+struct User;
+impl PrefixMarker for User {
+    const PREFIX: &'static str = "user";
+}
+
+struct Order;
+impl PrefixMarker for Order {
+    const PREFIX: &'static str = "order";
+}
+
+type UserId = TypedId<User>;
+type OrderId = TypedId<Order>;
+
+#[derive(Debug, PartialEq, Queryable, Insertable)]
+#[diesel(table_name = users)]
+struct UserRow {
+    id: MagicTypeId,
+    name: String,
+    manager_id: Option<MagicTypeId>,
+}
+
+#[derive(Debug, PartialEq, Queryable, Insertable)]
+#[diesel(table_name = orders)]
+struct OrderRow {
+    id: OrderId,
+    user_id: UserId,
+}
+
+fn connect() -> SqliteConnection {
+    let mut conn = SqliteConnection::establish(":memory:").unwrap();
+    conn.batch_execute(
+        "CREATE TABLE users (id TEXT PRIMARY KEY, name TEXT NOT NULL, manager_id TEXT);
+         CREATE TABLE orders (id BLOB PRIMARY KEY, user_id TEXT NOT NULL);",
+    )
+    .unwrap();
+    conn
+}
+
+#[test]
+fn test_magic_type_id_as_text() {
+    let mut conn = connect();
+    let manager = UserRow {
+        id: "user".create_type_id::<V7>(),
+        name: "Alice".to_string(),
+        manager_id: None,
+    };
+    let report = UserRow {
+        id: "user".create_type_id::<V7>(),
+        name: "Bob".to_string(),
+        manager_id: Some(manager.id.clone()),
+    };
+    let inserted = diesel::insert_into(users::table)
+        .values([&manager, &report])
+        .execute(&mut conn)
+        .unwrap();
+    assert_eq!(inserted, 2);
+
+    let loaded: UserRow = users::table
+        .filter(users::manager_id.eq(&manager.id))
+        .first(&mut conn)
+        .unwrap();
+    assert_eq!(loaded, report);
+
+    let storage: String = users::table
+        .select(diesel::dsl::sql::<diesel::sql_types::Text>("typeof(id)"))
+        .first(&mut conn)
+        .unwrap();
+    assert_eq!(storage, "text");
+}
+
+#[test]
+fn test_invalid_text_fails_to_load() {
+    let mut conn = connect();
+    let inserted = diesel::sql_query(
+        "INSERT INTO users (id, name) VALUES ('User_01h455vb4pex5vsknk084sn02q', 'Bob')",
+    )
+    .execute(&mut conn)
+    .unwrap();
+    assert_eq!(inserted, 1);
+
+    let result = users::table
+        .select(users::id)
+        .first::<MagicTypeId>(&mut conn);
+    assert!(matches!(
+        result,
+        Err(diesel::result::Error::DeserializationError(_))
+    ));
+}
+
+#[test]
+fn test_typed_id_as_binary() {
+    let mut conn = connect();
+    let order = OrderRow {
+        id: OrderId::new::<V7>(),
+        user_id: UserId::new::<V7>(),
+    };
+    let inserted = diesel::insert_into(orders::table)
+        .values(&order)
+        .execute(&mut conn)
+        .unwrap();
+    assert_eq!(inserted, 1);
+
+    let loaded: OrderRow = orders::table
+        .filter(orders::id.eq(&order.id))
+        .first(&mut conn)
+        .unwrap();
+    assert_eq!(loaded, order);
+
+    // The stored bytes are the UUID itself
+    let len: i64 = orders::table
+        .select(diesel::dsl::sql::<BigInt>("length(id)"))
+        .first(&mut conn)
+        .unwrap();
+    assert_eq!(len, 16);
+    let suffix: TypeIdSuffix = orders::table.select(orders::id).first(&mut conn).unwrap();
+    assert_eq!(&suffix, order.id.suffix());
+    let bytes: Vec<u8> = orders::table.select(orders::id).first(&mut conn).unwrap();
+    assert_eq!(bytes, order.id.suffix().to_uuid().as_bytes());
+}
+
+#[test]
+fn test_typed_id_rejects_other_prefix() {
+    let mut conn = connect();
+    let user = UserRow {
+        id: MagicTypeId::from_str("user_01h455vb4pex5vsknk084sn02q").unwrap(),
+        name: "Carol".to_string(),
+        manager_id: None,
+    };
+    let inserted = diesel::insert_into(users::table)
+        .values(&user)
+        .execute(&mut conn)
+        .unwrap();
+    assert_eq!(inserted, 1);
+
+    let user_id: UserId = users::table.select(users::id).first(&mut conn).unwrap();
+    assert_eq!(user_id, user.id);
+
+    let result = users::table.select(users::id).first::<OrderId>(&mut conn);
+    let Err(diesel::result::Error::DeserializationError(error)) = result else {
+        panic!("expected a deserialization error, got {result:?}");
+    };
+    // Diesel names the field and keeps the prefix error as the source
+    assert_eq!(
+        error.source().unwrap().to_string(),
+        "Prefix error: expected prefix 'order', found 'user'"
+    );
+}
+
+#[cfg(feature = "macros")]
+#[test]
+fn test_derived_ids() {
+    #[derive(Debug, Clone, PartialEq, MagicId)]
+    #[mti(prefix = "user")]
+    struct DerivedUserId(MagicTypeId);
+
+    #[derive(Debug, Clone, PartialEq, MagicId)]
+    #[mti(prefix = "order")]
+    struct DerivedOrderId(MagicTypeId);
+
+    #[derive(Debug, PartialEq, Queryable, Insertable)]
+    #[diesel(table_name = users)]
+    struct DerivedUserRow {
+        id: DerivedUserId,
+        name: String,
+        manager_id: Option<DerivedUserId>,
+    }
+
+    #[derive(Debug, PartialEq, Queryable, Insertable)]
+    #[diesel(table_name = orders)]
+    struct DerivedOrderRow {
+        id: DerivedOrderId,
+        user_id: DerivedUserId,
+    }
+
+    let mut conn = connect();
+    let user = DerivedUserRow {
+        id: DerivedUserId::new(),
+        name: "Dave".to_string(),
+        manager_id: Some(DerivedUserId::new()),
+    };
+    let order = DerivedOrderRow {
+        id: DerivedOrderId::new(),
+        user_id: user.id.clone(),
+    };
+    assert_eq!(
+        diesel::insert_into(users::table)
+            .values(&user)
+            .execute(&mut conn)
+            .unwrap(),
+        1
+    );
+    assert_eq!(
+        diesel::insert_into(orders::table)
+            .values(&order)
+            .execute(&mut conn)
+            .unwrap(),
+        1
+    );
+
+    let loaded: DerivedUserRow = users::table
+        .filter(users::id.eq(&user.id))
+        .first(&mut conn)
+        .unwrap();
+    assert_eq!(loaded, user);
+    let loaded: DerivedOrderRow = orders::table
+        .filter(orders::id.eq(&order.id))
+        .first(&mut conn)
+        .unwrap();
+    assert_eq!(loaded, order);
+
+    // The text column is prefix-checked, the binary column takes the prefix of the type
+    let result = users::table
+        .select(users::id)
+        .first::<DerivedOrderId>(&mut conn);
+    assert!(matches!(
+        result,
+        Err(diesel::result::Error::DeserializationError(_))
+    ));
+    let id: DerivedUserId = orders::table.select(orders::id).first(&mut conn).unwrap();
+    assert_eq!(id.suffix(), order.id.suffix());
+}
+
+#[test]
+fn test_id_enum_as_text() {
+    mti::id_enum! {
+        #[derive(Debug, PartialEq)]
+        enum AnyId: AnyIdKind {
+            User(UserId) = "user",
+            Order = "order",
+        }
+    }
+
+    let mut conn = connect();
+    let user_id = UserId::new::<V7>();
+    let order_id = OrderId::new::<V7>();
+    assert_eq!(
+        diesel::insert_into(users::table)
+            .values((
+                users::id.eq(AnyId::User(user_id.clone())),
+                users::name.eq("Erin"),
+            ))
+            .execute(&mut conn)
+            .unwrap(),
+        1
+    );
+    assert_eq!(
+        diesel::insert_into(orders::table)
+            .values((
+                orders::id.eq(&order_id),
+                orders::user_id.eq(order_id.as_magic_type_id())
+            ))
+            .execute(&mut conn)
+            .unwrap(),
+        1
+    );
+
+    let loaded: AnyId = users::table.select(users::id).first(&mut conn).unwrap();
+    assert_eq!(loaded, AnyId::User(user_id));
+    let loaded: AnyId = orders::table
+        .select(orders::user_id)
+        .first(&mut conn)
+        .unwrap();
+    assert_eq!(loaded.kind(), AnyIdKind::Order);
+
+    assert_eq!(
+        diesel::sql_query(
+            "INSERT INTO users (id, name) VALUES ('invoice_01h455vb4pex5vsknk084sn02q', 'Frank')",
+        )
+        .execute(&mut conn)
+        .unwrap(),
+        1
+    );
+    let result = users::table
+        .select(users::id)
+        .filter(users::name.eq("Frank"))
+        .first::<AnyId>(&mut conn);
+    assert!(matches!(
+        result,
+        Err(diesel::result::Error::DeserializationError(_))
+    ));
+}
+
+#[test]
+fn test_uuid_sql_type_on_postgres() {
+    use diesel::deserialize::FromSql;
+    use diesel::pg::Pg;
+    use diesel::serialize::ToSql;
+    use diesel::sql_types::Uuid;
+
+    fn assert_uuid_column<T: ToSql<Uuid, Pg> + FromSql<Uuid, Pg>>() {}
+
+    // There is no PostgreSQL server here, so this only checks that the impls exist
+    assert_uuid_column::<TypeIdSuffix>();
+    assert_uuid_column::<OrderId>();
+}
@@ -0,0 +1,133 @@
+//! Tests for enums declared with `id_enum!`.
+//!
+//! This module verifies that parsing routes identifiers to the variant of
+//! their prefix, that typed payloads are supported, and that the generated
+//! accessors, conversions and serialization agree with `MagicTypeId`.
+
+use mti::prelude::*;
+use std::str::FromStr;
+
+struct Order;
+impl PrefixMarker for Order {
+    const PREFIX: &'static str = "order";
+}
+
+id_enum! {
+    /// An identifier of any entity in the tests.
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub enum AnyId: AnyIdKind {
+        /// A user.
+        User = "user",
+        /// An order.
+        Order(TypedId<Order>) = "order",
+        /// A line item of an order.
+        OrderItem = "order_item",
+    }
+}
+
+#[test]
+fn test_parse_routes_on_prefix() {
+    let user = AnyId::from_str("user_01h455vb4pex5vsknk084sn02q").unwrap();
+    assert_eq!(user.kind(), AnyIdKind::User);
+    assert!(matches!(&user, AnyId::User(id) if id.as_str() == "user_01h455vb4pex5vsknk084sn02q"));
+
+    let order = AnyId::parse("order_01h455vb4pex5vsknk084sn02q").unwrap();
+    assert_eq!(order.kind(), AnyIdKind::Order);
+    assert!(matches!(&order, AnyId::Order(id) if id.prefix().as_str() == "order"));
+
+    let item: AnyId = "order_item_01h455vb4pex5vsknk084sn02q".parse().unwrap();
+    assert_eq!(item.kind(), AnyIdKind::OrderItem);
+}
+
+#[test]
+fn test_parse_rejects_undeclared_prefix() {
+    assert_eq!(
+        AnyId::parse("invoice_01h455vb4pex5vsknk084sn02q"),
+        Err(MagicTypeIdError::PrefixNotAllowed {
+            allowed: vec![
+                "user".to_string(),
+                "order".to_string(),
+                "order_item".to_string()
+            ],
+            found: "invoice".to_string(),
+        })
+    );
+    assert!(matches!(
+        AnyId::parse("01h455vb4pex5vsknk084sn02q"),
+        Err(MagicTypeIdError::PrefixNotAllowed { ref found, .. }) if found.is_empty()
+    ));
+    assert!(matches!(
+        AnyId::parse("user_01h455vb4pex5vsknk084sn0uq"),
+        Err(MagicTypeIdError::Suffix(_))
+    ));
+}
+
+#[test]
+fn test_accessors() {
+    let input = "order_01h455vb4pex5vsknk084sn02q";
+    let untyped = MagicTypeId::from_str(input).unwrap();
+    let id = AnyId::parse(input).unwrap();
+
+    assert_eq!(id.prefix(), untyped.prefix());
+    assert_eq!(id.suffix(), untyped.suffix());
+    assert_eq!(id.as_str(), input);
+    assert_eq!(id.as_magic_type_id(), &untyped);
+    assert_eq!(id.to_string(), input);
+    assert_eq!(AsRef::<str>::as_ref(&id), input);
+    assert_eq!(id.into_inner(), untyped);
+}
+
+#[test]
+fn test_kinds() {
+    assert_eq!(
+        AnyIdKind::ALL,
+        &[AnyIdKind::User, AnyIdKind::Order, AnyIdKind::OrderItem]
+    );
+    assert_eq!(AnyId::PREFIXES, &["user", "order", "order_item"]);
+    for kind in AnyIdKind::ALL {
+        let id = kind.prefix().create_type_id::<V7>();
+        assert_eq!(AnyId::try_from(id).unwrap().kind(), *kind);
+    }
+}
+
+#[test]
+fn test_magic_type_id_conversions() {
+    let untyped = "user".create_type_id::<V7>();
+    let id = AnyId::try_from(untyped.clone()).unwrap();
+    assert_eq!(MagicTypeId::from(id.clone()), untyped);
+    assert_eq!(AsRef::<MagicTypeId>::as_ref(&id), &untyped);
+
+    assert!(AnyId::try_from("invoice".create_type_id::<V7>()).is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_routes_on_prefix() {
+    let id = AnyId::parse("order_01h455vb4pex5vsknk084sn02q").unwrap();
+    let json = serde_json::to_string(&id).unwrap();
+    assert_eq!(json, "\"order_01h455vb4pex5vsknk084sn02q\"");
+    assert_eq!(serde_json::from_str::<AnyId>(&json).unwrap(), id);
+
+    assert!(serde_json::from_str::<AnyId>("\"invoice_01h455vb4pex5vsknk084sn02q\"").is_err());
+}
+
+#[cfg(feature = "macros")]
+#[test]
+fn test_derived_payload() {
+    #[derive(Debug, Clone, PartialEq, Eq, MagicId)]
+    #[mti(prefix = "user")]
+    struct UserId(MagicTypeId);
+
+    id_enum! {
+        #[derive(Debug)]
+        enum Actor: ActorKind {
+            User(UserId) = "user",
+            System = "system",
+        }
+    }
+
+    let actor = Actor::parse("user_01h455vb4pex5vsknk084sn02q").unwrap();
+    assert!(matches!(&actor, Actor::User(id) if id.as_str() == actor.as_str()));
+    assert_eq!(actor.kind(), ActorKind::User);
+    assert_eq!(ActorKind::System.prefix(), "system");
+}
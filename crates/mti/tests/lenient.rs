@@ -0,0 +1,211 @@
+//! Integration tests for `MagicTypeId::parse_lenient`.
+//!
+//! These tests check that human-entered identifiers are repaired into their canonical form, that
+//! every repair is reported with its position in the original input, and that input which cannot
+//! be repaired still fails like strict parsing.
+
+use std::str::FromStr;
+
+use mti::prelude::*;
+
+const CANONICAL: &str = "user_01h455vb4pex5vsknk084sn02q";
+
+fn lenient(input: &str) -> Result<LenientParse, MagicTypeIdError> {
+    MagicTypeId::parse_lenient(input, &LenientOptions::new())
+}
+
+fn quoted(input: &str) -> Result<LenientParse, MagicTypeIdError> {
+    MagicTypeId::parse_lenient(input, &LenientOptions::new().strip_quotes(true))
+}
+
+#[test]
+fn test_canonical_input_is_unchanged() {
+    let parsed = lenient(CANONICAL).unwrap();
+    assert_eq!(parsed.id(), &MagicTypeId::from_str(CANONICAL).unwrap());
+    assert!(parsed.corrections().is_empty());
+    assert!(!parsed.was_corrected());
+}
+
+#[test]
+fn test_uppercase_is_folded() {
+    let parsed = lenient("USER_01H455VB4PEX5VSKNK084SN02Q").unwrap();
+    assert_eq!(parsed.id().as_str(), CANONICAL);
+    assert_eq!(
+        parsed.corrections()[..2],
+        [
+            Correction::Lowercased {
+                position: 0,
+                found: 'U'
+            },
+            Correction::Lowercased {
+                position: 1,
+                found: 'S'
+            },
+        ]
+    );
+    // Every letter is reported, and digits and the separator are not
+    assert_eq!(parsed.corrections().len(), 4 + 14);
+}
+
+#[test]
+fn test_look_alikes_are_replaced_in_the_suffix() {
+    let parsed = lenient("user_o1h455vb4pex5vskNk084snO2q").unwrap();
+    assert_eq!(parsed.id().as_str(), CANONICAL);
+    assert_eq!(
+        parsed.corrections(),
+        [
+            Correction::ReplacedLookAlike {
+                position: 5,
+                found: 'o',
+                replacement: '0'
+            },
+            Correction::Lowercased {
+                position: 21,
+                found: 'N'
+            },
+            Correction::ReplacedLookAlike {
+                position: 28,
+                found: 'O',
+                replacement: '0'
+            },
+        ]
+    );
+
+    let parsed = lenient("0Ih455vb4pex5vsknk084sn0lq").unwrap();
+    assert_eq!(parsed.id().as_str(), "01h455vb4pex5vsknk084sn01q");
+    assert_eq!(
+        parsed.corrections(),
+        [
+            Correction::ReplacedLookAlike {
+                position: 1,
+                found: 'I',
+                replacement: '1'
+            },
+            Correction::ReplacedLookAlike {
+                position: 24,
+                found: 'l',
+                replacement: '1'
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_look_alikes_are_kept_in_the_prefix() {
+    let parsed = lenient("Blog_Oil_01h455vb4pex5vsknk084sn02q").unwrap();
+    assert_eq!(parsed.id().as_str(), "blog_oil_01h455vb4pex5vsknk084sn02q");
+    assert_eq!(
+        parsed.corrections(),
+        [
+            Correction::Lowercased {
+                position: 0,
+                found: 'B'
+            },
+            Correction::Lowercased {
+                position: 5,
+                found: 'O'
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_whitespace_is_trimmed() {
+    let parsed = lenient("\t user_01h455vb4pex5vsknk084sn02q\r\n").unwrap();
+    assert_eq!(parsed.id().as_str(), CANONICAL);
+    assert_eq!(parsed.corrections(), [Correction::TrimmedWhitespace]);
+
+    // Positions refer to the original input
+    let parsed = lenient("  user_01h455vb4pex5vsknk084sn02Q").unwrap();
+    assert_eq!(
+        parsed.corrections(),
+        [
+            Correction::TrimmedWhitespace,
+            Correction::Lowercased {
+                position: 32,
+                found: 'Q'
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_quotes_are_stripped_only_when_enabled() {
+    assert!(matches!(
+        lenient("\"user_01h455vb4pex5vsknk084sn02q\""),
+        Err(MagicTypeIdError::Prefix(_))
+    ));
+
+    for (input, quote) in [
+        ("\"user_01h455vb4pex5vsknk084sn02q\"", '"'),
+        ("'user_01h455vb4pex5vsknk084sn02q'", '\''),
+        ("`user_01h455vb4pex5vsknk084sn02q`", '`'),
+        (
+            "\u{201C}user_01h455vb4pex5vsknk084sn02q\u{201D}",
+            '\u{201C}',
+        ),
+        (
+            "\u{2018}user_01h455vb4pex5vsknk084sn02q\u{2019}",
+            '\u{2018}',
+        ),
+    ] {
+        let parsed = quoted(input).unwrap();
+        assert_eq!(parsed.id().as_str(), CANONICAL, "{input}");
+        assert_eq!(parsed.corrections(), [Correction::StrippedQuotes { quote }]);
+    }
+
+    // Mismatched quotes are left alone
+    assert!(quoted("\"user_01h455vb4pex5vsknk084sn02q'").is_err());
+}
+
+#[test]
+fn test_quotes_and_whitespace_combine() {
+    let parsed = quoted(" \u{201C} USER_01h455vb4pex5vsknk084sn02q \u{201D}\n").unwrap();
+    assert_eq!(parsed.id().as_str(), CANONICAL);
+    assert_eq!(
+        parsed.corrections()[..3],
+        [
+            Correction::TrimmedWhitespace,
+            Correction::StrippedQuotes { quote: '\u{201C}' },
+            // One space, a three-byte quote and another space precede the identifier
+            Correction::Lowercased {
+                position: 5,
+                found: 'U'
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_unrepairable_input_fails_like_strict_parsing() {
+    for input in [
+        "user_01h455vb4pex5vsknk084sn02",
+        "user_01h455vb4pex5vsknk084sn0uq",
+        "user-name_01h455vb4pex5vsknk084sn02q",
+        "user_81h455vb4pex5vsknk084sn02q",
+        "",
+    ] {
+        assert_eq!(
+            lenient(input).map(LenientParse::into_id),
+            MagicTypeId::from_str(input),
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn test_correction_messages() {
+    assert_eq!(
+        Correction::ReplacedLookAlike {
+            position: 5,
+            found: 'O',
+            replacement: '0'
+        }
+        .to_string(),
+        "replaced 'O' with '0' at position 5"
+    );
+    assert_eq!(
+        Correction::StrippedQuotes { quote: '"' }.to_string(),
+        "removed surrounding \" quotes"
+    );
+}
@@ -0,0 +1,113 @@
+//! Tests for the procedural macros of the `macros` feature.
+//!
+//! This module verifies that derived identifier newtypes enforce their
+//! prefix and expose the generated constructors and conversions, and that
+//! the `typeid!` and `prefix!` literals match their runtime-parsed equivalents.
+#![cfg(feature = "macros")]
+
+use mti::prelude::*;
+use std::collections::HashSet;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, MagicId)]
+#[mti(prefix = "user")]
+struct UserId(MagicTypeId);
+
+#[derive(Debug, Clone, PartialEq, Eq, MagicId)]
+#[mti(prefix = "order_item", version = "v4")]
+struct OrderItemId {
+    id: MagicTypeId,
+}
+
+#[test]
+fn test_new_uses_prefix_and_version() {
+    let user_id = UserId::new();
+    assert_eq!(UserId::PREFIX, "user");
+    assert_eq!(user_id.prefix().as_str(), "user");
+    assert_eq!(user_id.suffix().to_uuid().get_version_num(), 7);
+
+    let item_id = OrderItemId::default();
+    assert_eq!(item_id.prefix().as_str(), "order_item");
+    assert_eq!(item_id.suffix().to_uuid().get_version_num(), 4);
+}
+
+#[test]
+fn test_from_uuid() {
+    let uuid = Uuid::new_v4();
+    let item_id = OrderItemId::from_uuid(uuid);
+    assert_eq!(item_id.suffix().to_uuid(), uuid);
+    assert!(item_id.as_str().starts_with("order_item_"));
+}
+
+#[test]
+fn test_parse_and_display_roundtrip() {
+    let input = "user_01h455vb4pex5vsknk084sn02q";
+    let user_id = UserId::parse(input).unwrap();
+    assert_eq!(user_id.to_string(), input);
+    assert_eq!(UserId::from_str(input).unwrap(), user_id);
+    assert_eq!(AsRef::<str>::as_ref(&user_id), input);
+}
+
+#[test]
+fn test_parse_rejects_other_prefix() {
+    let result = UserId::parse("order_01h455vb4pex5vsknk084sn02q");
+    assert!(matches!(
+        result,
+        Err(MagicTypeIdError::PrefixMismatch { ref expected, ref found })
+            if expected == "user" && found == "order"
+    ));
+
+    assert!(matches!(
+        UserId::parse("user_invalid"),
+        Err(MagicTypeIdError::Suffix(_))
+    ));
+}
+
+#[test]
+fn test_magic_type_id_conversions() {
+    let user_id = UserId::new();
+    let untyped: MagicTypeId = user_id.clone().into();
+    assert_eq!(&untyped, AsRef::<MagicTypeId>::as_ref(&user_id));
+    assert_eq!(UserId::try_from(untyped).unwrap(), user_id);
+    assert_eq!(user_id.clone().into_inner(), *user_id);
+
+    let other = "order".create_type_id::<V7>();
+    assert!(UserId::try_from(other).is_err());
+}
+
+#[test]
+fn test_user_derives_are_preserved() {
+    let mut ids: Vec<UserId> = (0..10).map(|_| UserId::new()).collect();
+    let unique: HashSet<_> = ids.iter().cloned().collect();
+    assert_eq!(unique.len(), ids.len());
+
+    ids.sort();
+    assert!(ids.windows(2).all(|pair| pair[0] <= pair[1]));
+}
+
+#[test]
+fn test_typeid_literal_matches_runtime_parse() {
+    let literal = typeid!("user_01h455vb4pex5vsknk084sn02q");
+    let parsed = MagicTypeId::from_str("user_01h455vb4pex5vsknk084sn02q").unwrap();
+    assert_eq!(literal, parsed);
+    assert_eq!(literal.as_str(), parsed.as_str());
+
+    let nested = typeid!("order_item_7zzzzzzzzzzzzzzzzzzzzzzzzz");
+    assert_eq!(nested.prefix().as_str(), "order_item");
+    assert_eq!(nested.suffix().to_uuid(), Uuid::max());
+}
+
+#[test]
+fn test_typeid_literal_without_prefix() {
+    let literal = typeid!("00000000000000000000000000");
+    assert!(literal.prefix().is_empty());
+    assert_eq!(literal.as_str(), "00000000000000000000000000");
+    assert_eq!(literal.suffix().to_uuid(), Uuid::nil());
+}
+
+#[test]
+fn test_prefix_literal() {
+    let prefix = prefix!("order_item");
+    assert_eq!(prefix, TypeIdPrefix::from_str("order_item").unwrap());
+    assert_eq!(UserId::PREFIX, prefix!("user").as_str());
+}
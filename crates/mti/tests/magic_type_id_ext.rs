@@ -70,4 +70,108 @@ mod tests {
         let invalid_create = "123".try_create_type_id::<V4>();
         assert!(invalid_create.is_err());
     }
+
+    #[test]
+    fn test_create_type_id_monotonic_under_contention() {
+        use std::sync::Arc;
+        use std::thread;
+
+        let generator = Arc::new(MonotonicGenerator::new());
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let generator = Arc::clone(&generator);
+                thread::spawn(move || {
+                    (0..5_000)
+                        .map(|_| "event".create_type_id_monotonic(&generator))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        let mut all = Vec::new();
+        for handle in handles {
+            let ids = handle.join().unwrap();
+            assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+            assert!(ids
+                .windows(2)
+                .all(|pair| pair[0].as_str() < pair[1].as_str()));
+            all.extend(ids);
+        }
+
+        all.sort();
+        all.dedup();
+        assert_eq!(all.len(), 20_000);
+    }
+
+    #[test]
+    fn test_try_create_type_id_monotonic() {
+        let generator = MonotonicGenerator::new();
+
+        let first = "event".try_create_type_id_monotonic(&generator).unwrap();
+        assert!("Invalid Prefix!"
+            .try_create_type_id_monotonic(&generator)
+            .is_err());
+        let second = "Event!".create_type_id_monotonic(&generator);
+
+        assert_eq!(second.prefix().as_str(), "event");
+        assert!(first < second);
+        assert_eq!(first.suffix().to_uuid().get_version_num(), 7);
+    }
+
+    #[test]
+    fn test_create_type_id_with_seeded_source_is_reproducible() {
+        use std::time::{Duration, UNIX_EPOCH};
+
+        let source = SeededIdSource::new(2024)
+            .with_start_time(UNIX_EPOCH + Duration::from_secs(1_700_000_000))
+            .with_tick(Duration::from_millis(1));
+
+        let ids: Vec<String> = (0..3)
+            .map(|_| {
+                "user"
+                    .create_type_id_with_source::<V7, _>(&source)
+                    .to_string()
+            })
+            .collect();
+
+        // The same seed and clock produce the same IDs on every run and every platform
+        assert_eq!(
+            ids,
+            [
+                "user_01hf7yat00eqp8xy7chxpszmqj",
+                "user_01hf7yat01fyjspp5zfhqmrpdr",
+                "user_01hf7yat02f91vcs35r6gd9ttt",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_try_create_type_id_with_source() {
+        let source = SeededIdSource::new(1);
+        let replay = SeededIdSource::new(1);
+
+        assert!("Invalid Prefix!"
+            .try_create_type_id_with_source::<V4, _>(&source)
+            .is_err());
+        // A rejected prefix does not consume randomness from the source
+        assert_eq!(
+            "user"
+                .try_create_type_id_with_source::<V4, _>(&source)
+                .unwrap(),
+            "user".create_type_id_with_source::<V4, _>(&replay)
+        );
+    }
+
+    #[test]
+    fn test_create_type_id_monotonic_with_seeded_source() {
+        let generator = MonotonicGenerator::with_source(SeededIdSource::new(3));
+        let replay = MonotonicGenerator::with_source(SeededIdSource::new(3));
+
+        for _ in 0..100 {
+            assert_eq!(
+                "event".create_type_id_monotonic(&generator),
+                "event".create_type_id_monotonic(&replay)
+            );
+        }
+    }
 }
@@ -0,0 +1,93 @@
+//! Tests for `MagicTypeIdRef`.
+//!
+//! This module verifies that the borrowed view validates exactly like
+//! `MagicTypeId`, borrows from its input, and converts to an owned id.
+use mti::prelude::*;
+use std::str::FromStr;
+
+#[test]
+fn test_parse_borrows_components() {
+    let input = String::from("order_item_01h455vb4pex5vsknk084sn02q");
+    let id = MagicTypeIdRef::parse(&input).unwrap();
+
+    assert_eq!(id.prefix(), "order_item");
+    assert_eq!(id.suffix_str(), "01h455vb4pex5vsknk084sn02q");
+    assert_eq!(id.as_str(), input);
+    assert!(std::ptr::eq(id.as_str(), input.as_str()));
+    assert_eq!(id.suffix().to_string(), id.suffix_str());
+    assert_eq!(id.uuid(), id.suffix().to_uuid());
+}
+
+#[test]
+fn test_parse_without_prefix() {
+    let id = MagicTypeIdRef::parse("00000000000000000000000000").unwrap();
+    assert_eq!(id.prefix(), "");
+    assert_eq!(id.uuid(), Uuid::nil());
+}
+
+#[test]
+fn test_parse_errors_match_magic_type_id() {
+    for input in [
+        "_01h455vb4pex5vsknk084sn02q",
+        "User_01h455vb4pex5vsknk084sn02q",
+        "user__01h455vb4pex5vsknk084sn02q",
+        "user_81h455vb4pex5vsknk084sn02q",
+        "user_01h455vb4pex5vsknk084sn02",
+        "user",
+        "",
+    ] {
+        assert_eq!(
+            MagicTypeIdRef::parse(input).unwrap_err(),
+            MagicTypeId::from_str(input).unwrap_err(),
+            "input: {input:?}"
+        );
+    }
+}
+
+#[test]
+fn test_to_owned_roundtrip() {
+    let input = "user_01h455vb4pex5vsknk084sn02q";
+    let id = MagicTypeIdRef::parse(input).unwrap();
+    let owned = id.to_owned();
+
+    assert_eq!(owned, MagicTypeId::from_str(input).unwrap());
+    assert_eq!(id, owned);
+    assert_eq!(owned, id);
+    assert_eq!(MagicTypeId::from(id).as_str(), input);
+}
+
+#[test]
+fn test_ordering_matches_magic_type_id() {
+    let inputs = [
+        "user_01h455vb4pex5vsknk084sn02q",
+        "order_01h455vb4pex5vsknk084sn02q",
+        "user_00000000000000000000000000",
+        "01h455vb4pex5vsknk084sn02q",
+    ];
+    let mut owned: Vec<MagicTypeId> = inputs
+        .iter()
+        .map(|s| MagicTypeId::from_str(s).unwrap())
+        .collect();
+    let mut borrowed: Vec<MagicTypeIdRef<'_>> = inputs
+        .iter()
+        .map(|s| MagicTypeIdRef::parse(s).unwrap())
+        .collect();
+    owned.sort();
+    borrowed.sort();
+
+    let owned: Vec<&str> = owned.iter().map(MagicTypeId::as_str).collect();
+    let borrowed: Vec<&str> = borrowed.iter().map(MagicTypeIdRef::as_str).collect();
+    assert_eq!(owned, borrowed);
+}
+
+#[test]
+fn test_ext_methods_keep_partial_validation() {
+    // Only the requested component is validated
+    assert_eq!("prefix_invalid".prefix_str().unwrap(), "prefix");
+    assert_eq!(
+        "Invalid_01h455vb4pex5vsknk084sn02q".suffix_str().unwrap(),
+        "01h455vb4pex5vsknk084sn02q"
+    );
+    assert!("_01h455vb4pex5vsknk084sn02q".prefix_str().is_err());
+    assert!("".suffix_str().is_err());
+}
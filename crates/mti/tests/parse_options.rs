@@ -0,0 +1,221 @@
+//! Integration tests for `MagicTypeId::parse_with` and `ParseOptions`.
+//!
+//! These tests check that every rule of `ParseOptions` rejects identifiers with its own error
+//! variant, and that the default options accept exactly what `MagicTypeId::from_str` accepts.
+
+use std::str::FromStr;
+
+use mti::prelude::*;
+
+const V7_ID: &str = "user_01h455vb4pex5vsknk084sn02q";
+const NIL_ID: &str = "user_00000000000000000000000000";
+const MAX_ID: &str = "user_7zzzzzzzzzzzzzzzzzzzzzzzzz";
+
+fn with_uuid(prefix: &str, uuid: Uuid) -> String {
+    format!("{prefix}_{}", TypeIdSuffix::from(uuid))
+}
+
+#[test]
+fn test_default_options_match_from_str() {
+    let options = ParseOptions::default();
+    assert_eq!(options, ParseOptions::new());
+
+    for input in [
+        V7_ID,
+        NIL_ID,
+        MAX_ID,
+        "01h455vb4pex5vsknk084sn02q",
+        "user_81h455vb4pex5vsknk084sn02q",
+        "User_01h455vb4pex5vsknk084sn02q",
+        "_01h455vb4pex5vsknk084sn02q",
+    ] {
+        assert_eq!(
+            MagicTypeId::parse_with(input, &options),
+            MagicTypeId::from_str(input),
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn test_malformed_input_reports_parse_errors() {
+    let options = ParseOptions::strict().require_prefix("user");
+    assert!(matches!(
+        MagicTypeId::parse_with("User_01h455vb4pex5vsknk084sn02q", &options),
+        Err(MagicTypeIdError::Prefix(_))
+    ));
+    assert!(matches!(
+        MagicTypeId::parse_with("user_01h455vb4pex5vsknk084sn02", &options),
+        Err(MagicTypeIdError::Suffix(_))
+    ));
+}
+
+#[test]
+fn test_prefix_rules() {
+    let exact = ParseOptions::new().require_prefix("user");
+    assert!(MagicTypeId::parse_with(V7_ID, &exact).is_ok());
+    assert_eq!(
+        MagicTypeId::parse_with("01h455vb4pex5vsknk084sn02q", &exact),
+        Err(MagicTypeIdError::PrefixMismatch {
+            expected: "user".to_string(),
+            found: String::new(),
+        })
+    );
+
+    let set = ParseOptions::new().allowed_prefixes(["admin", "user"]);
+    assert!(MagicTypeId::parse_with(V7_ID, &set).is_ok());
+    assert_eq!(
+        MagicTypeId::parse_with("order_01h455vb4pex5vsknk084sn02q", &set),
+        Err(MagicTypeIdError::PrefixNotAllowed {
+            allowed: vec!["admin".to_string(), "user".to_string()],
+            found: "order".to_string(),
+        })
+    );
+
+    // The last prefix rule wins
+    let replaced = set.require_prefix("order");
+    assert!(MagicTypeId::parse_with("order_01h455vb4pex5vsknk084sn02q", &replaced).is_ok());
+    assert!(MagicTypeId::parse_with(V7_ID, &replaced).is_err());
+
+    // An empty prefix set rejects everything
+    let empty_set = ParseOptions::new().allowed_prefixes(Vec::<String>::new());
+    assert!(matches!(
+        MagicTypeId::parse_with(V7_ID, &empty_set),
+        Err(MagicTypeIdError::PrefixNotAllowed { .. })
+    ));
+}
+
+#[test]
+fn test_empty_prefix_rule() {
+    let options = ParseOptions::new().allow_empty_prefix(false);
+    assert!(MagicTypeId::parse_with(V7_ID, &options).is_ok());
+    assert_eq!(
+        MagicTypeId::parse_with("01h455vb4pex5vsknk084sn02q", &options),
+        Err(MagicTypeIdError::EmptyPrefixNotAllowed)
+    );
+
+    // The empty prefix can be listed explicitly
+    let set = ParseOptions::new().allowed_prefixes(["", "user"]);
+    assert!(MagicTypeId::parse_with("01h455vb4pex5vsknk084sn02q", &set).is_ok());
+}
+
+#[test]
+fn test_nil_and_max_rules() {
+    let options = ParseOptions::new().allow_nil(false).allow_max(false);
+    assert!(MagicTypeId::parse_with(V7_ID, &options).is_ok());
+    assert_eq!(
+        MagicTypeId::parse_with(NIL_ID, &options),
+        Err(MagicTypeIdError::NilNotAllowed)
+    );
+    assert_eq!(
+        MagicTypeId::parse_with(MAX_ID, &options),
+        Err(MagicTypeIdError::MaxNotAllowed)
+    );
+
+    let nil_only = ParseOptions::new().allow_max(false);
+    assert!(MagicTypeId::parse_with(NIL_ID, &nil_only).is_ok());
+}
+
+#[test]
+fn test_variant_rule() {
+    let options = ParseOptions::new().require_rfc_variant(true);
+    assert!(MagicTypeId::parse_with(V7_ID, &options).is_ok());
+
+    // A UUIDv7 layout with the Microsoft variant bits (110)
+    let microsoft = Uuid::from_u128(0x0189_0a5d_ac96_774b_dcce_b302_099a_3057);
+    assert_eq!(microsoft.get_variant(), Variant::Microsoft);
+    assert_eq!(
+        MagicTypeId::parse_with(&with_uuid("user", microsoft), &options),
+        Err(MagicTypeIdError::VariantNotAllowed)
+    );
+
+    // Nil and Max are governed by their own rules
+    assert!(MagicTypeId::parse_with(NIL_ID, &options).is_ok());
+    assert!(MagicTypeId::parse_with(MAX_ID, &options).is_ok());
+}
+
+#[test]
+fn test_version_rule() {
+    let options = ParseOptions::new().allowed_versions([V7::VERSION]);
+    assert!(MagicTypeId::parse_with(V7_ID, &options).is_ok());
+
+    for (id, found) in [
+        ("user".create_type_id::<V4>(), SuffixVersion::V4),
+        ("user".create_type_id::<V6>(), SuffixVersion::V6),
+        (MagicTypeId::from_str(NIL_ID).unwrap(), SuffixVersion::Nil),
+        (MagicTypeId::from_str(MAX_ID).unwrap(), SuffixVersion::Max),
+    ] {
+        assert_eq!(
+            MagicTypeId::parse_with(&id, &options),
+            Err(MagicTypeIdError::VersionNotAllowed { found })
+        );
+    }
+
+    let with_nil = ParseOptions::new().allowed_versions([SuffixVersion::V7, SuffixVersion::Nil]);
+    assert!(MagicTypeId::parse_with(NIL_ID, &with_nil).is_ok());
+}
+
+#[test]
+fn test_strict_options() {
+    let strict = ParseOptions::strict();
+    assert!(MagicTypeId::parse_with(V7_ID, &strict).is_ok());
+    assert!(MagicTypeId::parse_with(&"user".create_type_id::<V4>(), &strict).is_ok());
+    assert_eq!(
+        MagicTypeId::parse_with("01h455vb4pex5vsknk084sn02q", &strict),
+        Err(MagicTypeIdError::EmptyPrefixNotAllowed)
+    );
+    assert_eq!(
+        MagicTypeId::parse_with(NIL_ID, &strict),
+        Err(MagicTypeIdError::NilNotAllowed)
+    );
+    assert_eq!(
+        MagicTypeId::parse_with(MAX_ID, &strict),
+        Err(MagicTypeIdError::MaxNotAllowed)
+    );
+}
+
+#[test]
+fn test_rules_are_checked_in_order() {
+    let options = ParseOptions::strict()
+        .require_prefix("user")
+        .allowed_versions([SuffixVersion::V7]);
+
+    // Breaks both the empty-prefix and the prefix rule
+    assert_eq!(
+        MagicTypeId::parse_with("00000000000000000000000000", &options),
+        Err(MagicTypeIdError::EmptyPrefixNotAllowed)
+    );
+    // Breaks both the prefix and the nil rule
+    assert!(matches!(
+        MagicTypeId::parse_with("order_00000000000000000000000000", &options),
+        Err(MagicTypeIdError::PrefixMismatch { .. })
+    ));
+    // Breaks both the nil and the version rule
+    assert_eq!(
+        MagicTypeId::parse_with(NIL_ID, &options),
+        Err(MagicTypeIdError::NilNotAllowed)
+    );
+}
+
+#[test]
+fn test_error_messages() {
+    assert_eq!(
+        MagicTypeIdError::PrefixNotAllowed {
+            allowed: vec!["user".to_string(), "admin".to_string()],
+            found: "order".to_string(),
+        }
+        .to_string(),
+        "Prefix error: prefix 'order' is not one of [\"user\", \"admin\"]"
+    );
+    assert_eq!(
+        MagicTypeIdError::VersionNotAllowed {
+            found: SuffixVersion::V4
+        }
+        .to_string(),
+        "Suffix error: UUID version 'v4' is not allowed"
+    );
+    assert_eq!(
+        MagicTypeIdError::NilNotAllowed.to_string(),
+        "Suffix error: the Nil UUID is not allowed"
+    );
+}
@@ -0,0 +1,199 @@
+//! Integration tests for `PrefixRegistry`.
+//!
+//! These tests check that registries built from static lists or at runtime resolve prefixes and
+//! deprecated aliases to their kinds, reject unknown prefixes and disallowed UUID versions, and
+//! refuse invalid or conflicting registrations.
+
+use std::str::FromStr;
+
+use mti::prelude::*;
+
+static KINDS: &[EntityKind] = &[
+    EntityKind::new("user", "User").with_aliases(&["usr", "account"]),
+    EntityKind::new("order", "Order").with_versions(&[SuffixVersion::V7]),
+    EntityKind::new("audit_log", "AuditLog").with_versions(&[SuffixVersion::V6, SuffixVersion::V7]),
+];
+
+const SUFFIX: &str = "01h455vb4pex5vsknk084sn02q";
+
+fn registry() -> PrefixRegistry {
+    PrefixRegistry::from_kinds(KINDS.iter().copied()).unwrap()
+}
+
+#[test]
+fn test_lookup() {
+    let registry = registry();
+    assert_eq!(registry.len(), 3);
+    assert!(!registry.is_empty());
+    assert_eq!(registry.get("user"), Some(&KINDS[0]));
+    assert_eq!(registry.get("usr"), Some(&KINDS[0]));
+    assert_eq!(registry.get("invoice"), None);
+    assert!(registry.contains("account"));
+    assert_eq!(
+        registry.kinds().map(EntityKind::entity).collect::<Vec<_>>(),
+        ["User", "Order", "AuditLog"]
+    );
+    assert!(PrefixRegistry::new().is_empty());
+}
+
+#[test]
+fn test_parse_canonical_prefix() {
+    let registry = registry();
+    let parsed = registry.parse(&format!("audit_log_{SUFFIX}")).unwrap();
+    assert_eq!(parsed.kind(), &KINDS[2]);
+    assert!(!parsed.is_deprecated_alias());
+    assert_eq!(
+        parsed.id(),
+        &MagicTypeId::from_str(&format!("audit_log_{SUFFIX}")).unwrap()
+    );
+    assert_eq!(
+        parsed.into_canonical().as_str(),
+        format!("audit_log_{SUFFIX}")
+    );
+}
+
+#[test]
+fn test_parse_deprecated_alias() {
+    let registry = registry();
+    let parsed = registry.parse(&format!("account_{SUFFIX}")).unwrap();
+    assert_eq!(parsed.kind().entity(), "User");
+    assert!(parsed.is_deprecated_alias());
+    assert_eq!(parsed.id().prefix().as_str(), "account");
+    assert_eq!(parsed.clone().into_id().prefix().as_str(), "account");
+
+    let canonical = parsed.into_canonical();
+    assert_eq!(canonical.as_str(), format!("user_{SUFFIX}"));
+}
+
+#[test]
+fn test_parse_rejects_unknown_prefixes() {
+    let registry = registry();
+    assert_eq!(
+        registry.parse(&format!("invoice_{SUFFIX}")).unwrap_err(),
+        MagicTypeIdError::UnknownPrefix {
+            found: "invoice".to_string()
+        }
+    );
+    assert_eq!(
+        registry.parse(SUFFIX).unwrap_err(),
+        MagicTypeIdError::UnknownPrefix {
+            found: String::new()
+        }
+    );
+}
+
+#[test]
+fn test_parse_rejects_disallowed_versions() {
+    let registry = registry();
+    let v4_order = "order".create_type_id::<V4>();
+    assert_eq!(
+        registry.parse(&v4_order).unwrap_err(),
+        MagicTypeIdError::VersionNotAllowed {
+            found: SuffixVersion::V4
+        }
+    );
+    assert!(registry.parse(&"audit_log".create_type_id::<V6>()).is_ok());
+
+    // Kinds without a version list accept every version
+    assert!(registry.parse(&"user".create_type_id::<V4>()).is_ok());
+}
+
+#[test]
+fn test_parse_reports_malformed_input() {
+    let registry = registry();
+    assert!(matches!(
+        registry.parse("user_01h455vb4pex5vsknk084sn0uq"),
+        Err(MagicTypeIdError::Suffix(_))
+    ));
+    assert!(matches!(
+        registry.parse("User_01h455vb4pex5vsknk084sn02q"),
+        Err(MagicTypeIdError::Prefix(_))
+    ));
+}
+
+#[test]
+fn test_register_rejects_invalid_prefixes() {
+    let mut registry = PrefixRegistry::new();
+    assert_eq!(
+        registry.register(EntityKind::new("uSer", "User")),
+        Err(RegistryError::InvalidPrefix {
+            prefix: "uSer",
+            error: ValidationError::ContainsInvalidCharacters {
+                position: 1,
+                character: 'S'
+            }
+        })
+    );
+    assert_eq!(
+        registry.register(EntityKind::new("user", "User").with_aliases(&["usr_"])),
+        Err(RegistryError::InvalidPrefix {
+            prefix: "usr_",
+            error: ValidationError::EndsWithUnderscore
+        })
+    );
+    assert!(registry.is_empty());
+}
+
+#[test]
+fn test_register_rejects_duplicates() {
+    let mut registry = registry();
+    for kind in [
+        EntityKind::new("order", "Purchase"),
+        EntityKind::new("purchase", "Purchase").with_aliases(&["usr"]),
+        EntityKind::new("purchase", "Purchase").with_aliases(&["purchase"]),
+        EntityKind::new("purchase", "Purchase").with_aliases(&["buy", "buy"]),
+    ] {
+        assert!(matches!(
+            registry.register(kind),
+            Err(RegistryError::DuplicatePrefix { .. })
+        ));
+    }
+    // Failed registrations leave the registry unchanged
+    assert_eq!(registry.len(), 3);
+    assert!(!registry.contains("purchase"));
+    assert!(!registry.contains("buy"));
+
+    registry
+        .register(EntityKind::new("purchase", "Purchase"))
+        .unwrap();
+    assert_eq!(
+        registry.get("purchase").map(EntityKind::entity),
+        Some("Purchase")
+    );
+}
+
+#[test]
+fn test_many_kinds() {
+    const PREFIXES: [&str; 60] = [
+        "aa", "ab", "ac", "ad", "ae", "af", "ag", "ah", "ai", "aj", "ak", "al", "am", "an", "ao",
+        "ap", "aq", "ar", "as", "at", "au", "av", "aw", "ax", "ay", "az", "ba", "bb", "bc", "bd",
+        "be", "bf", "bg", "bh", "bi", "bj", "bk", "bl", "bm", "bn", "bo", "bp", "bq", "br", "bs",
+        "bt", "bu", "bv", "bw", "bx", "by", "bz", "ca", "cb", "cc", "cd", "ce", "cf", "cg", "ch",
+    ];
+    let registry = PrefixRegistry::from_kinds(
+        PREFIXES
+            .iter()
+            .map(|&prefix| EntityKind::new(prefix, prefix)),
+    )
+    .unwrap();
+    assert_eq!(registry.len(), 60);
+    for prefix in PREFIXES {
+        let parsed = registry.parse(&format!("{prefix}_{SUFFIX}")).unwrap();
+        assert_eq!(parsed.kind().prefix(), prefix);
+    }
+}
+
+#[test]
+fn test_error_messages() {
+    assert_eq!(
+        RegistryError::DuplicatePrefix { prefix: "user" }.to_string(),
+        "Registry error: prefix 'user' is already registered"
+    );
+    assert_eq!(
+        MagicTypeIdError::UnknownPrefix {
+            found: "invoice".to_string()
+        }
+        .to_string(),
+        "Prefix error: unknown prefix 'invoice'"
+    );
+}
@@ -0,0 +1,233 @@
+//! Tests for the `rusqlite` integration.
+//!
+//! This module stores identifiers, suffixes and prefixes in an in-memory
+//! `SQLite` database, as text and as 16-byte blobs, and verifies that they
+//! read back unchanged, that derived and enum identifiers enforce their
+//! prefix, and that invalid stored values fail with the underlying error
+//! attached.
+//!
+//! These tests only run when the "rusqlite" feature is enabled.
+
+#![cfg(feature = "rusqlite")]
+
+use std::str::FromStr;
+
+use mti::prelude::*;
+use rusqlite::types::FromSqlError;
+use rusqlite::{params, Connection};
+
+struct Order;
+impl PrefixMarker for Order {
+    const PREFIX: &'static str = "order";
+}
+
+type OrderId = TypedId<Order>;
+
+const USER: &str = "user_01h455vb4pex5vsknk084sn02q";
+
+fn connect() -> Connection {
+    let conn = Connection::open_in_memory().unwrap();
+    conn.execute_batch(
+        "CREATE TABLE users (id TEXT PRIMARY KEY, kind TEXT NOT NULL);
+         CREATE TABLE orders (id BLOB PRIMARY KEY, user_id TEXT NOT NULL);",
+    )
+    .unwrap();
+    conn
+}
+
+/// Returns the error attached to a conversion failure.
+fn conversion_error(error: rusqlite::Error) -> Box<dyn std::error::Error + Send + Sync> {
+    match error {
+        rusqlite::Error::FromSqlConversionFailure(_, _, source) => source,
+        other => panic!("expected a conversion failure, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_magic_type_id_as_text() {
+    let conn = connect();
+    let id = "user".create_type_id::<V7>();
+    let prefix = id.prefix().clone();
+    let inserted = conn
+        .execute(
+            "INSERT INTO users (id, kind) VALUES (?1, ?2)",
+            params![id, prefix],
+        )
+        .unwrap();
+    assert_eq!(inserted, 1);
+
+    let (loaded, kind, storage): (MagicTypeId, TypeIdPrefix, String) = conn
+        .query_row("SELECT id, kind, typeof(id) FROM users", [], |row| {
+            Ok((row.get(0)?, row.get(1)?, row.get(2)?))
+        })
+        .unwrap();
+    assert_eq!(loaded, id);
+    assert_eq!(kind, prefix);
+    assert_eq!(storage, "text");
+}
+
+#[test]
+fn test_suffix_as_blob_or_text() {
+    let conn = connect();
+    let id = OrderId::new::<V7>();
+    let inserted = conn
+        .execute(
+            "INSERT INTO orders (id, user_id) VALUES (?1, ?2)",
+            params![id, id.suffix().to_string()],
+        )
+        .unwrap();
+    assert_eq!(inserted, 1);
+
+    let (loaded, length): (OrderId, i64) = conn
+        .query_row("SELECT id, length(id) FROM orders", [], |row| {
+            Ok((row.get(0)?, row.get(1)?))
+        })
+        .unwrap();
+    assert_eq!(loaded, id);
+    assert_eq!(length, 16);
+
+    // A suffix reads back from the blob and from its text form alike
+    let (from_blob, from_text): (TypeIdSuffix, TypeIdSuffix) = conn
+        .query_row("SELECT id, user_id FROM orders", [], |row| {
+            Ok((row.get(0)?, row.get(1)?))
+        })
+        .unwrap();
+    assert_eq!(&from_blob, id.suffix());
+    assert_eq!(&from_text, id.suffix());
+}
+
+#[test]
+fn test_typed_id_from_text_checks_prefix() {
+    let conn = connect();
+    let id = MagicTypeId::from_str(USER).unwrap();
+    let inserted = conn
+        .execute("INSERT INTO users (id, kind) VALUES (?1, 'user')", [&id])
+        .unwrap();
+    assert_eq!(inserted, 1);
+
+    let error = conn
+        .query_row("SELECT id FROM users", [], |row| row.get::<_, OrderId>(0))
+        .unwrap_err();
+    let source = conversion_error(error);
+    assert_eq!(
+        source.downcast_ref::<MagicTypeIdError>(),
+        Some(&MagicTypeIdError::PrefixMismatch {
+            expected: "order".to_string(),
+            found: "user".to_string(),
+        })
+    );
+}
+
+#[test]
+fn test_invalid_values_attach_error() {
+    let conn = connect();
+    conn.execute_batch(
+        "INSERT INTO users (id, kind) VALUES ('User_01h455vb4pex5vsknk084sn02q', 'User');
+         INSERT INTO orders (id, user_id) VALUES (x'0102', '');",
+    )
+    .unwrap();
+
+    let error = conn
+        .query_row("SELECT id FROM users", [], |row| {
+            row.get::<_, MagicTypeId>(0)
+        })
+        .unwrap_err();
+    assert!(conversion_error(error).is::<MagicTypeIdError>());
+
+    let error = conn
+        .query_row("SELECT kind FROM users", [], |row| {
+            row.get::<_, TypeIdPrefix>(0)
+        })
+        .unwrap_err();
+    assert!(conversion_error(error).is::<ValidationError>());
+
+    // A blob of the wrong size is reported as such
+    let error = conn
+        .query_row("SELECT id FROM orders", [], |row| row.get::<_, OrderId>(0))
+        .unwrap_err();
+    assert!(matches!(
+        conversion_error(error).downcast_ref::<FromSqlError>(),
+        Some(FromSqlError::InvalidBlobSize {
+            expected_size: 16,
+            blob_size: 2,
+        })
+    ));
+}
+
+#[cfg(feature = "macros")]
+#[test]
+fn test_derived_ids() {
+    #[derive(Debug, Clone, PartialEq, MagicId)]
+    #[mti(prefix = "user")]
+    struct DerivedUserId(MagicTypeId);
+
+    #[derive(Debug, Clone, PartialEq, MagicId)]
+    #[mti(prefix = "order")]
+    struct DerivedOrderId(MagicTypeId);
+
+    let conn = connect();
+    let order_id = DerivedOrderId::new();
+    let user_id = DerivedUserId::new();
+    let inserted = conn
+        .execute(
+            "INSERT INTO orders (id, user_id) VALUES (?1, ?2)",
+            params![order_id, user_id.as_str()],
+        )
+        .unwrap();
+    assert_eq!(inserted, 1);
+
+    let (id, length, user): (DerivedOrderId, i64, DerivedUserId) = conn
+        .query_row("SELECT id, length(id), user_id FROM orders", [], |row| {
+            Ok((row.get(0)?, row.get(1)?, row.get(2)?))
+        })
+        .unwrap();
+    assert_eq!(id, order_id);
+    assert_eq!(length, 16);
+    assert_eq!(user, user_id);
+
+    let error = conn
+        .query_row("SELECT user_id FROM orders", [], |row| {
+            row.get::<_, DerivedOrderId>(0)
+        })
+        .unwrap_err();
+    assert!(matches!(
+        conversion_error(error).downcast_ref::<MagicTypeIdError>(),
+        Some(MagicTypeIdError::PrefixMismatch { .. })
+    ));
+}
+
+#[test]
+fn test_id_enum_as_text() {
+    mti::id_enum! {
+        #[derive(Debug, PartialEq)]
+        enum AnyId: AnyIdKind {
+            User = "user",
+            Order(OrderId) = "order",
+        }
+    }
+
+    let conn = connect();
+    let id = AnyId::Order(OrderId::new::<V7>());
+    let inserted = conn
+        .execute("INSERT INTO users (id, kind) VALUES (?1, 'order')", [&id])
+        .unwrap();
+    assert_eq!(inserted, 1);
+
+    let (loaded, storage): (AnyId, String) = conn
+        .query_row("SELECT id, typeof(id) FROM users", [], |row| {
+            Ok((row.get(0)?, row.get(1)?))
+        })
+        .unwrap();
+    assert_eq!(loaded, id);
+    assert_eq!(storage, "text");
+
+    let error = conn
+        .query_row("SELECT 'invoice_01h455vb4pex5vsknk084sn02q'", [], |row| {
+            row.get::<_, AnyId>(0)
+        })
+        .unwrap_err();
+    assert!(matches!(
+        conversion_error(error).downcast_ref::<MagicTypeIdError>(),
+        Some(MagicTypeIdError::PrefixNotAllowed { .. })
+    ));
+}
@@ -0,0 +1,97 @@
+//! Tests for the `mti::serde::Prefixed` field helper.
+//!
+//! This module verifies that fields using the helper reject identifiers
+//! with another prefix, for plain, optional, and repeated fields, and that
+//! serialization is unchanged.
+//!
+//! These tests only run when the "serde" feature is enabled.
+
+#![cfg(feature = "serde")]
+
+use mti::prelude::*;
+use serde::{Deserialize, Serialize};
+
+struct User;
+impl PrefixMarker for User {
+    const PREFIX: &'static str = "user";
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Order {
+    #[serde(with = "mti::serde::Prefixed::<User>")]
+    user_id: MagicTypeId,
+    #[serde(default, with = "mti::serde::Prefixed::<User>")]
+    approved_by: Option<MagicTypeId>,
+    #[serde(default, with = "mti::serde::Prefixed::<User>")]
+    watchers: Vec<MagicTypeId>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Batch {
+    #[serde(with = "mti::serde::Prefixed::<User>")]
+    groups: Vec<Option<MagicTypeId>>,
+}
+
+const USER: &str = "user_01h455vb4pex5vsknk084sn02q";
+const CUSTOMER: &str = "customer_01h455vb4pex5vsknk084sn02q";
+
+#[test]
+fn test_accepts_expected_prefix() {
+    let json = format!(r#"{{"user_id":"{USER}","approved_by":"{USER}","watchers":["{USER}"]}}"#);
+    let order: Order = serde_json::from_str(&json).unwrap();
+    assert_eq!(order.user_id, USER);
+    assert_eq!(order.approved_by.as_ref().unwrap(), USER);
+    assert_eq!(order.watchers.len(), 1);
+
+    // Serialization is unchanged
+    assert_eq!(serde_json::to_string(&order).unwrap(), json);
+}
+
+#[test]
+fn test_rejects_other_prefix() {
+    let json = format!(r#"{{"user_id":"{CUSTOMER}"}}"#);
+    let error = serde_json::from_str::<Order>(&json).unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .starts_with("Prefix error: expected prefix 'user', found 'customer'"),
+        "unexpected message: {error}"
+    );
+}
+
+#[test]
+fn test_option_field() {
+    let missing: Order = serde_json::from_str(&format!(r#"{{"user_id":"{USER}"}}"#)).unwrap();
+    assert_eq!(missing.approved_by, None);
+
+    let null: Order =
+        serde_json::from_str(&format!(r#"{{"user_id":"{USER}","approved_by":null}}"#)).unwrap();
+    assert_eq!(null.approved_by, None);
+
+    let json = format!(r#"{{"user_id":"{USER}","approved_by":"{CUSTOMER}"}}"#);
+    assert!(serde_json::from_str::<Order>(&json).is_err());
+}
+
+#[test]
+fn test_vec_field() {
+    let json = format!(r#"{{"user_id":"{USER}","watchers":["{USER}","{CUSTOMER}"]}}"#);
+    let error = serde_json::from_str::<Order>(&json).unwrap_err();
+    assert!(error.to_string().contains("found 'customer'"));
+}
+
+#[test]
+fn test_nested_containers() {
+    let batch: Batch = serde_json::from_str(&format!(r#"{{"groups":["{USER}",null]}}"#)).unwrap();
+    assert_eq!(batch.groups.len(), 2);
+    assert!(batch.groups[1].is_none());
+
+    let json = format!(r#"{{"groups":[null,"{CUSTOMER}"]}}"#);
+    assert!(serde_json::from_str::<Batch>(&json).is_err());
+}
+
+#[test]
+fn test_invalid_identifier_is_rejected() {
+    let json = r#"{"user_id":"user_invalid"}"#;
+    let error = serde_json::from_str::<Order>(json).unwrap_err();
+    assert!(error.to_string().starts_with("Suffix error"));
+}
@@ -99,3 +99,170 @@ fn test_in_complex_structure() {
         "00000000000000000000000000"
     );
 }
+
+#[test]
+fn test_typed_id_roundtrip() {
+    struct User;
+    impl PrefixMarker for User {
+        const PREFIX: &'static str = "user";
+    }
+
+    let id = TypedId::<User>::from_str("user_00000000000000000000000000").unwrap();
+    let json = serde_json::to_string(&id).unwrap();
+    assert_eq!(json, "\"user_00000000000000000000000000\"");
+
+    let deserialized: TypedId<User> = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized, id);
+}
+
+#[test]
+fn test_typed_id_rejects_other_prefix() {
+    struct User;
+    impl PrefixMarker for User {
+        const PREFIX: &'static str = "user";
+    }
+
+    let result: Result<TypedId<User>, _> =
+        serde_json::from_str("\"customer_00000000000000000000000000\"");
+    let err = result.unwrap_err().to_string();
+    assert!(err.contains("expected prefix 'user'"), "{err}");
+}
+
+#[test]
+fn test_compact_type_id_roundtrip() {
+    let id = CompactTypeId::from_str("user_01h455vb4pex5vsknk084sn02q").unwrap();
+    let json = serde_json::to_string(&id).unwrap();
+    assert_eq!(json, "\"user_01h455vb4pex5vsknk084sn02q\"");
+
+    let deserialized: CompactTypeId = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized, id);
+
+    let result: Result<CompactTypeId, _> =
+        serde_json::from_str("\"User_01h455vb4pex5vsknk084sn02q\"");
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_magic_type_id_ref_borrows_from_input() {
+    #[derive(serde::Deserialize)]
+    struct Event<'a> {
+        #[serde(borrow)]
+        id: MagicTypeIdRef<'a>,
+    }
+
+    let json = String::from(r#"{"id":"user_01h455vb4pex5vsknk084sn02q"}"#);
+    let event: Event<'_> = serde_json::from_str(&json).unwrap();
+    assert_eq!(event.id.prefix(), "user");
+    assert!(json.contains(event.id.as_str()));
+    assert_eq!(
+        serde_json::to_string(&event.id).unwrap(),
+        "\"user_01h455vb4pex5vsknk084sn02q\""
+    );
+
+    let result: Result<MagicTypeIdRef<'_>, _> =
+        serde_json::from_str("\"User_01h455vb4pex5vsknk084sn02q\"");
+    assert!(result.is_err());
+}
+
+#[cfg(feature = "macros")]
+#[test]
+fn test_derived_id_roundtrip() {
+    #[derive(Debug, PartialEq, Eq, MagicId)]
+    #[mti(prefix = "user")]
+    struct UserId(MagicTypeId);
+
+    let id = UserId::parse("user_00000000000000000000000000").unwrap();
+    let json = serde_json::to_string(&id).unwrap();
+    assert_eq!(json, "\"user_00000000000000000000000000\"");
+
+    let deserialized: UserId = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized, id);
+
+    let result: Result<UserId, _> = serde_json::from_str("\"customer_00000000000000000000000000\"");
+    let err = result.unwrap_err().to_string();
+    assert!(err.contains("expected prefix 'user'"), "{err}");
+}
+
+#[test]
+fn test_binary_format_is_compact() {
+    let id = MagicTypeId::from_str("user_01h455vb4pex5vsknk084sn02q").unwrap();
+
+    // A length prefix, the prefix, and the 16 bytes of the UUID
+    let bytes = bincode::serialize(&id).unwrap();
+    assert_eq!(bytes.len(), 8 + "user".len() + 16);
+    assert_eq!(&bytes[8..12], b"user");
+    assert_eq!(&bytes[12..], id.suffix().to_uuid().as_bytes());
+    assert!(bytes.len() < bincode::serialize(id.as_str()).unwrap().len());
+
+    assert_eq!(bincode::deserialize::<MagicTypeId>(&bytes).unwrap(), id);
+}
+
+#[test]
+fn test_binary_format_without_prefix() {
+    let id = MagicTypeId::from_str("01h455vb4pex5vsknk084sn02q").unwrap();
+    let bytes = bincode::serialize(&id).unwrap();
+    assert_eq!(bytes.len(), 8 + 16);
+    assert_eq!(bincode::deserialize::<MagicTypeId>(&bytes).unwrap(), id);
+}
+
+#[test]
+fn test_binary_format_accepts_string_form() {
+    for input in [
+        "user_01h455vb4pex5vsknk084sn02q",
+        "01h455vb4pex5vsknk084sn02q",
+        "a_b_01h455vb4pex5vsknk084sn02q",
+    ] {
+        let bytes = bincode::serialize(input).unwrap();
+        let id: MagicTypeId = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(id, input);
+
+        let compact: CompactTypeId = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(compact, id);
+    }
+}
+
+#[test]
+fn test_binary_format_rejects_invalid_input() {
+    // Neither a valid prefix followed by 16 bytes nor a valid string
+    let mut invalid_prefix = b"User".to_vec();
+    invalid_prefix.extend_from_slice(&[0u8; 16]);
+    let bytes = bincode::serialize(&invalid_prefix).unwrap();
+    assert!(bincode::deserialize::<MagicTypeId>(&bytes).is_err());
+
+    let bytes = bincode::serialize(&[0xffu8; 10][..]).unwrap();
+    assert!(bincode::deserialize::<MagicTypeId>(&bytes).is_err());
+
+    let bytes = bincode::serialize("user_01h455vb4pex5vsknk084sn0uq").unwrap();
+    assert!(bincode::deserialize::<MagicTypeId>(&bytes).is_err());
+}
+
+#[test]
+fn test_binary_format_is_shared_by_wrappers() {
+    struct User;
+    impl PrefixMarker for User {
+        const PREFIX: &'static str = "user";
+    }
+
+    let id = MagicTypeId::from_str("user_01h455vb4pex5vsknk084sn02q").unwrap();
+    let bytes = bincode::serialize(&id).unwrap();
+
+    let compact = CompactTypeId::from(&id);
+    assert_eq!(bincode::serialize(&compact).unwrap(), bytes);
+    assert_eq!(
+        bincode::deserialize::<CompactTypeId>(&bytes).unwrap(),
+        compact
+    );
+
+    let typed = TypedId::<User>::try_from(id.clone()).unwrap();
+    assert_eq!(bincode::serialize(&typed).unwrap(), bytes);
+    assert_eq!(
+        bincode::deserialize::<TypedId<User>>(&bytes).unwrap(),
+        typed
+    );
+
+    // Human-readable formats are unchanged
+    assert_eq!(
+        serde_json::to_string(&compact).unwrap(),
+        serde_json::to_string(&id).unwrap()
+    );
+}
@@ -0,0 +1,231 @@
+//! Tests for the `sqlx` integration.
+//!
+//! This module stores identifiers in an in-memory `SQLite` database, both as
+//! text and as 16-byte UUID blobs, and verifies that they decode back
+//! unchanged and that typed, derived and enum identifiers enforce their
+//! prefix.
+//!
+//! These tests only run when the "sqlx" feature is enabled.
+
+#![cfg(feature = "sqlx")]
+
+use std::str::FromStr;
+
+use mti::prelude::*;
+use sqlx::{Connection, Row, SqliteConnection};
+
+struct Order;
+impl PrefixMarker for Order {
+    const PREFIX: &'static str = "order";
+}
+
+struct User;
+impl PrefixMarker for User {
+    const PREFIX: &'static str = "user";
+}
+
+type OrderId = TypedId<Order>;
+
+async fn connect() -> SqliteConnection {
+    let mut conn = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+    let _ = sqlx::raw_sql(
+        "CREATE TABLE users (id TEXT PRIMARY KEY, name TEXT NOT NULL);
+         CREATE TABLE orders (id BLOB PRIMARY KEY, user_id TEXT NOT NULL);",
+    )
+    .execute(&mut conn)
+    .await
+    .unwrap();
+    conn
+}
+
+#[tokio::test]
+async fn test_magic_type_id_as_text() {
+    let mut conn = connect().await;
+    let id = "user".create_type_id::<V7>();
+
+    let inserted = sqlx::query("INSERT INTO users (id, name) VALUES (?, ?)")
+        .bind(&id)
+        .bind("Alice")
+        .execute(&mut conn)
+        .await
+        .unwrap();
+    assert_eq!(inserted.rows_affected(), 1);
+
+    let row = sqlx::query("SELECT id, typeof(id) AS storage FROM users WHERE id = ?")
+        .bind(&id)
+        .fetch_one(&mut conn)
+        .await
+        .unwrap();
+    assert_eq!(row.get::<MagicTypeId, _>("id"), id);
+    assert_eq!(row.get::<String, _>("storage"), "text");
+}
+
+#[tokio::test]
+async fn test_invalid_text_fails_to_decode() {
+    let mut conn = connect().await;
+    let inserted = sqlx::query(
+        "INSERT INTO users (id, name) VALUES ('User_01h455vb4pex5vsknk084sn02q', 'Bob')",
+    )
+    .execute(&mut conn)
+    .await
+    .unwrap();
+    assert_eq!(inserted.rows_affected(), 1);
+
+    let result: Result<MagicTypeId, _> = sqlx::query_scalar("SELECT id FROM users")
+        .fetch_one(&mut conn)
+        .await;
+    assert!(matches!(result, Err(sqlx::Error::ColumnDecode { .. })));
+}
+
+#[tokio::test]
+async fn test_typed_id_as_uuid() {
+    let mut conn = connect().await;
+    let order_id = OrderId::new::<V7>();
+    let user_id = "user".create_type_id::<V7>();
+
+    let inserted = sqlx::query("INSERT INTO orders (id, user_id) VALUES (?, ?)")
+        .bind(&order_id)
+        .bind(&user_id)
+        .execute(&mut conn)
+        .await
+        .unwrap();
+    assert_eq!(inserted.rows_affected(), 1);
+
+    let row = sqlx::query("SELECT id, length(id) AS len, user_id FROM orders")
+        .fetch_one(&mut conn)
+        .await
+        .unwrap();
+    assert_eq!(row.get::<OrderId, _>("id"), order_id);
+    assert_eq!(row.get::<i64, _>("len"), 16);
+    assert_eq!(row.get::<TypeIdSuffix, _>("id"), *order_id.suffix());
+    assert_eq!(row.get::<MagicTypeId, _>("user_id"), user_id);
+
+    // The stored bytes are the UUID itself
+    let uuid: Uuid = sqlx::query_scalar("SELECT id FROM orders")
+        .fetch_one(&mut conn)
+        .await
+        .unwrap();
+    assert_eq!(uuid, order_id.suffix().to_uuid());
+}
+
+#[tokio::test]
+async fn test_suffix_lookup() {
+    let mut conn = connect().await;
+    let order_id = OrderId::new::<V7>();
+    let inserted = sqlx::query(
+        "INSERT INTO orders (id, user_id) VALUES (?, 'user_01h455vb4pex5vsknk084sn02q')",
+    )
+    .bind(order_id.suffix())
+    .execute(&mut conn)
+    .await
+    .unwrap();
+    assert_eq!(inserted.rows_affected(), 1);
+
+    let found: OrderId = sqlx::query_scalar("SELECT id FROM orders WHERE id = ?")
+        .bind(&order_id)
+        .fetch_one(&mut conn)
+        .await
+        .unwrap();
+    assert_eq!(found, order_id);
+}
+
+#[tokio::test]
+async fn test_typed_id_checks_prefix_of_text() {
+    let mut conn = connect().await;
+    let inserted = sqlx::query(
+        "INSERT INTO users (id, name) VALUES ('user_01h455vb4pex5vsknk084sn02q', 'Carol')",
+    )
+    .execute(&mut conn)
+    .await
+    .unwrap();
+    assert_eq!(inserted.rows_affected(), 1);
+
+    // A whole identifier stored as text is accepted when its prefix matches
+    let user: TypedId<User> = sqlx::query_scalar("SELECT id FROM users")
+        .fetch_one(&mut conn)
+        .await
+        .unwrap();
+    assert_eq!(
+        user,
+        MagicTypeId::from_str("user_01h455vb4pex5vsknk084sn02q").unwrap()
+    );
+
+    // ...and rejected otherwise
+    let result: Result<OrderId, _> = sqlx::query_scalar("SELECT id FROM users")
+        .fetch_one(&mut conn)
+        .await;
+    let Err(sqlx::Error::ColumnDecode { source, .. }) = result else {
+        panic!("expected a decode error, got {result:?}");
+    };
+    assert_eq!(
+        source.to_string(),
+        "Prefix error: expected prefix 'order', found 'user'"
+    );
+}
+
+#[cfg(feature = "macros")]
+#[tokio::test]
+async fn test_derived_ids() {
+    #[derive(Debug, Clone, PartialEq, MagicId)]
+    #[mti(prefix = "user")]
+    struct DerivedUserId(MagicTypeId);
+
+    #[derive(Debug, Clone, PartialEq, MagicId)]
+    #[mti(prefix = "order")]
+    struct DerivedOrderId(MagicTypeId);
+
+    let mut conn = connect().await;
+    let order_id = DerivedOrderId::new();
+    let user_id = DerivedUserId::new();
+    let inserted = sqlx::query("INSERT INTO orders (id, user_id) VALUES (?, ?)")
+        .bind(&order_id)
+        .bind(user_id.as_str())
+        .execute(&mut conn)
+        .await
+        .unwrap();
+    assert_eq!(inserted.rows_affected(), 1);
+
+    let row = sqlx::query("SELECT id, length(id) AS len, user_id FROM orders")
+        .fetch_one(&mut conn)
+        .await
+        .unwrap();
+    assert_eq!(row.get::<DerivedOrderId, _>("id"), order_id);
+    assert_eq!(row.get::<i64, _>("len"), 16);
+    assert_eq!(row.get::<DerivedUserId, _>("user_id"), user_id);
+
+    let result = row.try_get::<DerivedOrderId, _>("user_id");
+    assert!(matches!(result, Err(sqlx::Error::ColumnDecode { .. })));
+}
+
+#[tokio::test]
+async fn test_id_enum_as_text() {
+    mti::id_enum! {
+        #[derive(Debug, PartialEq)]
+        enum AnyId: AnyIdKind {
+            User(TypedId<User>) = "user",
+            Order(OrderId) = "order",
+        }
+    }
+
+    let mut conn = connect().await;
+    let id = AnyId::User(TypedId::new::<V7>());
+    let inserted = sqlx::query("INSERT INTO users (id, name) VALUES (?, 'Dave')")
+        .bind(&id)
+        .execute(&mut conn)
+        .await
+        .unwrap();
+    assert_eq!(inserted.rows_affected(), 1);
+
+    let row = sqlx::query("SELECT id, typeof(id) AS storage FROM users")
+        .fetch_one(&mut conn)
+        .await
+        .unwrap();
+    assert_eq!(row.get::<AnyId, _>("id"), id);
+    assert_eq!(row.get::<String, _>("storage"), "text");
+
+    let result: Result<AnyId, _> =
+        sqlx::query_scalar("SELECT 'invoice_01h455vb4pex5vsknk084sn02q'")
+            .fetch_one(&mut conn)
+            .await;
+    assert!(matches!(result, Err(sqlx::Error::ColumnDecode { .. })));
+}
@@ -78,3 +78,97 @@ fn test_general() {
     assert!("HelloWorld".try_create_type_id::<V7>().is_err());
     assert_eq!("HelloWorld".create_type_id::<V7>().prefix(), "helloworld");
 }
+
+#[test]
+fn test_timestamps() {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    let created = UNIX_EPOCH + Duration::new(1_700_000_000, 123_456_700);
+    let source = || SeededIdSource::new(0).with_start_time(created);
+
+    let v1_id = "user".create_type_id_with_source::<V1, _>(&source());
+    let v6_id = "user".create_type_id_with_source::<V6, _>(&source());
+    let v7_id = "user".create_type_id_with_source::<V7, _>(&source());
+
+    // V1 and V6 keep 100ns precision, V7 keeps milliseconds
+    assert_eq!(v1_id.timestamp(), Some(created));
+    assert_eq!(v6_id.timestamp(), Some(created));
+    assert_eq!(
+        v7_id.timestamp(),
+        Some(UNIX_EPOCH + Duration::from_millis(1_700_000_000_123))
+    );
+
+    for id in [&v1_id, &v6_id, &v7_id] {
+        assert_eq!(id.timestamp_millis(), Some(1_700_000_000_123));
+        assert_eq!(id.as_str().timestamp().unwrap(), id.timestamp());
+    }
+    assert_eq!(v1_id.timestamp_100ns(), Some(17_000_000_001_234_567));
+    assert_eq!(v7_id.timestamp_100ns(), Some(17_000_000_001_230_000));
+
+    let v4_id = "user".create_type_id::<V4>();
+    assert_eq!(v4_id.timestamp(), None);
+    assert_eq!(v4_id.timestamp_millis(), None);
+    assert_eq!(v4_id.as_str().timestamp().unwrap(), None);
+
+    assert!("user_invalid".timestamp().is_err());
+    // Only the suffix is validated
+    assert!("User_01h455vb4pex5vsknk084sn02q".timestamp().is_ok());
+}
+
+#[test]
+fn test_time_range_bounds() {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    let prefix = TypeIdPrefix::from_str("order").unwrap();
+    let start = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    let end = UNIX_EPOCH + Duration::from_secs(1_700_000_060);
+    let lower = MagicTypeId::min_for_time(prefix.clone(), start);
+    let upper = MagicTypeId::max_for_time(prefix, end);
+
+    let source = SeededIdSource::new(11)
+        .with_start_time(start - Duration::from_secs(1))
+        .with_tick(Duration::from_millis(250));
+    let ids: Vec<MagicTypeId> = (0..300)
+        .map(|_| "order".create_type_id_with_source::<V7, _>(&source))
+        .collect();
+
+    // Ticking by 250ms from one second before `start`, IDs 4..=244 fall inside [start, end]
+    for (index, id) in ids.iter().enumerate() {
+        let inside = (4..=244).contains(&index);
+        assert_eq!(lower <= *id && *id <= upper, inside, "{index}: {id}");
+        assert_eq!(
+            lower.as_str() <= id.as_str() && id.as_str() <= upper.as_str(),
+            inside,
+            "{index}: {id}"
+        );
+    }
+
+    // A sorted list of strings can be range-scanned with the bounds
+    let mut sorted: Vec<&str> = ids.iter().map(MagicTypeId::as_str).collect();
+    sorted.sort_unstable();
+    let first = sorted.partition_point(|id| *id < lower.as_str());
+    let last = sorted.partition_point(|id| *id <= upper.as_str());
+    assert_eq!(last - first, 241);
+}
+
+#[test]
+fn test_version_introspection() {
+    let v7_id = "order".create_type_id::<V7>();
+    assert_eq!(v7_id.version(), SuffixVersion::V7);
+    assert_eq!(v7_id.variant(), Variant::RFC4122);
+    assert!(v7_id.is_version::<V7>());
+    assert!(v7_id.is_time_based());
+    assert!(v7_id.is_sortable());
+
+    let v1_id = "order".create_type_id::<V1>();
+    assert!(v1_id.is_time_based());
+    assert!(!v1_id.is_sortable());
+
+    let v5_id = "order".create_type_id_v5(NamespaceId::DNS, b"example.com");
+    assert_eq!(v5_id.version(), SuffixVersion::V5);
+    assert!(!v5_id.is_version::<V7>());
+    assert!(!v5_id.is_time_based());
+
+    let nil_id = MagicTypeId::from_str("order_00000000000000000000000000").unwrap();
+    assert_eq!(nil_id.version(), Nil::VERSION);
+}
@@ -0,0 +1,145 @@
+//! Tests for the `tower` request-ID middleware.
+//!
+//! This module sends requests through a `RequestIdLayer` wrapping a service that echoes the
+//! `RequestId` it finds in the request extensions, and verifies which identifier reaches the
+//! service and the response header, for requests with valid, invalid and missing identifiers.
+//!
+//! These tests only run when the "tower" feature is enabled.
+
+#![cfg(feature = "tower")]
+
+use std::convert::Infallible;
+use std::str::FromStr;
+
+use http::{HeaderName, HeaderValue, Request, Response};
+use mti::prelude::*;
+use mti::tower::{RequestId, RequestIdLayer, X_REQUEST_ID};
+use tower::{service_fn, Layer, ServiceExt};
+
+const INCOMING: &str = "req_01h455vb4pex5vsknk084sn02q";
+
+/// Sends `request` through `layer` and returns the identifier the inner service saw, the
+/// identifier in its request header, and the response.
+async fn send(
+    layer: &RequestIdLayer,
+    request: Request<()>,
+) -> (MagicTypeId, Option<HeaderValue>, Response<()>) {
+    let header = layer.header_name().clone();
+    let service = layer.layer(service_fn(move |request: Request<()>| {
+        let seen = (
+            request.extensions().get::<RequestId>().cloned().unwrap(),
+            request.headers().get(&header).cloned(),
+        );
+        let mut response = Response::new(());
+        let _ = response.extensions_mut().insert(seen);
+        async move { Ok::<_, Infallible>(response) }
+    }));
+    let mut response = service.oneshot(request).await.unwrap();
+    let (id, value) = response
+        .extensions_mut()
+        .remove::<(RequestId, Option<HeaderValue>)>()
+        .unwrap();
+    (id.into_inner(), value, response)
+}
+
+fn request_with(header: &HeaderName, value: &str) -> Request<()> {
+    Request::builder().header(header, value).body(()).unwrap()
+}
+
+#[tokio::test]
+async fn test_keeps_valid_incoming_id() {
+    let layer = RequestIdLayer::default();
+    let (id, _, response) = send(&layer, request_with(&X_REQUEST_ID, INCOMING)).await;
+    assert_eq!(id, MagicTypeId::from_str(INCOMING).unwrap());
+    assert_eq!(response.headers()[X_REQUEST_ID], INCOMING);
+
+    // Any valid identifier is kept, whatever its prefix
+    let other = "order_01h455vb4pex5vsknk084sn02q";
+    let (id, _, response) = send(&layer, request_with(&X_REQUEST_ID, other)).await;
+    assert_eq!(id, other);
+    assert_eq!(response.headers()[X_REQUEST_ID], other);
+}
+
+#[tokio::test]
+async fn test_mints_id_when_missing() {
+    let layer = RequestIdLayer::default();
+    let (id, value, response) = send(&layer, Request::new(())).await;
+    assert_eq!(id.prefix().as_str(), "req");
+    assert!(id.is_version::<V7>());
+    assert_eq!(value.unwrap(), id.as_str());
+    assert_eq!(response.headers()[X_REQUEST_ID], id.as_str());
+
+    // Every request gets its own identifier
+    let (second, _, _) = send(&layer, Request::new(())).await;
+    assert_ne!(second, id);
+}
+
+#[tokio::test]
+async fn test_replaces_invalid_id() {
+    let layer = RequestIdLayer::default();
+    for invalid in ["not-a-type-id", "Req_01h455vb4pex5vsknk084sn02q", ""] {
+        let (id, value, response) = send(&layer, request_with(&X_REQUEST_ID, invalid)).await;
+        assert_eq!(id.prefix().as_str(), "req");
+        assert_eq!(value.unwrap(), id.as_str());
+        assert_eq!(response.headers()[X_REQUEST_ID], id.as_str());
+    }
+}
+
+#[tokio::test]
+async fn test_custom_header_prefix_and_options() {
+    let header = HeaderName::from_static("x-correlation-id");
+    let layer = RequestIdLayer::new(TypeIdPrefix::try_from("corr").unwrap())
+        .header(header.clone())
+        .parse_options(ParseOptions::new().require_prefix("corr"));
+
+    let kept = "corr_01h455vb4pex5vsknk084sn02q";
+    let (id, _, response) = send(&layer, request_with(&header, kept)).await;
+    assert_eq!(id, kept);
+    assert_eq!(response.headers()[&header], kept);
+    assert!(response.headers().get(X_REQUEST_ID).is_none());
+
+    // An identifier rejected by the options is replaced like an invalid one
+    let (id, _, response) = send(&layer, request_with(&header, INCOMING)).await;
+    assert_eq!(id.prefix().as_str(), "corr");
+    assert_eq!(response.headers()[&header], id.as_str());
+}
+
+#[cfg(feature = "instrument")]
+#[tokio::test]
+async fn test_records_id_on_current_span() {
+    use std::sync::{Arc, Mutex};
+
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Id, Record};
+    use tracing::Instrument;
+    use tracing_subscriber::layer::{Context, SubscriberExt};
+    use tracing_subscriber::registry::Registry;
+
+    /// Collects the values recorded in `request_id` fields.
+    #[derive(Clone, Default)]
+    struct Recorded(Arc<Mutex<Vec<String>>>);
+
+    impl Visit for Recorded {
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            if field.name() == "request_id" {
+                self.0.lock().unwrap().push(format!("{value:?}"));
+            }
+        }
+    }
+
+    impl<S: tracing::Subscriber> tracing_subscriber::Layer<S> for Recorded {
+        fn on_record(&self, _: &Id, values: &Record<'_>, _: Context<'_, S>) {
+            values.record(&mut self.clone());
+        }
+    }
+
+    let recorded = Recorded::default();
+    let subscriber = Registry::default().with(recorded.clone());
+    let _guard = tracing::subscriber::set_default(subscriber);
+
+    let span = tracing::info_span!("request", request_id = tracing::field::Empty);
+    let (id, _, _) = send(&RequestIdLayer::default(), Request::new(()))
+        .instrument(span)
+        .await;
+    assert_eq!(*recorded.0.lock().unwrap(), [id.to_string()]);
+}
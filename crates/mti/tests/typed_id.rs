@@ -0,0 +1,98 @@
+//! Tests for `TypedId` and the `PrefixMarker` trait.
+//!
+//! This module verifies that typed identifiers only accept their marker's
+//! prefix and otherwise behave like `MagicTypeId`.
+use mti::prelude::*;
+use std::collections::HashSet;
+use std::str::FromStr;
+
+struct User;
+
+impl PrefixMarker for User {
+    const PREFIX: &'static str = "user";
+}
+
+struct Invalid;
+
+impl PrefixMarker for Invalid {
+    const PREFIX: &'static str = "Not Valid";
+}
+
+type UserId = TypedId<User>;
+
+#[test]
+fn test_new_uses_marker_prefix() {
+    let id = UserId::new::<V7>();
+    assert_eq!(id.prefix().as_str(), "user");
+    assert!(id.as_str().starts_with("user_"));
+    assert_eq!(id.len(), 31);
+}
+
+#[test]
+fn test_from_str_accepts_matching_prefix() {
+    let id = UserId::from_str("user_01h455vb4pex5vsknk084sn02q").unwrap();
+    assert_eq!(id, "user_01h455vb4pex5vsknk084sn02q");
+    assert_eq!(id.to_string(), "user_01h455vb4pex5vsknk084sn02q");
+    assert_eq!(id.suffix().to_string(), "01h455vb4pex5vsknk084sn02q");
+}
+
+#[test]
+fn test_from_str_rejects_other_prefix() {
+    let err = UserId::from_str("order_01h455vb4pex5vsknk084sn02q").unwrap_err();
+    assert_eq!(
+        err,
+        MagicTypeIdError::PrefixMismatch {
+            expected: "user".to_string(),
+            found: "order".to_string(),
+        }
+    );
+    assert!(UserId::from_str("01h455vb4pex5vsknk084sn02q").is_err());
+}
+
+#[test]
+fn test_from_str_propagates_parse_errors() {
+    assert!(matches!(
+        UserId::from_str("user_invalid"),
+        Err(MagicTypeIdError::Suffix(_))
+    ));
+}
+
+#[test]
+fn test_lossless_conversions() {
+    let original = MagicTypeId::from_str("user_01h455vb4pex5vsknk084sn02q").unwrap();
+    let typed = UserId::try_from(original.clone()).unwrap();
+    assert_eq!(typed, original);
+
+    let back: MagicTypeId = typed.into();
+    assert_eq!(back, original);
+
+    let other = MagicTypeId::from_str("order_01h455vb4pex5vsknk084sn02q").unwrap();
+    assert!(UserId::try_from(other).is_err());
+}
+
+#[test]
+fn test_ordering_and_hashing_match_magic_type_id() {
+    let a = UserId::from_str("user_01h455vb4pex5vsknk084sn02q").unwrap();
+    let b = UserId::from_str("user_01h455vb4pex5vsknk084sn02r").unwrap();
+    assert!(a < b);
+    assert_eq!(a.cmp(&b), a.as_magic_type_id().cmp(b.as_magic_type_id()));
+
+    let mut set = HashSet::new();
+    assert!(set.insert(a.clone()));
+    assert!(!set.insert(a));
+    assert!(set.insert(b));
+}
+
+#[test]
+fn test_from_uuid_roundtrip() {
+    let uuid = Uuid::new_v4();
+    let id = UserId::from_uuid(uuid);
+    assert_eq!(id.suffix().to_uuid(), uuid);
+    assert_eq!(id.into_inner().prefix().as_str(), "user");
+}
+
+#[test]
+#[should_panic(expected = "invalid prefix")]
+fn test_invalid_marker_prefix_panics() {
+    let _ = TypedId::<Invalid>::new::<V7>();
+}
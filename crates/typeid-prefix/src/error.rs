@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::fmt;
 
 /// Represents errors that can occur during validation of `TypeID` prefixes.
@@ -11,8 +12,14 @@ pub enum ValidationError {
 
     /// The input contains characters that are not allowed in a `TypeID` prefix.
     ///
-    /// Valid characters are lowercase ASCII letters and underscores.
-    ContainsInvalidCharacters,
+    /// Valid characters are lowercase ASCII letters and underscores. The error describes the
+    /// first invalid character.
+    ContainsInvalidCharacters {
+        /// The byte offset of the invalid character in the input.
+        position: usize,
+        /// The invalid character.
+        character: char,
+    },
 
     /// The input starts with an underscore, which is not allowed.
     StartsWithUnderscore,
@@ -45,27 +52,27 @@ impl fmt::Display for ValidationError {
     /// assert_eq!(error.to_string(), "Input exceeds 63 characters");
     /// ```
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let error_message = match self {
+        let error_message: Cow<'static, str> = match self {
             Self::ExceedsMaxLength => {
-                "Input exceeds 63 characters"
+                "Input exceeds 63 characters".into()
             }
-            Self::ContainsInvalidCharacters => {
-                "Input contains invalid characters: only lowercase ASCII letters and underscores are allowed"
+            Self::ContainsInvalidCharacters { position, character } => {
+                format!("Input contains invalid character {character:?} at position {position}: only lowercase ASCII letters and underscores are allowed").into()
             }
             Self::StartsWithUnderscore => {
-                "Input cannot start with an underscore"
+                "Input cannot start with an underscore".into()
             }
             Self::EndsWithUnderscore => {
-                "Input cannot end with an underscore"
+                "Input cannot end with an underscore".into()
             }
             Self::InvalidStartCharacter => {
-                "Input must start with a lowercase alphabetic character"
+                "Input must start with a lowercase alphabetic character".into()
             }
             Self::InvalidEndCharacter => {
-                "Input must end with a lowercase alphabetic character"
+                "Input must end with a lowercase alphabetic character".into()
             }
             Self::IsEmpty => {
-                "Input cannot be empty"
+                "Input cannot be empty".into()
             }
         };
 
@@ -49,6 +49,11 @@
 //!
 //! When the `instrument` feature is enabled, the crate will log validation errors
 //! using the `tracing` crate.
+//!
+//! ## Optional rusqlite Support
+//!
+//! When the `rusqlite` feature is enabled, `TypeIdPrefix` implements `ToSql` and `FromSql`
+//! from `rusqlite`, stored as text.
 
 pub use type_id_prefix::TypeIdPrefix;
 
@@ -190,7 +195,10 @@ mod tests {
     fn test_typeid_prefix_invalid_characters_with_spaces() {
         assert_eq!(
             TypeIdPrefix::try_from("invalid string with spaces").unwrap_err(),
-            ValidationError::ContainsInvalidCharacters
+            ValidationError::ContainsInvalidCharacters {
+                position: 7,
+                character: ' '
+            }
         );
         assert_eq!(
             "invalid string with spaces"
@@ -200,6 +208,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_typeid_prefix_invalid_character_position() {
+        assert_eq!(
+            TypeIdPrefix::try_from("user_Name").unwrap_err(),
+            ValidationError::ContainsInvalidCharacters {
+                position: 5,
+                character: 'N'
+            }
+        );
+        // Positions are byte offsets, and non-ASCII characters are reported whole
+        assert_eq!(
+            TypeIdPrefix::try_from("caf\u{e9}_menu").unwrap_err(),
+            ValidationError::ContainsInvalidCharacters {
+                position: 3,
+                character: '\u{e9}'
+            }
+        );
+        assert_eq!(
+            TypeIdPrefix::try_from("user_Name").unwrap_err().to_string(),
+            "Input contains invalid character 'N' at position 5: only lowercase ASCII letters and underscores are allowed"
+        );
+    }
+
     #[test]
     fn test_typeid_prefix_max_length() {
         let input = "a".repeat(63);
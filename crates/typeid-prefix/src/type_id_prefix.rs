@@ -34,6 +34,7 @@ pub struct TypeIdPrefix(String);
 
 #[cfg(feature = "serde")]
 impl Serialize for TypeIdPrefix {
+    /// Serializes the prefix as a string, in binary formats as well, since it is already text.
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -57,6 +58,23 @@ impl<'de> Deserialize<'de> for TypeIdPrefix {
     }
 }
 
+/// A `TypeIdPrefix` is stored as text.
+#[cfg(feature = "rusqlite")]
+impl rusqlite::types::ToSql for TypeIdPrefix {
+    fn to_sql(&self) -> rusqlite::Result<rusqlite::types::ToSqlOutput<'_>> {
+        Ok(self.0.as_str().into())
+    }
+}
+
+#[cfg(feature = "rusqlite")]
+impl rusqlite::types::FromSql for TypeIdPrefix {
+    /// Reads a `TypeIdPrefix` from text, failing with the [`ValidationError`] if it is invalid.
+    fn column_result(value: rusqlite::types::ValueRef<'_>) -> rusqlite::types::FromSqlResult<Self> {
+        Self::validate(value.as_str()?)
+            .map_err(|e| rusqlite::types::FromSqlError::Other(Box::new(e)))
+    }
+}
+
 impl PartialEq<str> for TypeIdPrefix {
     fn eq(&self, other: &str) -> bool {
         self.0 == other
@@ -205,6 +223,32 @@ impl TryFrom<&str> for TypeIdPrefix {
 
 impl TypeIdPrefix {
     pub(crate) fn validate(input: &str) -> Result<Self, ValidationError> {
+        Self::check(input)?;
+        Ok(Self(input.to_string()))
+    }
+
+    /// Checks whether `input` is a valid `TypeID` prefix without allocating.
+    ///
+    /// This applies exactly the same rules as [`TypeIdPrefix::from_str`](std::str::FromStr::from_str),
+    /// but only reports the outcome instead of building a `TypeIdPrefix`. It is useful for types
+    /// that store the prefix inline.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same `ValidationError` that parsing `input` as a `TypeIdPrefix` would.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_prefix::prelude::*;
+    ///
+    /// assert!(TypeIdPrefix::check("valid_prefix").is_ok());
+    /// assert_eq!(
+    ///     TypeIdPrefix::check("_invalid"),
+    ///     Err(ValidationError::StartsWithUnderscore)
+    /// );
+    /// ```
+    pub fn check(input: &str) -> Result<(), ValidationError> {
         if input.len() > 63 {
             return Err(ValidationError::ExceedsMaxLength);
         }
@@ -213,8 +257,11 @@ impl TypeIdPrefix {
             return Err(ValidationError::IsEmpty);
         }
 
-        if !input.is_ascii() {
-            return Err(ValidationError::ContainsInvalidCharacters);
+        if let Some((position, character)) = input.char_indices().find(|(_, c)| !c.is_ascii()) {
+            return Err(ValidationError::ContainsInvalidCharacters {
+                position,
+                character,
+            });
         }
 
         if input.starts_with('_') {
@@ -233,11 +280,17 @@ impl TypeIdPrefix {
             return Err(ValidationError::InvalidEndCharacter);
         }
 
-        if !input.chars().all(|c| c.is_ascii_lowercase() || c == '_') {
-            return Err(ValidationError::ContainsInvalidCharacters);
+        if let Some((position, character)) = input
+            .char_indices()
+            .find(|&(_, c)| !(c.is_ascii_lowercase() || c == '_'))
+        {
+            return Err(ValidationError::ContainsInvalidCharacters {
+                position,
+                character,
+            });
         }
 
-        Ok(Self(input.to_string()))
+        Ok(())
     }
 
     pub(crate) fn clean_inner(input: &str) -> String {
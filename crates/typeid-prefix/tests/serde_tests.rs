@@ -1,8 +1,8 @@
-//! Tests for serde serialization and deserialization of TypeIdPrefix.
+//! Tests for serde serialization and deserialization of `TypeIdPrefix`.
 //!
-//! This module tests the serde implementation for the TypeIdPrefix struct,
+//! This module tests the serde implementation for the `TypeIdPrefix` struct,
 //! ensuring that it correctly serializes to and deserializes from strings
-//! while maintaining the validation rules of the TypeID specification.
+//! while maintaining the validation rules of the `TypeID` specification.
 
 #![cfg(feature = "serde")]
 #![doc(hidden)]
@@ -80,3 +80,17 @@ fn test_empty_string() {
     let result: Result<TypeIdPrefix, _> = serde_json::from_str(json);
     assert!(result.is_err(), "Should fail to deserialize empty string");
 }
+
+#[test]
+fn test_binary_format_roundtrip() {
+    // A prefix is text either way, so binary formats store it as a string too
+    let original = TypeIdPrefix::try_from("user_account").unwrap();
+    let bytes = bincode::serialize(&original).unwrap();
+    assert_eq!(bytes.len(), 8 + "user_account".len());
+
+    let deserialized: TypeIdPrefix = bincode::deserialize(&bytes).unwrap();
+    assert_eq!(original, deserialized);
+
+    let invalid = bincode::serialize("User").unwrap();
+    assert!(bincode::deserialize::<TypeIdPrefix>(&invalid).is_err());
+}
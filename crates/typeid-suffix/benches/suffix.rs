@@ -0,0 +1,77 @@
+//! Benchmarks for sorting, hashing and comparing `TypeIdSuffix` values.
+//!
+//! Run with `cargo bench -p typeid_suffix --bench suffix`.
+#![allow(missing_docs, unused_results)]
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use typeid_suffix::prelude::*;
+
+const COUNT: usize = 1_000;
+
+fn suffixes<V: UuidVersion + Default>() -> Vec<TypeIdSuffix> {
+    let mut suffixes: Vec<TypeIdSuffix> = (0..COUNT).map(|_| TypeIdSuffix::new::<V>()).collect();
+    // Start from a fixed, unsorted order
+    suffixes.reverse();
+    suffixes
+}
+
+fn bench_sort(c: &mut Criterion) {
+    let v7 = suffixes::<V7>();
+    let v4 = suffixes::<V4>();
+
+    let mut group = c.benchmark_group("sort");
+    group.bench_function("v7", |b| {
+        b.iter_batched(|| v7.clone(), |mut s| s.sort(), BatchSize::SmallInput);
+    });
+    group.bench_function("v4", |b| {
+        b.iter_batched(|| v4.clone(), |mut s| s.sort(), BatchSize::SmallInput);
+    });
+    group.finish();
+}
+
+fn bench_hash(c: &mut Criterion) {
+    let suffix = TypeIdSuffix::new::<V7>();
+    let v7 = suffixes::<V7>();
+
+    let mut group = c.benchmark_group("hash");
+    group.bench_function("single", |b| {
+        b.iter(|| {
+            let mut hasher = DefaultHasher::new();
+            black_box(&suffix).hash(&mut hasher);
+            hasher.finish()
+        });
+    });
+    group.bench_function("hash_set_insert", |b| {
+        b.iter(|| v7.iter().collect::<HashSet<_>>());
+    });
+    group.finish();
+}
+
+fn bench_compare(c: &mut Criterion) {
+    let a = TypeIdSuffix::new::<V7>();
+    let b = TypeIdSuffix::new::<V7>();
+
+    c.bench_function("compare", |bench| {
+        bench.iter(|| black_box(&a).cmp(black_box(&b)));
+    });
+}
+
+fn bench_to_uuid(c: &mut Criterion) {
+    let suffix = TypeIdSuffix::new::<V7>();
+
+    c.bench_function("to_uuid", |b| b.iter(|| black_box(&suffix).to_uuid()));
+}
+
+criterion_group!(
+    benches,
+    bench_sort,
+    bench_hash,
+    bench_compare,
+    bench_to_uuid
+);
+criterion_main!(benches);
@@ -44,13 +44,16 @@ pub fn decode_base32(encoded: &[u8; 26]) -> Result<[u8; 16], DecodeError> {
     let mut uuid_int = 0u128;
 
     // Iterate over each character in the encoded input
-    for &character in encoded {
+    for (position, &character) in encoded.iter().enumerate() {
         // Look up the 5-bit value corresponding to this character
         let value = DECODE_TABLE[character as usize];
         // If the character is invalid (not part of the base32 alphabet), return an error
         if value == 0xFF {
             return Err(DecodeError::InvalidSuffix(
-                InvalidSuffixReason::InvalidCharacter,
+                InvalidSuffixReason::InvalidCharacter {
+                    position,
+                    character: char::from(character),
+                },
             ));
         }
         // Shift the existing number left by 5 bits and add the new 5-bit value
@@ -32,11 +32,25 @@ pub enum InvalidSuffixReason {
     /// The suffix does not have the required length of 26 characters.
     InvalidLength,
     /// The suffix contains one or more non-ASCII characters.
-    NonAsciiCharacter,
+    ///
+    /// The error describes the first non-ASCII character.
+    NonAsciiCharacter {
+        /// The byte offset of the character in the suffix.
+        position: usize,
+        /// The non-ASCII character.
+        character: char,
+    },
     /// The first character of the suffix is greater than '7'.
     InvalidFirstCharacter,
     /// The suffix contains a character that is not in the base32 alphabet.
-    InvalidCharacter,
+    ///
+    /// The error describes the first such character.
+    InvalidCharacter {
+        /// The byte offset of the character in the suffix.
+        position: usize,
+        /// The character that is not in the base32 alphabet.
+        character: char,
+    },
 }
 
 /// Specifies the reason for an invalid UUID.
@@ -74,10 +88,20 @@ impl fmt::Display for InvalidSuffixReason {
     /// Provides a human-readable description of the invalid suffix reason.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let msg = match self {
-            Self::InvalidLength => "Suffix must be exactly 26 characters long",
-            Self::NonAsciiCharacter => "Suffix contains non-ASCII characters",
-            Self::InvalidFirstCharacter => "First character of suffix must be '7' or less",
-            Self::InvalidCharacter => "Suffix contains characters not in the base32 alphabet",
+            Self::InvalidLength => "Suffix must be exactly 26 characters long".to_string(),
+            Self::NonAsciiCharacter {
+                position,
+                character,
+            } => format!("Suffix contains non-ASCII character {character:?} at position {position}"),
+            Self::InvalidFirstCharacter => {
+                "First character of suffix must be '7' or less".to_string()
+            }
+            Self::InvalidCharacter {
+                position,
+                character,
+            } => format!(
+                "Suffix contains character {character:?} at position {position}, which is not in the base32 alphabet"
+            ),
         };
 
         #[cfg(feature = "instrument")]
@@ -16,6 +16,7 @@
 //! - **Error Handling**: Comprehensive error types for invalid suffixes and UUIDs.
 //! - **Validation**: Robust validation for `TypeID` suffixes and UUIDs.
 //! - **Zero-cost Abstractions**: Designed to have minimal runtime overhead.
+//! - **Monotonic Generation**: Strictly increasing `UUIDv7` suffixes from a shared generator.
 //! - **Optional Tracing**: Integrates with the `tracing` crate for logging (optional feature).
 //!
 //! ## Quick Start
@@ -76,6 +77,20 @@
 //! assert_eq!(uuid, recovered_uuid);
 //! ```
 //!
+//! ### Generating Strictly Increasing Suffixes
+//!
+//! A [`MonotonicGenerator`](prelude::MonotonicGenerator) produces `UUIDv7` suffixes that are
+//! strictly increasing, even within the same millisecond:
+//!
+//! ```rust
+//! use typeid_suffix::prelude::*;
+//!
+//! let generator = MonotonicGenerator::new();
+//! let first = generator.next_suffix();
+//! let second = generator.next_suffix();
+//! assert!(first < second);
+//! ```
+//!
 //! ### Error Handling
 //!
 //! ```rust
@@ -93,6 +108,9 @@
 //!
 //! - `instrument`: Enables logging with the `tracing` crate.
 //! - `serde`: Enables serialization and deserialization support using the `serde` crate.
+//! - `sqlx`: Implements `sqlx::Type`, `Encode` and `Decode`, storing a suffix as its UUID.
+//! - `diesel`: Implements Diesel's `ToSql` and `FromSql` for the `Uuid` and `Binary` SQL types.
+//! - `rusqlite`: Implements `ToSql` and `FromSql` from `rusqlite`, storing a suffix as a 16-byte blob.
 //!
 //! To enable optional features, add them to your `Cargo.toml`:
 //!
@@ -107,7 +125,10 @@
 //! This allows `TypeIdSuffix` instances to be easily serialized to and deserialized from various formats
 //! like JSON, YAML, CBOR, etc., that are supported by Serde.
 //!
-//! `TypeIdSuffix` is serialized as its string representation and deserialized from a string.
+//! `TypeIdSuffix` is serialized as its string representation in human-readable formats. In binary
+//! formats (where `Serializer::is_human_readable()` returns `false`), it is serialized as the 16 bytes
+//! of its UUID, and deserialized from either those bytes or the string. `NamespaceId` is serialized
+//! the same way.
 //!
 //! ```rust
 //! # #[cfg(feature = "serde")] {
@@ -149,7 +170,11 @@
 
 mod encoding;
 mod errors;
+mod monotonic;
 mod namespace;
+#[cfg(feature = "serde")]
+mod serde_support;
+mod source;
 mod typeid_suffix;
 mod versions;
 
@@ -160,10 +185,12 @@ mod versions;
 pub mod prelude {
     pub use std::str::FromStr;
 
-    pub use uuid::{Uuid, Version};
+    pub use uuid::{Uuid, Variant, Version};
 
     pub use crate::errors::*;
+    pub use crate::monotonic::MonotonicGenerator;
     pub use crate::namespace::NamespaceId;
+    pub use crate::source::{FromIdSource, IdSource, SeededIdSource, SystemIdSource};
     pub use crate::typeid_suffix::TypeIdSuffix;
     pub use crate::versions::*;
 }
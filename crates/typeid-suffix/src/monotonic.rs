@@ -0,0 +1,253 @@
+//! Monotonic `UUIDv7` generation.
+//!
+//! This module provides [`MonotonicGenerator`], which produces `UUIDv7` values that are strictly
+//! increasing, even when many are generated within the same millisecond or the system clock
+//! moves backwards.
+//!
+//! # Examples
+//!
+//! ```
+//! use typeid_suffix::prelude::*;
+//!
+//! let generator = MonotonicGenerator::new();
+//!
+//! let first = generator.next_suffix();
+//! let second = generator.next_suffix();
+//! assert!(first < second);
+//! ```
+
+use std::sync::{Mutex, PoisonError};
+
+use uuid::Uuid;
+
+use crate::source::{IdSource, SystemIdSource};
+use crate::typeid_suffix::TypeIdSuffix;
+
+/// The largest timestamp that fits in the 48-bit `unix_ts_ms` field of a `UUIDv7`.
+const MAX_MILLIS: u64 = (1 << 48) - 1;
+
+/// The largest value of the 74 bits that follow the timestamp (`rand_a` and `rand_b`).
+const MAX_COUNTER: u128 = (1 << 74) - 1;
+
+/// Generates strictly increasing `UUIDv7` values.
+///
+/// Each UUID carries the current Unix timestamp in milliseconds, followed by 74 bits that are
+/// treated as a counter, as described by the "monotonic random" method of
+/// [RFC 9562, section 6.2](https://www.rfc-editor.org/rfc/rfc9562#section-6.2):
+///
+/// - When the clock has advanced since the last UUID, the counter is reseeded with random bits,
+///   leaving its most significant bit clear so that it has room to grow.
+/// - Within the same millisecond, or when the clock has moved backwards, the timestamp of the last
+///   UUID is kept and the counter is incremented by a random amount.
+/// - If the counter would overflow, the timestamp is advanced by one millisecond and the counter is
+///   reseeded.
+///
+/// Every UUID returned by a generator is therefore greater than all the UUIDs it returned before.
+/// The generator is thread-safe: share it (for example in a `static` or an `Arc`) to get a single
+/// increasing sequence across threads. Separate generators do not coordinate with each other.
+///
+/// The clock and the random bits come from an [`IdSource`], which defaults to [`SystemIdSource`].
+/// Use [`with_source`](MonotonicGenerator::with_source) with a
+/// [`SeededIdSource`](crate::prelude::SeededIdSource) to get a reproducible sequence in tests.
+///
+/// # Examples
+///
+/// ```
+/// use typeid_suffix::prelude::*;
+///
+/// static IDS: MonotonicGenerator = MonotonicGenerator::new();
+///
+/// let suffixes: Vec<TypeIdSuffix> = (0..1000).map(|_| IDS.next_suffix()).collect();
+/// assert!(suffixes.windows(2).all(|pair| pair[0] < pair[1]));
+/// assert_eq!(suffixes[0].to_uuid().get_version(), Some(Version::SortRand));
+/// ```
+#[derive(Debug, Default)]
+pub struct MonotonicGenerator<S = SystemIdSource> {
+    source: S,
+    state: Mutex<State>,
+}
+
+/// The timestamp and counter of the last UUID returned by a generator.
+#[derive(Debug, Default)]
+struct State {
+    millis: u64,
+    counter: u128,
+}
+
+impl MonotonicGenerator {
+    /// Creates a new generator that uses the system clock and OS randomness.
+    ///
+    /// This is a `const fn`, so a generator can be stored in a `static`.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::with_source(SystemIdSource)
+    }
+}
+
+impl<S: IdSource> MonotonicGenerator<S> {
+    /// Creates a new generator that reads the clock and random bits from `source`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let generator = MonotonicGenerator::with_source(SeededIdSource::new(1));
+    /// let replay = MonotonicGenerator::with_source(SeededIdSource::new(1));
+    ///
+    /// for _ in 0..10 {
+    ///     assert_eq!(generator.next_suffix(), replay.next_suffix());
+    /// }
+    /// ```
+    #[must_use]
+    pub const fn with_source(source: S) -> Self {
+        Self {
+            source,
+            state: Mutex::new(State {
+                millis: 0,
+                counter: 0,
+            }),
+        }
+    }
+
+    /// Returns the next `UUIDv7`, which is greater than every UUID this generator returned before.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let generator = MonotonicGenerator::new();
+    /// let uuid = generator.next_uuid();
+    ///
+    /// assert_eq!(uuid.get_version(), Some(Version::SortRand));
+    /// assert!(generator.next_uuid() > uuid);
+    /// ```
+    #[cfg_attr(
+        feature = "instrument",
+        tracing::instrument(level = "trace", skip(self))
+    )]
+    #[must_use]
+    pub fn next_uuid(&self) -> Uuid {
+        let now = u64::try_from(self.source.now().as_millis())
+            .map_or(MAX_MILLIS, |millis| millis.min(MAX_MILLIS));
+        let mut random = [0; 16];
+        self.source.fill_bytes(&mut random);
+        self.advance(now, u128::from_le_bytes(random))
+    }
+
+    /// Returns the next `UUIDv7` as a `TypeIdSuffix`.
+    ///
+    /// See [`MonotonicGenerator::next_uuid`].
+    #[must_use]
+    pub fn next_suffix(&self) -> TypeIdSuffix {
+        TypeIdSuffix::from(self.next_uuid())
+    }
+
+    /// Advances the generator to the time `now` (in Unix milliseconds), drawing fresh counter
+    /// bits from `random`.
+    fn advance(&self, now: u64, random: u128) -> Uuid {
+        // The state is updated in one step, so it is always consistent even if another thread
+        // panicked while holding the lock.
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+
+        if now > state.millis {
+            state.millis = now;
+            state.counter = seed(random);
+        } else if let Some(counter) = state
+            .counter
+            .checked_add(increment(random))
+            .filter(|&counter| counter <= MAX_COUNTER)
+        {
+            state.counter = counter;
+        } else {
+            state.millis = (state.millis + 1).min(MAX_MILLIS);
+            state.counter = seed(random);
+        }
+
+        encode(state.millis, state.counter)
+    }
+}
+
+/// Builds a fresh counter from random bits, with its most significant bit clear.
+const fn seed(random: u128) -> u128 {
+    let rand_a = (random >> 64) & 0x7FF;
+    let rand_b = random & ((1 << 62) - 1);
+    (rand_a << 62) | rand_b
+}
+
+/// Returns a random increment between 1 and 2^32.
+const fn increment(random: u128) -> u128 {
+    ((random >> 80) & 0xFFFF_FFFF) + 1
+}
+
+/// Lays out a timestamp and a 74-bit counter as a `UUIDv7`.
+const fn encode(millis: u64, counter: u128) -> Uuid {
+    let rand_a = (counter >> 62) & 0xFFF;
+    let rand_b = counter & ((1 << 62) - 1);
+    Uuid::from_u128(((millis as u128) << 80) | (0x7 << 76) | (rand_a << 64) | (0b10 << 62) | rand_b)
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Version;
+
+    use super::*;
+
+    #[test]
+    fn encodes_a_valid_uuidv7() {
+        let uuid = encode(0x0189_0A5D_AC96, MAX_COUNTER);
+        assert_eq!(uuid.get_version(), Some(Version::SortRand));
+        assert_eq!(uuid.get_variant(), uuid::Variant::RFC4122);
+        assert_eq!(
+            uuid.get_timestamp().map(|ts| ts.to_unix()),
+            Some((1_688_096_058, 518_000_000))
+        );
+    }
+
+    #[test]
+    fn increases_within_the_same_millisecond() {
+        let generator = MonotonicGenerator::new();
+        let first = generator.advance(1_000, u128::MAX);
+        let second = generator.advance(1_000, 0);
+        let third = generator.advance(1_000, u128::MAX);
+
+        assert!(first < second && second < third);
+        assert_eq!(third.as_u128() >> 80, 1_000);
+    }
+
+    #[test]
+    fn increases_across_clock_regressions() {
+        let generator = MonotonicGenerator::new();
+        let before = generator.advance(5_000, 42);
+        let after = generator.advance(4_000, 42);
+
+        assert!(before < after);
+        assert_eq!(after.as_u128() >> 80, 5_000);
+    }
+
+    #[test]
+    fn reseeds_when_the_clock_advances() {
+        let generator = MonotonicGenerator::new();
+        let first = generator.advance(1_000, u128::MAX);
+        let second = generator.advance(1_001, 0);
+
+        assert!(first < second);
+        assert_eq!(second.as_u128() >> 80, 1_001);
+    }
+
+    #[test]
+    fn advances_the_timestamp_on_counter_overflow() {
+        let generator = MonotonicGenerator::new();
+        let first = generator.advance(1_000, 0);
+        generator
+            .state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .counter = MAX_COUNTER;
+        let second = generator.advance(1_000, 0);
+
+        assert!(first < second);
+        assert_eq!(second.as_u128() >> 80, 1_001);
+    }
+}
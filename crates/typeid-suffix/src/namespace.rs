@@ -35,6 +35,9 @@ use uuid::Uuid;
 
 use crate::errors::DecodeError;
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
 /// A validated namespace identifier for V3 and V5 UUIDs.
 ///
 /// Namespaces provide a way to create deterministic UUIDs from names.
@@ -272,6 +275,51 @@ impl AsRef<Uuid> for NamespaceId {
     }
 }
 
+#[cfg(feature = "serde")]
+impl Serialize for NamespaceId {
+    /// Serializes the `NamespaceId` as a hyphenated UUID string in human-readable formats, and
+    /// as the 16 bytes of the UUID in binary formats.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.collect_str(&self.0)
+        } else {
+            serializer.serialize_bytes(self.0.as_bytes())
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for NamespaceId {
+    /// Deserializes a `NamespaceId` from a UUID string, or, in binary formats, from either a
+    /// UUID string or the 16 bytes of a UUID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is not a valid UUID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "serde")] {
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let json = "\"6ba7b810-9dad-11d1-80b4-00c04fd430c8\"";
+    /// let namespace: NamespaceId = serde_json::from_str(json).unwrap();
+    /// assert_eq!(namespace, NamespaceId::DNS);
+    /// assert_eq!(serde_json::to_string(&namespace).unwrap(), json);
+    /// # }
+    /// ```
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        crate::serde_support::deserialize_uuid_or_str(deserializer, "a UUID")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
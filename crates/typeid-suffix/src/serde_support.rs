@@ -0,0 +1,89 @@
+//! Shared deserialization for types that wrap a UUID.
+//!
+//! In human-readable formats, these types are represented by their string form. In binary
+//! formats, they are written as the 16 bytes of their UUID, but a string is accepted too, so
+//! that data written before the compact form existed can still be read.
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use serde::de::{self, Deserializer, SeqAccess, Visitor};
+use serde::Deserialize;
+use uuid::Uuid;
+
+/// Deserializes a `T` from its string form, or, in binary formats, from 16 UUID bytes or its
+/// string form.
+pub fn deserialize_uuid_or_str<'de, T, D>(
+    deserializer: D,
+    expecting: &'static str,
+) -> Result<T, D::Error>
+where
+    T: From<Uuid> + FromStr,
+    T::Err: fmt::Display,
+    D: Deserializer<'de>,
+{
+    if deserializer.is_human_readable() {
+        let s = String::deserialize(deserializer)?;
+        T::from_str(&s).map_err(de::Error::custom)
+    } else {
+        deserializer.deserialize_bytes(UuidOrStrVisitor {
+            expecting,
+            target: PhantomData,
+        })
+    }
+}
+
+/// Visits the 16 bytes of a UUID, or a string to parse.
+struct UuidOrStrVisitor<T> {
+    expecting: &'static str,
+    target: PhantomData<fn() -> T>,
+}
+
+impl<'de, T> Visitor<'de> for UuidOrStrVisitor<T>
+where
+    T: From<Uuid> + FromStr,
+    T::Err: fmt::Display,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} string or 16 UUID bytes", self.expecting)
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        T::from_str(v).map_err(E::custom)
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        if let Ok(uuid) = Uuid::from_slice(v) {
+            return Ok(T::from(uuid));
+        }
+        // Formats that do not distinguish strings from bytes hand over the string form as bytes
+        std::str::from_utf8(v)
+            .map_err(|_| E::invalid_length(v.len(), &self))
+            .and_then(|s| self.visit_str(s))
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut bytes = [0u8; 16];
+        for (index, byte) in bytes.iter_mut().enumerate() {
+            *byte = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(index, &self))?;
+        }
+        if seq.next_element::<u8>()?.is_some() {
+            return Err(de::Error::invalid_length(17, &self));
+        }
+        Ok(T::from(Uuid::from_bytes(bytes)))
+    }
+}
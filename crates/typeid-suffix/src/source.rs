@@ -0,0 +1,274 @@
+//! Pluggable clocks and entropy for identifier generation.
+//!
+//! By default, new suffixes read the system clock and the operating system's random number
+//! generator through the `Default` implementations of the UUID versions. This module provides the
+//! [`IdSource`] trait, which supplies both explicitly, so that generation can be made
+//! deterministic:
+//!
+//! - [`SystemIdSource`] uses the system clock and OS randomness, like the default constructors.
+//! - [`SeededIdSource`] uses a fixed (optionally ticking) clock and a seeded pseudo-random number
+//!   generator, which makes it produce the same sequence of identifiers on every run.
+//!
+//! # Examples
+//!
+//! ```
+//! use typeid_suffix::prelude::*;
+//!
+//! let first_run = SeededIdSource::new(42);
+//! let second_run = SeededIdSource::new(42);
+//!
+//! assert_eq!(
+//!     TypeIdSuffix::new_with_source::<V7, _>(&first_run),
+//!     TypeIdSuffix::new_with_source::<V7, _>(&second_run),
+//! );
+//! ```
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use uuid::{Builder, Uuid};
+
+use crate::versions::{Nil, UuidVersion, V1, V3, V4, V5, V6, V7};
+
+/// The number of 100-nanosecond intervals between the Gregorian epoch (1582-10-15) and the Unix
+/// epoch, as used by the timestamps of V1 and V6 UUIDs.
+pub const GREGORIAN_OFFSET: u64 = 0x01B2_1DD2_1381_4000;
+
+/// The increment of the `SplitMix64` generator.
+const SPLITMIX_GAMMA: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// Supplies the clock and the entropy used to generate new identifiers.
+///
+/// Implementations must be usable from several threads at once, so both methods take `&self`.
+pub trait IdSource {
+    /// Returns the current time as the duration since the Unix epoch.
+    fn now(&self) -> Duration;
+
+    /// Fills `buf` with random bytes.
+    fn fill_bytes(&self, buf: &mut [u8]);
+}
+
+impl<S: IdSource + ?Sized> IdSource for &S {
+    fn now(&self) -> Duration {
+        (**self).now()
+    }
+
+    fn fill_bytes(&self, buf: &mut [u8]) {
+        (**self).fill_bytes(buf);
+    }
+}
+
+/// An [`IdSource`] backed by the system clock and the operating system's random number generator.
+///
+/// Generating from this source behaves like the `Default` implementations of the UUID versions.
+///
+/// # Examples
+///
+/// ```
+/// use typeid_suffix::prelude::*;
+///
+/// let suffix = TypeIdSuffix::new_with_source::<V7, _>(&SystemIdSource);
+/// assert_eq!(suffix.to_uuid().get_version(), Some(Version::SortRand));
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemIdSource;
+
+impl IdSource for SystemIdSource {
+    fn now(&self) -> Duration {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+    }
+
+    fn fill_bytes(&self, buf: &mut [u8]) {
+        // A UUIDv4 carries 122 random bits; use the 13 bytes that hold no version or variant bits
+        for chunk in buf.chunks_mut(13) {
+            let bytes = Uuid::new_v4().into_bytes();
+            let random = bytes[..6].iter().chain(&bytes[9..]);
+            for (dst, src) in chunk.iter_mut().zip(random) {
+                *dst = *src;
+            }
+        }
+    }
+}
+
+/// A deterministic [`IdSource`] for tests.
+///
+/// Its clock starts at a fixed time (the Unix epoch unless set with
+/// [`with_start_time`](SeededIdSource::with_start_time)) and advances by a fixed tick on every
+/// read (no tick unless set with [`with_tick`](SeededIdSource::with_tick)). Its random bytes come
+/// from a `SplitMix64` generator seeded with the given seed. Two sources built the same way
+/// therefore produce the same sequence of identifiers.
+///
+/// The generator is not cryptographically secure and must not be used in production.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::{Duration, UNIX_EPOCH};
+/// use typeid_suffix::prelude::*;
+///
+/// let source = SeededIdSource::new(7)
+///     .with_start_time(UNIX_EPOCH + Duration::from_secs(1_700_000_000))
+///     .with_tick(Duration::from_millis(1));
+///
+/// let first = TypeIdSuffix::new_with_source::<V7, _>(&source);
+/// let second = TypeIdSuffix::new_with_source::<V7, _>(&source);
+/// assert!(first < second);
+///
+/// let replay = SeededIdSource::new(7)
+///     .with_start_time(UNIX_EPOCH + Duration::from_secs(1_700_000_000))
+///     .with_tick(Duration::from_millis(1));
+/// assert_eq!(TypeIdSuffix::new_with_source::<V7, _>(&replay), first);
+/// ```
+#[derive(Debug)]
+pub struct SeededIdSource {
+    clock_nanos: AtomicU64,
+    tick_nanos: u64,
+    state: AtomicU64,
+}
+
+impl SeededIdSource {
+    /// Creates a source whose random bytes are derived from `seed`, with a clock stopped at the
+    /// Unix epoch.
+    #[must_use]
+    pub const fn new(seed: u64) -> Self {
+        Self {
+            clock_nanos: AtomicU64::new(0),
+            tick_nanos: 0,
+            state: AtomicU64::new(seed),
+        }
+    }
+
+    /// Sets the time returned by the first read of the clock.
+    ///
+    /// Times before the Unix epoch are clamped to it, and times after the year 2554 are clamped
+    /// to the largest representable time.
+    #[must_use]
+    pub fn with_start_time(self, start: SystemTime) -> Self {
+        let nanos = start
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, duration_to_nanos);
+        Self {
+            clock_nanos: AtomicU64::new(nanos),
+            ..self
+        }
+    }
+
+    /// Sets how far the clock advances after each read.
+    #[must_use]
+    pub fn with_tick(self, tick: Duration) -> Self {
+        Self {
+            tick_nanos: duration_to_nanos(tick),
+            ..self
+        }
+    }
+
+    /// Returns the next output of the `SplitMix64` generator.
+    fn next_u64(&self) -> u64 {
+        let mut z = self
+            .state
+            .fetch_add(SPLITMIX_GAMMA, Ordering::Relaxed)
+            .wrapping_add(SPLITMIX_GAMMA);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+impl IdSource for SeededIdSource {
+    fn now(&self) -> Duration {
+        Duration::from_nanos(
+            self.clock_nanos
+                .fetch_add(self.tick_nanos, Ordering::Relaxed),
+        )
+    }
+
+    fn fill_bytes(&self, buf: &mut [u8]) {
+        for chunk in buf.chunks_mut(8) {
+            let random = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&random[..chunk.len()]);
+        }
+    }
+}
+
+/// Converts a duration to nanoseconds, saturating at `u64::MAX`.
+fn duration_to_nanos(duration: Duration) -> u64 {
+    u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
+}
+
+/// UUID versions that can be generated from an [`IdSource`].
+///
+/// Time-based versions read the clock of the source, and all versions that need randomness draw
+/// it from the source, so a deterministic source yields deterministic UUIDs.
+pub trait FromIdSource: UuidVersion + Sized {
+    /// Generates a UUID of this version from `source`.
+    fn from_source<S: IdSource + ?Sized>(source: &S) -> Self;
+}
+
+impl FromIdSource for V1 {
+    /// Uses the clock of the source and random bytes for the clock sequence and node ID.
+    fn from_source<S: IdSource + ?Sized>(source: &S) -> Self {
+        let (ticks, counter, node_id) = gregorian_parts(source);
+        Self(Builder::from_gregorian_timestamp(ticks, counter, &node_id).into_uuid())
+    }
+}
+
+impl FromIdSource for V3 {
+    /// V3 UUIDs are name-based, so the source is not used.
+    fn from_source<S: IdSource + ?Sized>(_source: &S) -> Self {
+        Self::default()
+    }
+}
+
+impl FromIdSource for V4 {
+    fn from_source<S: IdSource + ?Sized>(source: &S) -> Self {
+        let mut bytes = [0; 16];
+        source.fill_bytes(&mut bytes);
+        Self(Builder::from_random_bytes(bytes).into_uuid())
+    }
+}
+
+impl FromIdSource for V5 {
+    /// V5 UUIDs are name-based, so the source is not used.
+    fn from_source<S: IdSource + ?Sized>(_source: &S) -> Self {
+        Self::default()
+    }
+}
+
+impl FromIdSource for V6 {
+    /// Uses the clock of the source and random bytes for the clock sequence and node ID.
+    fn from_source<S: IdSource + ?Sized>(source: &S) -> Self {
+        let (ticks, counter, node_id) = gregorian_parts(source);
+        Self(Builder::from_sorted_gregorian_timestamp(ticks, counter, &node_id).into_uuid())
+    }
+}
+
+impl FromIdSource for V7 {
+    fn from_source<S: IdSource + ?Sized>(source: &S) -> Self {
+        let millis = u64::try_from(source.now().as_millis()).unwrap_or(u64::MAX);
+        let mut random = [0; 10];
+        source.fill_bytes(&mut random);
+        Self(Builder::from_unix_timestamp_millis(millis, &random).into_uuid())
+    }
+}
+
+impl FromIdSource for Nil {
+    /// The Nil UUID is constant, so the source is not used.
+    fn from_source<S: IdSource + ?Sized>(_source: &S) -> Self {
+        Self::default()
+    }
+}
+
+/// Reads the Gregorian timestamp, clock sequence and node ID of a V1 or V6 UUID from `source`.
+fn gregorian_parts<S: IdSource + ?Sized>(source: &S) -> (u64, u16, [u8; 6]) {
+    let ticks = u64::try_from(source.now().as_nanos() / 100)
+        .unwrap_or(u64::MAX)
+        .saturating_add(GREGORIAN_OFFSET);
+    let mut random = [0; 8];
+    source.fill_bytes(&mut random);
+    let counter = u16::from_le_bytes([random[0], random[1]]);
+    let mut node_id = [0; 6];
+    node_id.copy_from_slice(&random[2..]);
+    (ticks, counter, node_id)
+}
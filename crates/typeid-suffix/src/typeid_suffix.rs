@@ -4,8 +4,10 @@
 use std::borrow::Borrow;
 use std::cmp::Ordering;
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::ops::Deref;
 use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use uuid::{Uuid, Variant, Version};
 
@@ -15,14 +17,28 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use crate::encoding::{decode_base32, encode_base32};
 use crate::errors::{DecodeError, InvalidSuffixReason, InvalidUuidReason};
 use crate::namespace::NamespaceId;
-use crate::versions::{UuidVersion, V7};
+use crate::source::{FromIdSource, IdSource, GREGORIAN_OFFSET};
+use crate::versions::{CustomLayout, SuffixVersion, UuidVersion, V7, V8};
 
 /// Represents a `TypeId` suffix, which is a 26-character base32-encoded UUID.
 ///
 /// This struct encapsulates the suffix part of a `TypeId`, providing methods for
 /// creation, conversion, and validation.
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TypeIdSuffix([u8; 26]);
+///
+/// The suffix is stored in decoded form, so comparing, hashing and converting it to a
+/// `Uuid` never decode base32. The encoded representation is computed once, when the
+/// suffix is created, and only backs `Deref<Target = str>`, `Display` and serialization.
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow)
+)]
+#[cfg_attr(feature = "diesel", diesel(sql_type = diesel::sql_types::Uuid))]
+#[cfg_attr(feature = "diesel", diesel(sql_type = diesel::sql_types::Binary))]
+pub struct TypeIdSuffix {
+    uuid: Uuid,
+    encoded: [u8; 26],
+}
 
 impl TypeIdSuffix {
     /// Creates a new ``TypeIdSuffix`` from a specific UUID version.
@@ -52,7 +68,48 @@ impl TypeIdSuffix {
     where
         V: UuidVersion + Default,
     {
-        Self(encode_base32(V::default().as_bytes()))
+        Self::from(*V::default())
+    }
+
+    /// Creates a new `TypeIdSuffix` of a specific UUID version from an [`IdSource`].
+    ///
+    /// Unlike [`TypeIdSuffix::new`], which reads the system clock and OS randomness, this method
+    /// takes both from `source`. With a [`SeededIdSource`](crate::prelude::SeededIdSource), the
+    /// same source configuration always produces the same sequence of suffixes.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `V`: A UUID version that implements `FromIdSource`.
+    /// * `S`: The type of the source.
+    ///
+    /// # Returns
+    ///
+    /// A new `TypeIdSuffix` instance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let source = SeededIdSource::new(42);
+    /// let suffixes: Vec<_> = (0..3)
+    ///     .map(|_| TypeIdSuffix::new_with_source::<V4, _>(&source))
+    ///     .collect();
+    ///
+    /// let replay = SeededIdSource::new(42);
+    /// for suffix in &suffixes {
+    ///     assert_eq!(&TypeIdSuffix::new_with_source::<V4, _>(&replay), suffix);
+    /// }
+    /// ```
+    #[cfg_attr(feature = "instrument", tracing::instrument(skip(source)))]
+    #[inline]
+    #[must_use]
+    pub fn new_with_source<V, S>(source: &S) -> Self
+    where
+        V: FromIdSource,
+        S: IdSource + ?Sized,
+    {
+        Self::from(*V::from_source(source))
     }
 
     /// Creates a new `TypeIdSuffix` from a V3 UUID (MD5-based name hash).
@@ -127,6 +184,42 @@ impl TypeIdSuffix {
         Self::from(uuid)
     }
 
+    /// Creates a new `TypeIdSuffix` from a V8 UUID holding the fields of a custom layout.
+    ///
+    /// # Examples
+    ///
+    /// See [`CustomLayout`].
+    #[must_use]
+    pub fn from_layout<L: CustomLayout>(layout: &L) -> Self {
+        Self::from(*V8::from_layout(layout))
+    }
+
+    /// Decodes a V8 suffix into the fields of a custom layout.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DecodeError::InvalidUuid` if the suffix does not hold a V8 UUID of the
+    /// RFC 9562 variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// struct Raw(u128);
+    ///
+    /// impl CustomLayout for Raw {
+    ///     fn pack(&self) -> u128 { self.0 }
+    ///     fn unpack(bits: u128) -> Self { Self(bits) }
+    /// }
+    ///
+    /// assert_eq!(TypeIdSuffix::from_layout(&Raw(7)).to_layout::<Raw>().unwrap().0, 7);
+    /// assert!(TypeIdSuffix::new::<V7>().to_layout::<Raw>().is_err());
+    /// ```
+    pub fn to_layout<L: CustomLayout>(&self) -> Result<L, DecodeError> {
+        V8::try_from(self.uuid).map(|v8| v8.to_layout())
+    }
+
     /// Checks if a given UUID is valid according to the `TypeId` specification.
     ///
     /// This method validates both the variant and version of the UUID.
@@ -165,37 +258,155 @@ impl TypeIdSuffix {
 
     /// Converts the `TypeIdSuffix` to a UUID.
     ///
-    /// This method decodes the base32-encoded suffix back into a UUID.
+    /// The suffix is stored in decoded form, so this is a plain copy.
     ///
     /// # Returns
     ///
     /// The `Uuid` represented by this `TypeIdSuffix`.
     ///
-    /// # Panics
+    /// # Examples
     ///
-    /// This method uses `expect()` internally, but it should never panic under normal circumstances.
-    /// A panic would indicate a serious internal inconsistency in the `TypeIdSuffix` struct,
-    /// which should be reported as a bug in the library.
+    /// ```
+    /// use typeid_suffix::prelude::*;
     ///
-    /// The reason it shouldn't panic is that:
-    /// 1. The `TypeIdSuffix` is always created from a valid UUID or a valid base32 string.
-    /// 2. All creation methods (`new()`, `from_str()`, `From<Uuid>`) perform thorough validation.
-    /// 3. The internal representation is immutable after creation.
+    /// let suffix = TypeIdSuffix::new::<V4>();
+    /// let uuid = suffix.to_uuid();
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn to_uuid(&self) -> Uuid {
+        self.uuid
+    }
+
+    /// Returns a reference to the UUID represented by this `TypeIdSuffix`.
     ///
     /// # Examples
     ///
     /// ```
     /// use typeid_suffix::prelude::*;
     ///
-    /// let suffix = TypeIdSuffix::new::<V4>();
-    /// let uuid = suffix.to_uuid();
+    /// let suffix = TypeIdSuffix::new::<V7>();
+    /// assert_eq!(suffix.as_uuid(), &suffix.to_uuid());
     /// ```
     #[inline]
     #[must_use]
-    pub fn to_uuid(&self) -> Uuid {
-        let decoded_bytes = decode_base32(&self.0)
-            .expect("This should never fail because we've already validated the input");
-        Uuid::from_bytes(decoded_bytes)
+    pub const fn as_uuid(&self) -> &Uuid {
+        &self.uuid
+    }
+
+    /// Returns the version of the UUID held by this suffix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// assert_eq!(TypeIdSuffix::new::<V4>().version(), SuffixVersion::V4);
+    /// assert_eq!(TypeIdSuffix::new::<Nil>().version(), SuffixVersion::Nil);
+    /// assert_eq!(TypeIdSuffix::from(Uuid::max()).version(), SuffixVersion::Max);
+    /// ```
+    #[must_use]
+    pub const fn version(&self) -> SuffixVersion {
+        SuffixVersion::of(&self.uuid)
+    }
+
+    /// Returns the variant of the UUID held by this suffix.
+    ///
+    /// UUIDs generated by this crate use the RFC 9562 variant, which the `uuid` crate calls
+    /// `Variant::RFC4122`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// assert_eq!(TypeIdSuffix::new::<V7>().variant(), Variant::RFC4122);
+    /// ```
+    #[must_use]
+    pub const fn variant(&self) -> Variant {
+        self.uuid.get_variant()
+    }
+
+    /// Returns whether the UUID held by this suffix is of version `V`.
+    ///
+    /// This lets an API reject identifiers minted with the wrong generator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let suffix = TypeIdSuffix::new::<V7>();
+    /// assert!(suffix.is_version::<V7>());
+    /// assert!(!suffix.is_version::<V4>());
+    /// ```
+    #[must_use]
+    pub fn is_version<V: UuidVersion>(&self) -> bool {
+        self.version() == V::VERSION
+    }
+
+    /// Returns whether this suffix carries a creation timestamp, that is, whether it holds a
+    /// V1, V6 or V7 UUID.
+    ///
+    /// See [`SuffixVersion::is_time_based`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// assert!(TypeIdSuffix::new::<V1>().is_time_based());
+    /// assert!(!TypeIdSuffix::new::<V4>().is_time_based());
+    /// ```
+    #[must_use]
+    pub const fn is_time_based(&self) -> bool {
+        self.version().is_time_based()
+    }
+
+    /// Returns whether this suffix sorts by creation time, that is, whether it holds a V6 or V7
+    /// UUID.
+    ///
+    /// See [`SuffixVersion::is_sortable`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// assert!(TypeIdSuffix::new::<V7>().is_sortable());
+    /// assert!(!TypeIdSuffix::new::<V1>().is_sortable());
+    /// ```
+    #[must_use]
+    pub const fn is_sortable(&self) -> bool {
+        self.version().is_sortable()
+    }
+
+    /// Returns whether this suffix sorts when read as custom layout `L`.
+    ///
+    /// V6 and V7 suffixes are always sortable. V8 suffixes of the RFC 9562 variant are sortable
+    /// if `L` declares [`CustomLayout::SORTABLE`]. Every other suffix is not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// struct Ordered(u128);
+    ///
+    /// impl CustomLayout for Ordered {
+    ///     const SORTABLE: bool = true;
+    ///     fn pack(&self) -> u128 { self.0 }
+    ///     fn unpack(bits: u128) -> Self { Self(bits) }
+    /// }
+    ///
+    /// let suffix = TypeIdSuffix::from_layout(&Ordered(7));
+    /// assert!(!suffix.is_sortable());
+    /// assert!(suffix.is_sortable_as::<Ordered>());
+    /// assert!(!TypeIdSuffix::new::<V4>().is_sortable_as::<Ordered>());
+    /// ```
+    #[must_use]
+    pub fn is_sortable_as<L: CustomLayout>(&self) -> bool {
+        self.is_sortable() || (V8::try_from(self.uuid).is_ok() && V8::is_sortable::<L>())
     }
 
     /// Returns a string slice of the ``TypeIdSuffix``.
@@ -221,28 +432,250 @@ impl TypeIdSuffix {
     fn as_str(&self) -> &str {
         // SAFETY: This unwrap is safe because we know that the internal bytes
         // are always valid ASCII characters, which are valid UTF-8
-        std::str::from_utf8(&self.0).unwrap()
+        std::str::from_utf8(&self.encoded).unwrap()
     }
 }
 
 impl TypeIdSuffix {
-    /// Checks if the ``TypeIdSuffix`` contains a V6 or V7 UUID.
-    fn is_sortable(&self) -> bool {
-        matches!(
-            self.to_uuid().get_version(),
-            Some(Version::SortMac | Version::SortRand)
-        )
+    /// Returns the time at which a time-based suffix was created.
+    ///
+    /// The timestamp is read from the UUID according to its version: V7 UUIDs store
+    /// milliseconds since the Unix epoch, while V1 and V6 UUIDs store 100-nanosecond
+    /// intervals since the Gregorian epoch (1582-10-15), which are converted to Unix time.
+    ///
+    /// # Returns
+    ///
+    /// `None` if the UUID is not a V1, V6 or V7 UUID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use std::time::{Duration, UNIX_EPOCH};
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let suffix = TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").unwrap();
+    /// assert_eq!(suffix.timestamp(), Some(UNIX_EPOCH + Duration::from_millis(1_688_096_058_518)));
+    ///
+    /// assert_eq!(TypeIdSuffix::new::<V4>().timestamp(), None);
+    /// ```
+    #[must_use]
+    pub fn timestamp(&self) -> Option<SystemTime> {
+        let intervals = self.timestamp_100ns()?;
+        // u64 nanoseconds run out in 2554, before the largest V1, V6 or V7 timestamps
+        let ticks = intervals.unsigned_abs();
+        let elapsed = Duration::new(
+            ticks / 10_000_000,
+            u32::try_from(ticks % 10_000_000 * 100).unwrap_or(0),
+        );
+        if intervals >= 0 {
+            UNIX_EPOCH.checked_add(elapsed)
+        } else {
+            UNIX_EPOCH.checked_sub(elapsed)
+        }
+    }
+
+    /// Returns the creation time of a time-based suffix in milliseconds since the Unix epoch.
+    ///
+    /// V1 and V6 timestamps are truncated towards negative infinity, so a V1 or V6 UUID created
+    /// before 1970 yields a negative value.
+    ///
+    /// # Returns
+    ///
+    /// `None` if the UUID is not a V1, V6 or V7 UUID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let suffix = TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").unwrap();
+    /// assert_eq!(suffix.timestamp_millis(), Some(1_688_096_058_518));
+    /// ```
+    #[must_use]
+    pub fn timestamp_millis(&self) -> Option<i64> {
+        match self.raw_timestamp()? {
+            RawTimestamp::UnixMillis(millis) => i64::try_from(millis).ok(),
+            RawTimestamp::Gregorian(_) => Some(self.timestamp_100ns()?.div_euclid(10_000)),
+        }
+    }
+
+    /// Returns the creation time of a time-based suffix in 100-nanosecond intervals since the
+    /// Unix epoch.
+    ///
+    /// This is the native precision of V1 and V6 UUIDs; V7 timestamps only have millisecond
+    /// precision and are scaled up.
+    ///
+    /// # Returns
+    ///
+    /// `None` if the UUID is not a V1, V6 or V7 UUID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// // A V1 UUID from RFC 9562, created at 2022-02-22T19:22:22Z
+    /// let uuid = Uuid::parse_str("c232ab00-9414-11ec-b3c8-9f6bdeced846").unwrap();
+    /// let suffix = TypeIdSuffix::from(uuid);
+    /// assert_eq!(suffix.timestamp_100ns(), Some(16_455_577_420_000_000));
+    /// ```
+    #[must_use]
+    pub fn timestamp_100ns(&self) -> Option<i64> {
+        match self.raw_timestamp()? {
+            RawTimestamp::UnixMillis(millis) => i64::try_from(millis).ok()?.checked_mul(10_000),
+            RawTimestamp::Gregorian(ticks) => i64::try_from(ticks)
+                .ok()?
+                .checked_sub(i64::try_from(GREGORIAN_OFFSET).ok()?),
+        }
+    }
+
+    /// Returns the smallest `UUIDv7` suffix for the millisecond containing `time`.
+    ///
+    /// Together with [`TypeIdSuffix::max_for_time`], this builds the bounds of a range scan over
+    /// `UUIDv7` suffixes: every `UUIDv7` created during a millisecond sorts between the minimum and
+    /// the maximum for that millisecond, both as a `TypeIdSuffix` and as an encoded string.
+    ///
+    /// Times before the Unix epoch are clamped to it, and times beyond the range of the 48-bit
+    /// `UUIDv7` timestamp are clamped to its largest value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::{Duration, SystemTime};
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let start = SystemTime::now() - Duration::from_secs(60);
+    /// let suffix = TypeIdSuffix::new::<V7>();
+    /// let end = SystemTime::now();
+    ///
+    /// let range = TypeIdSuffix::min_for_time(start)..=TypeIdSuffix::max_for_time(end);
+    /// assert!(range.contains(&suffix));
+    /// assert!(range.start().as_ref() <= suffix.as_ref() && suffix.as_ref() <= range.end().as_ref());
+    /// ```
+    #[must_use]
+    pub fn min_for_time(time: SystemTime) -> Self {
+        Self::from(v7_bound(time, 0x00))
+    }
+
+    /// Returns the largest `UUIDv7` suffix for the millisecond containing `time`.
+    ///
+    /// See [`TypeIdSuffix::min_for_time`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::{Duration, UNIX_EPOCH};
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let time = UNIX_EPOCH + Duration::from_millis(1_688_096_058_518);
+    /// let min = TypeIdSuffix::min_for_time(time);
+    /// let max = TypeIdSuffix::max_for_time(time);
+    ///
+    /// assert_eq!(min.to_string(), "01h455vb4pe008000000000000");
+    /// assert_eq!(max.to_string(), "01h455vb4pfzzvzzzzzzzzzzzz");
+    /// assert_eq!(min.timestamp(), Some(time));
+    /// assert_eq!(max.timestamp(), Some(time));
+    /// ```
+    #[must_use]
+    pub fn max_for_time(time: SystemTime) -> Self {
+        Self::from(v7_bound(time, 0xFF))
+    }
+
+    /// Reads the timestamp field of a V1, V6 or V7 UUID.
+    fn raw_timestamp(&self) -> Option<RawTimestamp> {
+        let bits = self.uuid.as_u128();
+        // Every field is at most 48 bits wide, so the masked value always fits in a u64
+        #[allow(clippy::cast_possible_truncation)]
+        let field = |shift: u32, width: u32| ((bits >> shift) & ((1 << width) - 1)) as u64;
+        match self.uuid.get_version()? {
+            Version::Mac => Some(RawTimestamp::Gregorian(
+                (field(64, 12) << 48) | (field(80, 16) << 32) | field(96, 32),
+            )),
+            Version::SortMac => Some(RawTimestamp::Gregorian(
+                (field(96, 32) << 28) | (field(80, 16) << 12) | field(64, 12),
+            )),
+            Version::SortRand => Some(RawTimestamp::UnixMillis(field(80, 48))),
+            _ => None,
+        }
+    }
+
+    /// Compares two suffixes by the time embedded in their UUIDs.
+    ///
+    /// Unlike [`Ord`], which orders every suffix by its 128-bit UUID value, this comparator
+    /// understands the layout of each time-based UUID version. It compares the timestamps of
+    /// V1, V6 and V7 UUIDs, so a V1 suffix and a V7 suffix are ordered by when they were
+    /// created even though their bit layouts differ. Suffixes with equal timestamps fall back
+    /// to [`Ord`], which keeps the result a total order over time-based suffixes.
+    ///
+    /// # Returns
+    ///
+    /// `None` if either suffix does not contain a time-based UUID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let earlier = TypeIdSuffix::new::<V1>();
+    /// std::thread::sleep(std::time::Duration::from_millis(2));
+    /// let later = TypeIdSuffix::new::<V7>();
+    ///
+    /// assert_eq!(earlier.chronological_cmp(&later), Some(Ordering::Less));
+    /// assert_eq!(earlier.chronological_cmp(&TypeIdSuffix::new::<V4>()), None);
+    /// ```
+    #[must_use]
+    pub fn chronological_cmp(&self, other: &Self) -> Option<Ordering> {
+        let this = self.timestamp_100ns()?;
+        let that = other.timestamp_100ns()?;
+        Some(this.cmp(&that).then_with(|| self.cmp(other)))
     }
 }
 
+/// Builds the `UUIDv7` for the millisecond containing `time`, with every `rand_a` and `rand_b`
+/// bit taken from `fill`.
+fn v7_bound(time: SystemTime, fill: u8) -> Uuid {
+    const MAX_MILLIS: u64 = (1 << 48) - 1;
+    let millis = time.duration_since(UNIX_EPOCH).map_or(0, |elapsed| {
+        u64::try_from(elapsed.as_millis()).map_or(MAX_MILLIS, |millis| millis.min(MAX_MILLIS))
+    });
+    uuid::Builder::from_unix_timestamp_millis(millis, &[fill; 10]).into_uuid()
+}
+
+/// The timestamp field of a time-based UUID, in the unit and epoch of its version.
+enum RawTimestamp {
+    /// Milliseconds since the Unix epoch (V7).
+    UnixMillis(u64),
+    /// 100-nanosecond intervals since the Gregorian epoch (V1 and V6).
+    Gregorian(u64),
+}
+
 impl Ord for TypeIdSuffix {
+    /// Orders suffixes by their UUID, read as a big-endian 128-bit integer.
+    ///
+    /// This is a total order consistent with [`Eq`] for every mix of UUID versions, and it
+    /// matches the lexicographic order of the encoded suffixes. For V6 and V7 UUIDs it is
+    /// also chronological; use [`TypeIdSuffix::chronological_cmp`] to order other
+    /// time-based versions by their timestamp.
     fn cmp(&self, other: &Self) -> Ordering {
-        if self.is_sortable() && other.is_sortable() {
-            self.to_uuid().cmp(&other.to_uuid())
-        } else {
-            // Fall back to lexicographic ordering for non-V6/V7 UUIDs
-            self.0.cmp(&other.0)
-        }
+        self.uuid.as_u128().cmp(&other.uuid.as_u128())
+    }
+}
+
+impl PartialEq for TypeIdSuffix {
+    fn eq(&self, other: &Self) -> bool {
+        // The encoded form is derived from the UUID, so comparing UUIDs is sufficient
+        self.uuid == other.uuid
+    }
+}
+
+impl Eq for TypeIdSuffix {}
+
+impl Hash for TypeIdSuffix {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.uuid.hash(state);
     }
 }
 
@@ -286,6 +719,12 @@ impl Borrow<str> for TypeIdSuffix {
     }
 }
 
+impl fmt::Debug for TypeIdSuffix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("TypeIdSuffix").field(&self.as_str()).finish()
+    }
+}
+
 impl fmt::Display for TypeIdSuffix {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str(self)
@@ -367,9 +806,12 @@ impl FromStr for TypeIdSuffix {
                 InvalidSuffixReason::InvalidLength,
             ));
         }
-        if !input.is_ascii() {
+        if let Some((position, character)) = input.char_indices().find(|(_, c)| !c.is_ascii()) {
             return Err(DecodeError::InvalidSuffix(
-                InvalidSuffixReason::NonAsciiCharacter,
+                InvalidSuffixReason::NonAsciiCharacter {
+                    position,
+                    character,
+                },
             ));
         }
         if input.as_bytes()[0] > b'7' {
@@ -386,7 +828,10 @@ impl FromStr for TypeIdSuffix {
         if !Self::is_valid_uuid(&uuid) {
             return Err(DecodeError::InvalidUuid(InvalidUuidReason::InvalidVersion));
         }
-        Ok(Self(encoded_bytes))
+        Ok(Self {
+            uuid,
+            encoded: encoded_bytes,
+        })
     }
 }
 
@@ -415,13 +860,17 @@ impl From<Uuid> for TypeIdSuffix {
     fn from(value: Uuid) -> Self {
         // SAFETY: The Uuid crate guarantees that the bytes are always 16 bytes long
         let encoded_bytes = encode_base32(value.as_bytes());
-        Self(encoded_bytes)
+        Self {
+            uuid: value,
+            encoded: encoded_bytes,
+        }
     }
 }
 
 #[cfg(feature = "serde")]
 impl Serialize for TypeIdSuffix {
-    /// Serializes the `TypeIdSuffix` as its string representation.
+    /// Serializes the `TypeIdSuffix` as its string representation in human-readable formats,
+    /// and as the 16 bytes of its UUID in binary formats.
     ///
     /// # Examples
     ///
@@ -439,7 +888,11 @@ impl Serialize for TypeIdSuffix {
     where
         S: Serializer,
     {
-        serializer.serialize_str(self.as_str())
+        if serializer.is_human_readable() {
+            serializer.serialize_str(self.as_str())
+        } else {
+            serializer.serialize_bytes(self.uuid.as_bytes())
+        }
     }
 }
 
@@ -447,7 +900,8 @@ impl Serialize for TypeIdSuffix {
 impl<'de> Deserialize<'de> for TypeIdSuffix {
     /// Deserializes a `TypeIdSuffix` from its string representation.
     ///
-    /// This expects a string that is a valid `TypeID` suffix.
+    /// This expects a string that is a valid `TypeID` suffix. Binary formats also accept the
+    /// 16 bytes of a UUID, as written by [`Serialize`].
     ///
     /// # Errors
     ///
@@ -473,8 +927,136 @@ impl<'de> Deserialize<'de> for TypeIdSuffix {
     where
         D: Deserializer<'de>,
     {
-        let s = String::deserialize(deserializer)?;
-        Self::from_str(&s).map_err(serde::de::Error::custom)
+        crate::serde_support::deserialize_uuid_or_str(deserializer, "a TypeID suffix")
+    }
+}
+
+#[cfg(feature = "sqlx")]
+impl<DB: sqlx::Database> sqlx::Type<DB> for TypeIdSuffix
+where
+    Uuid: sqlx::Type<DB>,
+{
+    /// A `TypeIdSuffix` is stored as its UUID, for example in a `UUID` column in `PostgreSQL` or a
+    /// 16-byte `BLOB` in `SQLite`.
+    fn type_info() -> DB::TypeInfo {
+        <Uuid as sqlx::Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <Uuid as sqlx::Type<DB>>::compatible(ty)
+    }
+}
+
+#[cfg(feature = "sqlx")]
+impl<'q, DB: sqlx::Database> sqlx::Encode<'q, DB> for TypeIdSuffix
+where
+    Uuid: sqlx::Encode<'q, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as sqlx::Database>::ArgumentBuffer<'q>,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        <Uuid as sqlx::Encode<'q, DB>>::encode_by_ref(&self.uuid, buf)
+    }
+}
+
+#[cfg(feature = "sqlx")]
+impl<'r, DB: sqlx::Database> sqlx::Decode<'r, DB> for TypeIdSuffix
+where
+    Uuid: sqlx::Decode<'r, DB>,
+{
+    fn decode(
+        value: <DB as sqlx::Database>::ValueRef<'r>,
+    ) -> Result<Self, sqlx::error::BoxDynError> {
+        <Uuid as sqlx::Decode<'r, DB>>::decode(value).map(Self::from)
+    }
+}
+
+/// A `TypeIdSuffix` is stored as its UUID, in a `UUID` column in `PostgreSQL`.
+#[cfg(feature = "diesel")]
+impl<DB: diesel::backend::Backend> diesel::serialize::ToSql<diesel::sql_types::Uuid, DB>
+    for TypeIdSuffix
+where
+    Uuid: diesel::serialize::ToSql<diesel::sql_types::Uuid, DB>,
+{
+    fn to_sql<'b>(
+        &'b self,
+        out: &mut diesel::serialize::Output<'b, '_, DB>,
+    ) -> diesel::serialize::Result {
+        self.uuid.to_sql(out)
+    }
+}
+
+#[cfg(feature = "diesel")]
+impl<DB: diesel::backend::Backend> diesel::deserialize::FromSql<diesel::sql_types::Uuid, DB>
+    for TypeIdSuffix
+where
+    Uuid: diesel::deserialize::FromSql<diesel::sql_types::Uuid, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+        Uuid::from_sql(bytes).map(Self::from)
+    }
+}
+
+/// A `TypeIdSuffix` is stored as the 16 bytes of its UUID, in a `BLOB` or `BINARY(16)` column in
+/// backends without a UUID type.
+#[cfg(feature = "diesel")]
+impl<DB: diesel::backend::Backend> diesel::serialize::ToSql<diesel::sql_types::Binary, DB>
+    for TypeIdSuffix
+where
+    [u8]: diesel::serialize::ToSql<diesel::sql_types::Binary, DB>,
+{
+    fn to_sql<'b>(
+        &'b self,
+        out: &mut diesel::serialize::Output<'b, '_, DB>,
+    ) -> diesel::serialize::Result {
+        self.uuid.as_bytes().as_slice().to_sql(out)
+    }
+}
+
+#[cfg(feature = "diesel")]
+impl<DB: diesel::backend::Backend> diesel::deserialize::FromSql<diesel::sql_types::Binary, DB>
+    for TypeIdSuffix
+where
+    Vec<u8>: diesel::deserialize::FromSql<diesel::sql_types::Binary, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+        let bytes = Vec::<u8>::from_sql(bytes)?;
+        Ok(Self::from(Uuid::from_slice(&bytes)?))
+    }
+}
+
+/// A `TypeIdSuffix` is stored as a 16-byte blob holding its UUID.
+///
+/// To store it as text instead, bind its string form, e.g. `suffix.to_string()`; reading accepts
+/// either.
+#[cfg(feature = "rusqlite")]
+impl rusqlite::types::ToSql for TypeIdSuffix {
+    fn to_sql(&self) -> rusqlite::Result<rusqlite::types::ToSqlOutput<'_>> {
+        Ok(self.uuid.as_bytes().as_slice().into())
+    }
+}
+
+#[cfg(feature = "rusqlite")]
+impl rusqlite::types::FromSql for TypeIdSuffix {
+    /// Reads a `TypeIdSuffix` from a 16-byte blob, or from text holding its base32 form.
+    fn column_result(value: rusqlite::types::ValueRef<'_>) -> rusqlite::types::FromSqlResult<Self> {
+        use rusqlite::types::{FromSqlError, ValueRef};
+
+        match value {
+            ValueRef::Blob(bytes) => {
+                Uuid::from_slice(bytes)
+                    .map(Self::from)
+                    .map_err(|_| FromSqlError::InvalidBlobSize {
+                        expected_size: 16,
+                        blob_size: bytes.len(),
+                    })
+            }
+            ValueRef::Text(_) => {
+                Self::from_str(value.as_str()?).map_err(|e| FromSqlError::Other(Box::new(e)))
+            }
+            _ => Err(FromSqlError::InvalidType),
+        }
     }
 }
 
@@ -5,23 +5,144 @@
 //! `UuidVersion` trait, allowing them to be used generically within the
 //! `TypeID` system.
 
+use std::fmt;
 use std::ops::Deref;
 
-use uuid::Uuid;
+use uuid::{Uuid, Variant, Version};
+
+use crate::errors::{DecodeError, InvalidUuidReason};
 
 /// Trait for UUID versions used in `TypeID`.
 ///
 /// This trait is implemented by all UUID version structs in this module,
 /// allowing them to be used interchangeably where a UUID version is required.
-pub trait UuidVersion: Deref<Target = Uuid> {}
+pub trait UuidVersion: Deref<Target = Uuid> {
+    /// The version of the UUIDs this type represents.
+    const VERSION: SuffixVersion;
+}
+
+/// The version of the UUID held by a `TypeID` suffix.
+///
+/// Each version that has a marker type in this module (`V1`, `V3`, `V4`, `V5`, `V6`, `V7`, `V8`
+/// and `Nil`) maps onto that type through [`UuidVersion::VERSION`]. Versions that cannot be
+/// generated by this crate are still reported, so every suffix has a version. Later releases may
+/// add variants, so matches on this enum need a wildcard arm.
+///
+/// # Examples
+///
+/// ```
+/// use typeid_suffix::prelude::*;
+///
+/// let suffix = TypeIdSuffix::new::<V7>();
+/// assert_eq!(suffix.version(), SuffixVersion::V7);
+/// assert_eq!(suffix.version(), V7::VERSION);
+/// assert!(suffix.version().is_time_based());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum SuffixVersion {
+    /// The Nil UUID, with all bits set to zero. See [`Nil`].
+    Nil,
+    /// Version 1, time-based with a node ID. See [`V1`].
+    V1,
+    /// Version 2, reserved for DCE security.
+    V2,
+    /// Version 3, name-based with MD5. See [`V3`].
+    V3,
+    /// Version 4, random. See [`V4`].
+    V4,
+    /// Version 5, name-based with SHA-1. See [`V5`].
+    V5,
+    /// Version 6, reordered time-based. See [`V6`].
+    V6,
+    /// Version 7, Unix-time-ordered. See [`V7`].
+    V7,
+    /// Version 8, custom layout. See [`V8`].
+    V8,
+    /// The Max UUID, with all bits set to one.
+    Max,
+    /// A version number that is not defined by RFC 9562.
+    Unknown(u8),
+}
+
+impl SuffixVersion {
+    /// Returns the version of `uuid`.
+    #[must_use]
+    pub const fn of(uuid: &Uuid) -> Self {
+        match uuid.get_version() {
+            Some(Version::Nil) => Self::Nil,
+            Some(Version::Mac) => Self::V1,
+            Some(Version::Dce) => Self::V2,
+            Some(Version::Md5) => Self::V3,
+            Some(Version::Random) => Self::V4,
+            Some(Version::Sha1) => Self::V5,
+            Some(Version::SortMac) => Self::V6,
+            Some(Version::SortRand) => Self::V7,
+            Some(Version::Custom) => Self::V8,
+            Some(Version::Max) => Self::Max,
+            _ => Self::Unknown(uuid.as_bytes()[6] >> 4),
+        }
+    }
+
+    /// Returns the version number stored in the UUID, from 0 to 15.
+    ///
+    /// The Nil UUID has version number 0 and the Max UUID has version number 15.
+    #[must_use]
+    pub const fn number(self) -> u8 {
+        match self {
+            Self::Nil => 0,
+            Self::V1 => 1,
+            Self::V2 => 2,
+            Self::V3 => 3,
+            Self::V4 => 4,
+            Self::V5 => 5,
+            Self::V6 => 6,
+            Self::V7 => 7,
+            Self::V8 => 8,
+            Self::Max => 15,
+            Self::Unknown(number) => number,
+        }
+    }
+
+    /// Returns whether UUIDs of this version carry a creation timestamp that this crate can read.
+    ///
+    /// This is true for V1, V6 and V7, the versions for which `TypeIdSuffix::timestamp` returns
+    /// a value.
+    #[must_use]
+    pub const fn is_time_based(self) -> bool {
+        matches!(self, Self::V1 | Self::V6 | Self::V7)
+    }
+
+    /// Returns whether UUIDs of this version sort by creation time.
+    ///
+    /// This is true for V6 and V7, whose timestamps occupy the most significant bits, so that the
+    /// ordering of their suffixes is chronological. V1 UUIDs are time-based but not sortable.
+    #[must_use]
+    pub const fn is_sortable(self) -> bool {
+        matches!(self, Self::V6 | Self::V7)
+    }
+}
+
+impl fmt::Display for SuffixVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Nil => f.write_str("nil"),
+            Self::Max => f.write_str("max"),
+            Self::Unknown(number) => write!(f, "unknown version {number}"),
+            version => write!(f, "v{}", version.number()),
+        }
+    }
+}
 
 /// Represents a Version 1 UUID (time-based).
 ///
 /// Version 1 UUIDs are generated using a timestamp and node ID.
 #[derive(Debug)]
-pub struct V1(Uuid);
+pub struct V1(pub(crate) Uuid);
 
-impl UuidVersion for V1 {}
+impl UuidVersion for V1 {
+    const VERSION: SuffixVersion = SuffixVersion::V1;
+}
 
 impl Default for V1 {
     /// Creates a new Version 1 UUID using the current timestamp.
@@ -42,9 +163,11 @@ impl Deref for V1 {
 ///
 /// Version 3 UUIDs are generated by hashing a namespace and name using MD5.
 #[derive(Debug)]
-pub struct V3(Uuid);
+pub struct V3(pub(crate) Uuid);
 
-impl UuidVersion for V3 {}
+impl UuidVersion for V3 {
+    const VERSION: SuffixVersion = SuffixVersion::V3;
+}
 
 impl Default for V3 {
     /// Creates a new Version 3 UUID using the DNS namespace and default name.
@@ -65,9 +188,11 @@ impl Deref for V3 {
 ///
 /// Version 4 UUIDs are generated using random or pseudo-random numbers.
 #[derive(Debug)]
-pub struct V4(Uuid);
+pub struct V4(pub(crate) Uuid);
 
-impl UuidVersion for V4 {}
+impl UuidVersion for V4 {
+    const VERSION: SuffixVersion = SuffixVersion::V4;
+}
 
 impl Default for V4 {
     /// Creates a new random Version 4 UUID.
@@ -88,9 +213,11 @@ impl Deref for V4 {
 ///
 /// Version 5 UUIDs are generated by hashing a namespace and name using SHA-1.
 #[derive(Debug)]
-pub struct V5(Uuid);
+pub struct V5(pub(crate) Uuid);
 
-impl UuidVersion for V5 {}
+impl UuidVersion for V5 {
+    const VERSION: SuffixVersion = SuffixVersion::V5;
+}
 
 impl Deref for V5 {
     type Target = Uuid;
@@ -111,9 +238,11 @@ impl Default for V5 {
 ///
 /// Version 6 UUIDs are similar to Version 1, but with improved privacy and monotonicity.
 #[derive(Debug)]
-pub struct V6(Uuid);
+pub struct V6(pub(crate) Uuid);
 
-impl UuidVersion for V6 {}
+impl UuidVersion for V6 {
+    const VERSION: SuffixVersion = SuffixVersion::V6;
+}
 
 impl Deref for V6 {
     type Target = Uuid;
@@ -134,7 +263,7 @@ impl Default for V6 {
 ///
 /// Version 7 UUIDs are time-ordered and use a Unix timestamp with millisecond precision.
 #[derive(Debug)]
-pub struct V7(Uuid);
+pub struct V7(pub(crate) Uuid);
 
 impl Deref for V7 {
     type Target = Uuid;
@@ -144,7 +273,9 @@ impl Deref for V7 {
     }
 }
 
-impl UuidVersion for V7 {}
+impl UuidVersion for V7 {
+    const VERSION: SuffixVersion = SuffixVersion::V7;
+}
 
 impl Default for V7 {
     /// Creates a new Version 7 UUID using the current timestamp.
@@ -153,11 +284,182 @@ impl Default for V7 {
     }
 }
 
+/// Represents a Version 8 UUID (custom layout).
+///
+/// Version 8 UUIDs only fix the 4 version bits and the 2 variant bits, leaving 122 bits for
+/// application-specific data. A `V8` is built from those 122 bits, either directly with
+/// [`V8::from_bits`] or from the fields of a [`CustomLayout`].
+///
+/// The free bits are laid out in order around the version and variant bits, so comparing two
+/// `V8` UUIDs (or their `TypeID` suffixes) compares their 122-bit values.
+///
+/// # Examples
+///
+/// ```
+/// use typeid_suffix::prelude::*;
+///
+/// let v8 = V8::from_bits(0x2A);
+/// assert_eq!(v8.bits(), 0x2A);
+/// assert_eq!(v8.get_version(), Some(Version::Custom));
+/// ```
+#[derive(Debug)]
+pub struct V8(pub(crate) Uuid);
+
+impl V8 {
+    /// The number of bits available to a custom layout.
+    pub const BITS: u32 = 122;
+
+    /// The largest value that fits in the free bits of a `V8` UUID.
+    pub const MAX_BITS: u128 = (1 << Self::BITS) - 1;
+
+    /// Creates a `V8` UUID from the low 122 bits of `bits`.
+    ///
+    /// Higher bits are discarded.
+    #[must_use]
+    pub const fn from_bits(bits: u128) -> Self {
+        let bits = bits & Self::MAX_BITS;
+        let custom_a = bits >> 74;
+        let custom_b = (bits >> 62) & 0xFFF;
+        let custom_c = bits & ((1 << 62) - 1);
+        Self(Uuid::from_u128(
+            (custom_a << 80) | (0x8 << 76) | (custom_b << 64) | (0b10 << 62) | custom_c,
+        ))
+    }
+
+    /// Returns the 122 free bits of this UUID.
+    #[must_use]
+    pub const fn bits(&self) -> u128 {
+        let uuid = self.0.as_u128();
+        let custom_a = uuid >> 80;
+        let custom_b = (uuid >> 64) & 0xFFF;
+        let custom_c = uuid & ((1 << 62) - 1);
+        (custom_a << 74) | (custom_b << 62) | custom_c
+    }
+
+    /// Creates a `V8` UUID from the fields of a custom layout.
+    ///
+    /// # Examples
+    ///
+    /// See [`CustomLayout`].
+    #[must_use]
+    pub fn from_layout<L: CustomLayout>(layout: &L) -> Self {
+        Self::from_bits(layout.pack())
+    }
+
+    /// Decodes the free bits of this UUID into the fields of a custom layout.
+    #[must_use]
+    pub fn to_layout<L: CustomLayout>(&self) -> L {
+        L::unpack(self.bits())
+    }
+
+    /// Returns whether `V8` UUIDs holding layout `L` sort in the order of that layout.
+    ///
+    /// See [`CustomLayout::SORTABLE`].
+    #[must_use]
+    pub const fn is_sortable<L: CustomLayout>() -> bool {
+        L::SORTABLE
+    }
+}
+
+impl UuidVersion for V8 {
+    const VERSION: SuffixVersion = SuffixVersion::V8;
+}
+
+impl Deref for V8 {
+    type Target = Uuid;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl TryFrom<Uuid> for V8 {
+    type Error = DecodeError;
+
+    /// Checks that `uuid` is a Version 8 UUID of the RFC 9562 variant.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DecodeError::InvalidUuid` if the version is not 8 or the variant is not RFC 9562.
+    fn try_from(uuid: Uuid) -> Result<Self, Self::Error> {
+        if uuid.get_version() != Some(Version::Custom) {
+            return Err(DecodeError::InvalidUuid(InvalidUuidReason::InvalidVersion));
+        }
+        if uuid.get_variant() != Variant::RFC4122 {
+            return Err(DecodeError::InvalidUuid(InvalidUuidReason::InvalidVariant));
+        }
+        Ok(Self(uuid))
+    }
+}
+
+/// An application-defined layout of the 122 free bits of a [`V8`] UUID.
+///
+/// Implement this trait to pack your own fields, such as a shard, a tenant, a type tag or a
+/// timestamp, into a `UUIDv8` suffix and to decode them again.
+///
+/// Suffixes compare by their UUID, and the free bits of a `V8` are stored in order, so `V8`
+/// suffixes sort by the packed value. A layout that places its most significant field in the
+/// highest bits, such as a timestamp, can declare this by setting [`CustomLayout::SORTABLE`].
+///
+/// # Examples
+///
+/// ```
+/// use typeid_suffix::prelude::*;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct ShardedEvent {
+///     millis: u64,  // 48 bits
+///     shard: u16,   // 10 bits
+///     sequence: u64, // 64 bits
+/// }
+///
+/// impl CustomLayout for ShardedEvent {
+///     const SORTABLE: bool = true;
+///
+///     fn pack(&self) -> u128 {
+///         (u128::from(self.millis & 0xFFFF_FFFF_FFFF) << 74)
+///             | (u128::from(self.shard & 0x3FF) << 64)
+///             | u128::from(self.sequence)
+///     }
+///
+///     fn unpack(bits: u128) -> Self {
+///         Self {
+///             millis: (bits >> 74) as u64,
+///             shard: ((bits >> 64) & 0x3FF) as u16,
+///             sequence: bits as u64,
+///         }
+///     }
+/// }
+///
+/// let event = ShardedEvent { millis: 1_700_000_000_000, shard: 3, sequence: 42 };
+/// let suffix = TypeIdSuffix::from_layout(&event);
+/// assert_eq!(suffix.to_uuid().get_version(), Some(Version::Custom));
+/// assert_eq!(suffix.to_layout::<ShardedEvent>(), Ok(event));
+///
+/// let later = TypeIdSuffix::from_layout(&ShardedEvent { millis: 1_700_000_000_001, shard: 0, sequence: 0 });
+/// assert!(suffix < later);
+/// ```
+pub trait CustomLayout: Sized {
+    /// Whether packing preserves the order of the layout.
+    ///
+    /// Set this to `true` if `a < b` implies `a.pack() < b.pack()` for your layout, so that
+    /// suffixes built from it sort in the same order. Defaults to `false`.
+    const SORTABLE: bool = false;
+
+    /// Packs the fields of the layout into the low 122 bits of a `u128`.
+    ///
+    /// Bits above the 122nd are discarded.
+    fn pack(&self) -> u128;
+
+    /// Unpacks the fields of the layout from the low 122 bits of a `u128`.
+    fn unpack(bits: u128) -> Self;
+}
+
 /// Represents a Nil UUID (all zeros).
 ///
 /// A Nil UUID is a special case where all 128 bits are set to zero.
 #[derive(Debug)]
-pub struct Nil(Uuid);
+pub struct Nil(pub(crate) Uuid);
 
 impl Deref for Nil {
     type Target = Uuid;
@@ -167,7 +469,9 @@ impl Deref for Nil {
     }
 }
 
-impl UuidVersion for Nil {}
+impl UuidVersion for Nil {
+    const VERSION: SuffixVersion = SuffixVersion::Nil;
+}
 
 impl Default for Nil {
     /// Creates a new Nil UUID (all zeros).
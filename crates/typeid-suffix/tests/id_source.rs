@@ -0,0 +1,125 @@
+//! Integration tests for generating suffixes from an `IdSource`.
+//!
+//! These tests check that `SeededIdSource` makes generation reproducible for every UUID version,
+//! and that the generated UUIDs carry the clock of the source.
+
+use std::time::{Duration, UNIX_EPOCH};
+
+use typeid_suffix::prelude::*;
+
+const START_SECS: u64 = 1_700_000_000;
+
+fn seeded(seed: u64) -> SeededIdSource {
+    SeededIdSource::new(seed)
+        .with_start_time(UNIX_EPOCH + Duration::from_secs(START_SECS))
+        .with_tick(Duration::from_millis(1))
+}
+
+fn sequence<V: FromIdSource>(source: &SeededIdSource) -> Vec<TypeIdSuffix> {
+    (0..16)
+        .map(|_| TypeIdSuffix::new_with_source::<V, _>(source))
+        .collect()
+}
+
+#[test]
+fn test_same_seed_same_sequence() {
+    assert_eq!(sequence::<V1>(&seeded(1)), sequence::<V1>(&seeded(1)));
+    assert_eq!(sequence::<V4>(&seeded(1)), sequence::<V4>(&seeded(1)));
+    assert_eq!(sequence::<V6>(&seeded(1)), sequence::<V6>(&seeded(1)));
+    assert_eq!(sequence::<V7>(&seeded(1)), sequence::<V7>(&seeded(1)));
+}
+
+#[test]
+fn test_different_seeds_different_sequences() {
+    assert_ne!(sequence::<V4>(&seeded(1)), sequence::<V4>(&seeded(2)));
+    assert_ne!(sequence::<V7>(&seeded(1)), sequence::<V7>(&seeded(2)));
+}
+
+#[test]
+fn test_generated_versions() {
+    let source = seeded(3);
+    let version = |suffix: TypeIdSuffix| suffix.to_uuid().get_version();
+
+    assert_eq!(
+        version(TypeIdSuffix::new_with_source::<V1, _>(&source)),
+        Some(Version::Mac)
+    );
+    assert_eq!(
+        version(TypeIdSuffix::new_with_source::<V4, _>(&source)),
+        Some(Version::Random)
+    );
+    assert_eq!(
+        version(TypeIdSuffix::new_with_source::<V6, _>(&source)),
+        Some(Version::SortMac)
+    );
+    assert_eq!(
+        version(TypeIdSuffix::new_with_source::<V7, _>(&source)),
+        Some(Version::SortRand)
+    );
+    assert!(TypeIdSuffix::new_with_source::<Nil, _>(&source)
+        .to_uuid()
+        .is_nil());
+}
+
+#[test]
+fn test_time_based_versions_use_the_source_clock() {
+    let unix = |suffix: TypeIdSuffix| suffix.to_uuid().get_timestamp().map(|ts| ts.to_unix());
+
+    let source =
+        SeededIdSource::new(0).with_start_time(UNIX_EPOCH + Duration::from_secs(START_SECS));
+    assert_eq!(
+        unix(TypeIdSuffix::new_with_source::<V1, _>(&source)),
+        Some((START_SECS, 0))
+    );
+    assert_eq!(
+        unix(TypeIdSuffix::new_with_source::<V6, _>(&source)),
+        Some((START_SECS, 0))
+    );
+    assert_eq!(
+        unix(TypeIdSuffix::new_with_source::<V7, _>(&source)),
+        Some((START_SECS, 0))
+    );
+}
+
+#[test]
+fn test_ticking_clock_orders_v7() {
+    let suffixes = sequence::<V7>(&seeded(4));
+    assert!(suffixes.windows(2).all(|pair| pair[0] < pair[1]));
+}
+
+#[test]
+fn test_fixed_clock_without_tick() {
+    let source = SeededIdSource::new(5);
+    assert_eq!(source.now(), Duration::ZERO);
+    assert_eq!(source.now(), Duration::ZERO);
+}
+
+#[test]
+fn test_system_source() {
+    let suffix = TypeIdSuffix::new_with_source::<V7, _>(&SystemIdSource);
+    assert_eq!(suffix.to_uuid().get_version(), Some(Version::SortRand));
+    assert_ne!(
+        TypeIdSuffix::new_with_source::<V4, _>(&SystemIdSource),
+        TypeIdSuffix::new_with_source::<V4, _>(&SystemIdSource)
+    );
+}
+
+#[test]
+fn test_dyn_source() {
+    let source: &dyn IdSource = &seeded(6);
+    let suffix = TypeIdSuffix::new_with_source::<V7, _>(source);
+    assert_eq!(TypeIdSuffix::new_with_source::<V7, _>(&seeded(6)), suffix);
+}
+
+#[test]
+fn test_seeded_monotonic_generator() {
+    let generator = MonotonicGenerator::with_source(SeededIdSource::new(8));
+    let replay = MonotonicGenerator::with_source(SeededIdSource::new(8));
+
+    let suffixes: Vec<TypeIdSuffix> = (0..1_000).map(|_| generator.next_suffix()).collect();
+    let replayed: Vec<TypeIdSuffix> = (0..1_000).map(|_| replay.next_suffix()).collect();
+
+    assert_eq!(suffixes, replayed);
+    // The clock never moves, so the generator orders the suffixes by counter alone
+    assert!(suffixes.windows(2).all(|pair| pair[0] < pair[1]));
+}
@@ -0,0 +1,85 @@
+//! Integration tests for `MonotonicGenerator`.
+//!
+//! These tests check that the suffixes from one generator are strictly increasing, both in a
+//! tight loop on one thread and when many threads share the generator.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::thread;
+
+use typeid_suffix::prelude::*;
+
+#[test]
+fn test_strictly_increasing_in_a_tight_loop() {
+    let generator = MonotonicGenerator::new();
+    let uuids: Vec<Uuid> = (0..100_000).map(|_| generator.next_uuid()).collect();
+
+    assert!(uuids.windows(2).all(|pair| pair[0] < pair[1]));
+    assert!(uuids
+        .iter()
+        .all(|uuid| uuid.get_version() == Some(Version::SortRand)));
+}
+
+#[test]
+fn test_suffixes_sort_like_their_strings() {
+    let generator = MonotonicGenerator::new();
+    let suffixes: Vec<TypeIdSuffix> = (0..1_000).map(|_| generator.next_suffix()).collect();
+
+    assert!(suffixes.windows(2).all(|pair| pair[0] < pair[1]));
+    assert!(suffixes
+        .windows(2)
+        .all(|pair| pair[0].to_string() < pair[1].to_string()));
+}
+
+#[test]
+fn test_timestamp_tracks_the_clock() {
+    let generator = MonotonicGenerator::new();
+    let before = Uuid::now_v7();
+    let uuid = generator.next_uuid();
+
+    let millis = |uuid: Uuid| uuid.as_u128() >> 80;
+    assert!(millis(uuid) >= millis(before));
+}
+
+#[test]
+fn test_strictly_increasing_under_contention() {
+    const THREADS: usize = 8;
+    const PER_THREAD: usize = 10_000;
+
+    let generator = Arc::new(MonotonicGenerator::new());
+    let handles: Vec<_> = (0..THREADS)
+        .map(|_| {
+            let generator = Arc::clone(&generator);
+            thread::spawn(move || {
+                (0..PER_THREAD)
+                    .map(|_| generator.next_uuid())
+                    .collect::<Vec<_>>()
+            })
+        })
+        .collect();
+
+    let mut all = Vec::with_capacity(THREADS * PER_THREAD);
+    for handle in handles {
+        let uuids = handle.join().expect("generator thread panicked");
+        // Each thread observes its own UUIDs in increasing order
+        assert!(uuids.windows(2).all(|pair| pair[0] < pair[1]));
+        all.extend(uuids);
+    }
+
+    // No UUID was handed out twice
+    let unique: HashSet<Uuid> = all.iter().copied().collect();
+    assert_eq!(unique.len(), THREADS * PER_THREAD);
+
+    // Each UUID is newer than the one before it across all threads
+    let next = generator.next_uuid();
+    assert!(all.iter().all(|uuid| *uuid < next));
+}
+
+#[test]
+fn test_static_generator() {
+    static IDS: MonotonicGenerator = MonotonicGenerator::new();
+
+    let first = IDS.next_suffix();
+    let second = IDS.next_suffix();
+    assert!(first < second);
+}
@@ -0,0 +1,119 @@
+//! Property tests for the ordering of `TypeIdSuffix`.
+//!
+//! These tests check that `Ord` is a lawful total order consistent with `Eq`
+//! over suffixes of mixed UUID versions, and that it matches the order of the
+//! encoded strings.
+
+use std::cmp::Ordering;
+
+use proptest::prelude::*;
+use typeid_suffix::prelude::*;
+use uuid::Uuid;
+
+prop_compose! {
+    /// Generates a suffix of an arbitrary version, including Nil- and Max-like values.
+    fn arbitrary_suffix()(
+        version in prop_oneof![Just(None), (0u8..=15).prop_map(Some)],
+        bytes in proptest::array::uniform16(any::<u8>()),
+    ) -> TypeIdSuffix {
+        let mut bytes = bytes;
+        if let Some(version) = version {
+            bytes[6] = (bytes[6] & 0x0F) | (version << 4);
+            bytes[8] = (bytes[8] & 0x3F) | 0x80;
+        }
+        TypeIdSuffix::from(Uuid::from_bytes(bytes))
+    }
+}
+
+prop_compose! {
+    /// Generates suffixes that often share a timestamp-sized prefix, to exercise ties.
+    fn clustered_suffix()(
+        version in prop_oneof![Just(1u8), Just(4u8), Just(6u8), Just(7u8)],
+        high in 0u8..4,
+        low in any::<u64>(),
+    ) -> TypeIdSuffix {
+        let mut bytes = [0u8; 16];
+        bytes[0] = high;
+        bytes[8..16].copy_from_slice(&low.to_be_bytes());
+        bytes[6] = (bytes[6] & 0x0F) | (version << 4);
+        bytes[8] = (bytes[8] & 0x3F) | 0x80;
+        TypeIdSuffix::from(Uuid::from_bytes(bytes))
+    }
+}
+
+fn any_suffix() -> impl Strategy<Value = TypeIdSuffix> {
+    prop_oneof![arbitrary_suffix(), clustered_suffix()]
+}
+
+proptest! {
+    #[test]
+    fn ordering_is_total_and_consistent_with_eq(a in any_suffix(), b in any_suffix()) {
+        let ab = a.cmp(&b);
+        prop_assert_eq!(ab == Ordering::Equal, a == b);
+        prop_assert_eq!(a.partial_cmp(&b), Some(ab));
+    }
+
+    #[test]
+    fn ordering_is_antisymmetric(a in any_suffix(), b in any_suffix()) {
+        prop_assert_eq!(a.cmp(&b), b.cmp(&a).reverse());
+    }
+
+    #[test]
+    fn ordering_is_transitive(a in any_suffix(), b in any_suffix(), c in any_suffix()) {
+        if a <= b && b <= c {
+            prop_assert!(a <= c);
+        }
+        if a >= b && b >= c {
+            prop_assert!(a >= c);
+        }
+        if a == b && b == c {
+            prop_assert_eq!(a, c);
+        }
+    }
+
+    #[test]
+    fn ordering_matches_encoded_strings(a in any_suffix(), b in any_suffix()) {
+        prop_assert_eq!(a.cmp(&b), a.as_ref().cmp(b.as_ref()));
+    }
+
+    #[test]
+    fn ordering_matches_uuid_order(a in any_suffix(), b in any_suffix()) {
+        prop_assert_eq!(a.cmp(&b), a.to_uuid().cmp(&b.to_uuid()));
+    }
+
+    #[test]
+    fn chronological_cmp_is_antisymmetric(a in clustered_suffix(), b in clustered_suffix()) {
+        prop_assert_eq!(
+            a.chronological_cmp(&b),
+            b.chronological_cmp(&a).map(Ordering::reverse)
+        );
+    }
+
+    #[test]
+    fn chronological_cmp_is_transitive(
+        a in clustered_suffix(),
+        b in clustered_suffix(),
+        c in clustered_suffix(),
+    ) {
+        if let (Some(ab), Some(bc), Some(ac)) = (
+            a.chronological_cmp(&b),
+            b.chronological_cmp(&c),
+            a.chronological_cmp(&c),
+        ) {
+            if ab != Ordering::Greater && bc != Ordering::Greater {
+                prop_assert_ne!(ac, Ordering::Greater);
+            }
+        }
+    }
+}
+
+#[test]
+fn chronological_cmp_orders_v1_and_v7_by_time() {
+    let earlier = TypeIdSuffix::new::<V7>();
+    std::thread::sleep(std::time::Duration::from_millis(2));
+    let later = TypeIdSuffix::new::<V1>();
+
+    assert_eq!(earlier.chronological_cmp(&later), Some(Ordering::Less));
+    assert_eq!(later.chronological_cmp(&earlier), Some(Ordering::Greater));
+    assert_eq!(earlier.chronological_cmp(&TypeIdSuffix::new::<V4>()), None);
+}
@@ -7,6 +7,7 @@
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::str::FromStr;
 use typeid_suffix::prelude::*;
 
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
@@ -69,3 +70,62 @@ fn test_serde_with_different_formats() {
     let from_pretty_json: TypeIdSuffix = serde_json::from_str(&pretty_json).unwrap();
     assert_eq!(suffix, from_pretty_json);
 }
+
+#[test]
+fn test_binary_format_uses_uuid_bytes() {
+    let suffix = TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").unwrap();
+
+    // A length prefix and the 16 bytes of the UUID, instead of the 26-character string
+    let bytes = bincode::serialize(&suffix).unwrap();
+    assert_eq!(bytes.len(), 8 + 16);
+    assert_eq!(&bytes[8..], suffix.to_uuid().as_bytes());
+    assert_eq!(
+        bincode::deserialize::<TypeIdSuffix>(&bytes).unwrap(),
+        suffix
+    );
+
+    let user = User {
+        id: suffix,
+        name: "Alice".to_string(),
+        email: "alice@example.com".to_string(),
+    };
+    let bytes = bincode::serialize(&user).unwrap();
+    assert_eq!(bincode::deserialize::<User>(&bytes).unwrap(), user);
+}
+
+#[test]
+fn test_binary_format_accepts_string_form() {
+    let bytes = bincode::serialize("01h455vb4pex5vsknk084sn02q").unwrap();
+    let suffix: TypeIdSuffix = bincode::deserialize(&bytes).unwrap();
+    assert_eq!(suffix.to_string(), "01h455vb4pex5vsknk084sn02q");
+
+    let invalid = bincode::serialize("8zzzzzzzzzzzzzzzzzzzzzzzzz").unwrap();
+    assert!(bincode::deserialize::<TypeIdSuffix>(&invalid).is_err());
+
+    let truncated = bincode::serialize(&[0xffu8; 15][..]).unwrap();
+    assert!(bincode::deserialize::<TypeIdSuffix>(&truncated).is_err());
+}
+
+#[test]
+fn test_namespace_id_serde() {
+    let json = serde_json::to_string(&NamespaceId::URL).unwrap();
+    assert_eq!(json, "\"6ba7b811-9dad-11d1-80b4-00c04fd430c8\"");
+    assert_eq!(
+        serde_json::from_str::<NamespaceId>(&json).unwrap(),
+        NamespaceId::URL
+    );
+    assert!(serde_json::from_str::<NamespaceId>("\"not-a-uuid\"").is_err());
+
+    let bytes = bincode::serialize(&NamespaceId::URL).unwrap();
+    assert_eq!(bytes.len(), 8 + 16);
+    assert_eq!(
+        bincode::deserialize::<NamespaceId>(&bytes).unwrap(),
+        NamespaceId::URL
+    );
+
+    let string_form = bincode::serialize("6ba7b811-9dad-11d1-80b4-00c04fd430c8").unwrap();
+    assert_eq!(
+        bincode::deserialize::<NamespaceId>(&string_form).unwrap(),
+        NamespaceId::URL
+    );
+}
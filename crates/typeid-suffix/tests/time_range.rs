@@ -0,0 +1,88 @@
+//! Integration tests for the `UUIDv7` time-range bounds of `TypeIdSuffix`.
+//!
+//! These tests check that every `UUIDv7` created during a millisecond sorts between the minimum
+//! and maximum suffixes for that millisecond, both as suffixes and as encoded strings.
+
+use std::time::{Duration, UNIX_EPOCH};
+
+use proptest::prelude::*;
+use typeid_suffix::prelude::*;
+use uuid::Builder;
+
+const MAX_MILLIS: u64 = (1 << 48) - 1;
+
+prop_compose! {
+    fn arbitrary_v7()(millis in 1..MAX_MILLIS, random in proptest::array::uniform10(any::<u8>())) -> (u64, TypeIdSuffix) {
+        (millis, TypeIdSuffix::from(Builder::from_unix_timestamp_millis(millis, &random).into_uuid()))
+    }
+}
+
+proptest! {
+    #[test]
+    fn test_v7_within_its_millisecond((millis, suffix) in arbitrary_v7()) {
+        let time = UNIX_EPOCH + Duration::from_millis(millis);
+        let min = TypeIdSuffix::min_for_time(time);
+        let max = TypeIdSuffix::max_for_time(time);
+
+        prop_assert!(min <= suffix && suffix <= max);
+        prop_assert!(min.as_ref() <= suffix.as_ref() && suffix.as_ref() <= max.as_ref());
+    }
+
+    #[test]
+    fn test_v7_outside_neighbouring_milliseconds((millis, suffix) in arbitrary_v7()) {
+        let before = UNIX_EPOCH + Duration::from_millis(millis - 1);
+        let after = UNIX_EPOCH + Duration::from_millis(millis + 1);
+
+        prop_assert!(TypeIdSuffix::max_for_time(before) < suffix);
+        prop_assert!(TypeIdSuffix::max_for_time(before).as_ref() < suffix.as_ref());
+        prop_assert!(suffix < TypeIdSuffix::min_for_time(after));
+        prop_assert!(suffix.as_ref() < TypeIdSuffix::min_for_time(after).as_ref());
+    }
+
+    #[test]
+    fn test_bounds_ignore_sub_millisecond_precision(millis in 0..MAX_MILLIS, nanos in 0..1_000_000u64) {
+        let start = UNIX_EPOCH + Duration::from_millis(millis);
+        let time = start + Duration::from_nanos(nanos);
+
+        prop_assert_eq!(TypeIdSuffix::min_for_time(time), TypeIdSuffix::min_for_time(start));
+        prop_assert_eq!(TypeIdSuffix::max_for_time(time), TypeIdSuffix::max_for_time(start));
+    }
+}
+
+#[test]
+fn test_bounds_are_valid_v7_suffixes() {
+    let time = UNIX_EPOCH + Duration::from_millis(1_688_096_058_518);
+
+    for bound in [
+        TypeIdSuffix::min_for_time(time),
+        TypeIdSuffix::max_for_time(time),
+    ] {
+        assert_eq!(bound.to_uuid().get_version(), Some(Version::SortRand));
+        assert_eq!(TypeIdSuffix::from_str(&bound).unwrap(), bound);
+        assert_eq!(bound.timestamp(), Some(time));
+    }
+}
+
+#[test]
+fn test_bounds_contain_generated_suffixes() {
+    let start = std::time::SystemTime::now();
+    let suffixes: Vec<TypeIdSuffix> = (0..100).map(|_| TypeIdSuffix::new::<V7>()).collect();
+    let end = std::time::SystemTime::now();
+
+    let range = TypeIdSuffix::min_for_time(start)..=TypeIdSuffix::max_for_time(end);
+    assert!(suffixes.iter().all(|suffix| range.contains(suffix)));
+}
+
+#[test]
+fn test_bounds_are_clamped() {
+    let before_epoch = UNIX_EPOCH - Duration::from_secs(1);
+    assert_eq!(
+        TypeIdSuffix::min_for_time(before_epoch),
+        TypeIdSuffix::min_for_time(UNIX_EPOCH)
+    );
+
+    let far_future = UNIX_EPOCH + Duration::from_millis(MAX_MILLIS) + Duration::from_secs(1);
+    let max = TypeIdSuffix::max_for_time(far_future);
+    assert_eq!(max.timestamp_millis(), i64::try_from(MAX_MILLIS).ok());
+    assert_eq!(max.to_string(), "7zzzzzzzzzfzzvzzzzzzzzzzzz");
+}
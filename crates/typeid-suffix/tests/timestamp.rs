@@ -0,0 +1,140 @@
+//! Integration tests for reading timestamps from time-based suffixes.
+//!
+//! These tests cover the Unix-epoch timestamps of V7 UUIDs, the Gregorian-epoch timestamps of
+//! V1 and V6 UUIDs, and versions that carry no timestamp at all.
+
+use std::str::FromStr;
+use std::time::{Duration, UNIX_EPOCH};
+
+use typeid_suffix::prelude::*;
+use uuid::Builder;
+
+/// The number of 100-nanosecond intervals between 1582-10-15 and 1970-01-01.
+const GREGORIAN_OFFSET: i64 = 0x01B2_1DD2_1381_4000;
+
+/// 2022-02-22T19:22:22Z, the creation time of the test vectors in RFC 9562.
+const RFC_SECS: u64 = 1_645_557_742;
+
+#[test]
+fn test_rfc_9562_test_vectors() {
+    let expected = Some(UNIX_EPOCH + Duration::from_secs(RFC_SECS));
+
+    let v1 = TypeIdSuffix::from(Uuid::parse_str("c232ab00-9414-11ec-b3c8-9f6bdeced846").unwrap());
+    let v6 = TypeIdSuffix::from(Uuid::parse_str("1ec9414c-232a-6b00-b3c8-9f6bdeced846").unwrap());
+    let v7 = TypeIdSuffix::from(Uuid::parse_str("017f22e2-79b0-7cc3-98c4-dc0c0c07398f").unwrap());
+
+    for suffix in [&v1, &v6, &v7] {
+        assert_eq!(suffix.timestamp(), expected);
+        assert_eq!(suffix.timestamp_millis(), Some(1_645_557_742_000));
+        assert_eq!(suffix.timestamp_100ns(), Some(16_455_577_420_000_000));
+    }
+}
+
+#[test]
+fn test_v7_millisecond_precision() {
+    let suffix = TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").unwrap();
+
+    assert_eq!(suffix.timestamp_millis(), Some(1_688_096_058_518));
+    assert_eq!(suffix.timestamp_100ns(), Some(16_880_960_585_180_000));
+    assert_eq!(
+        suffix.timestamp(),
+        Some(UNIX_EPOCH + Duration::from_millis(1_688_096_058_518))
+    );
+}
+
+#[test]
+fn test_v1_and_v6_keep_100ns_precision() {
+    let created = UNIX_EPOCH + Duration::new(1_700_000_000, 123_456_700);
+
+    for suffix in [
+        TypeIdSuffix::new_with_source::<V1, _>(&SeededIdSource::new(1).with_start_time(created)),
+        TypeIdSuffix::new_with_source::<V6, _>(&SeededIdSource::new(1).with_start_time(created)),
+    ] {
+        assert_eq!(suffix.timestamp(), Some(created));
+        assert_eq!(suffix.timestamp_millis(), Some(1_700_000_000_123));
+        assert_eq!(suffix.timestamp_100ns(), Some(17_000_000_001_234_567));
+    }
+}
+
+#[test]
+fn test_gregorian_timestamps_before_the_unix_epoch() {
+    // The very first tick of the Gregorian calendar
+    let uuid = Builder::from_gregorian_timestamp(1, 0, &[0; 6]).into_uuid();
+    let suffix = TypeIdSuffix::from(uuid);
+
+    assert_eq!(suffix.timestamp_100ns(), Some(1 - GREGORIAN_OFFSET));
+    assert_eq!(
+        suffix.timestamp_millis(),
+        Some((1 - GREGORIAN_OFFSET).div_euclid(10_000))
+    );
+    assert_eq!(
+        suffix.timestamp(),
+        UNIX_EPOCH.checked_sub(Duration::from_nanos(
+            (GREGORIAN_OFFSET - 1).unsigned_abs() * 100
+        ))
+    );
+}
+
+#[test]
+fn test_timestamps_past_the_year_2554() {
+    // The largest V7 timestamp, in the year 10889
+    let millis = 0xFFFF_FFFF_FFFE;
+    let v7 = TypeIdSuffix::from(Builder::from_unix_timestamp_millis(millis, &[0; 10]).into_uuid());
+    let expected = UNIX_EPOCH + Duration::from_millis(millis);
+    assert_eq!(v7.timestamp_millis(), Some(i64::try_from(millis).unwrap()));
+    assert_eq!(v7.timestamp(), Some(expected));
+    assert_eq!(
+        TypeIdSuffix::min_for_time(expected).timestamp(),
+        Some(expected)
+    );
+    assert_eq!(
+        TypeIdSuffix::max_for_time(expected).timestamp(),
+        Some(expected)
+    );
+
+    // The largest V1 and V6 timestamps, in the year 5236
+    let ticks = (1 << 60) - 1;
+    let since_unix = i64::try_from(ticks).unwrap() - GREGORIAN_OFFSET;
+    let expected = UNIX_EPOCH
+        + Duration::new(
+            since_unix.unsigned_abs() / 10_000_000,
+            u32::try_from(since_unix % 10_000_000 * 100).unwrap(),
+        );
+    for uuid in [
+        Builder::from_gregorian_timestamp(ticks, 0, &[0; 6]).into_uuid(),
+        Builder::from_sorted_gregorian_timestamp(ticks, 0, &[0; 6]).into_uuid(),
+    ] {
+        let suffix = TypeIdSuffix::from(uuid);
+        assert_eq!(suffix.timestamp_100ns(), Some(since_unix));
+        assert_eq!(suffix.timestamp(), Some(expected));
+    }
+}
+
+#[test]
+fn test_generated_suffixes_are_recent() {
+    let before = std::time::SystemTime::now() - Duration::from_secs(1);
+
+    for suffix in [
+        TypeIdSuffix::new::<V1>(),
+        TypeIdSuffix::new::<V6>(),
+        TypeIdSuffix::new::<V7>(),
+    ] {
+        let timestamp = suffix.timestamp().expect("time-based suffix");
+        assert!(timestamp >= before);
+    }
+}
+
+#[test]
+fn test_versions_without_timestamps() {
+    for suffix in [
+        TypeIdSuffix::new::<V4>(),
+        TypeIdSuffix::new_v3(NamespaceId::DNS, b"example.com"),
+        TypeIdSuffix::new_v5(NamespaceId::DNS, b"example.com"),
+        TypeIdSuffix::new::<Nil>(),
+        TypeIdSuffix::from(Uuid::max()),
+    ] {
+        assert_eq!(suffix.timestamp(), None);
+        assert_eq!(suffix.timestamp_millis(), None);
+        assert_eq!(suffix.timestamp_100ns(), None);
+    }
+}
@@ -46,3 +46,40 @@ fn test_invalid_first_character() {
     let invalid_suffix = "80000000000000000000000000";
     assert!(TypeIdSuffix::from_str(invalid_suffix).is_err());
 }
+
+#[test]
+fn test_invalid_character_position() {
+    assert_eq!(
+        TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn0uq"),
+        Err(DecodeError::InvalidSuffix(
+            InvalidSuffixReason::InvalidCharacter {
+                position: 24,
+                character: 'u'
+            }
+        ))
+    );
+    // Only the first invalid character is reported
+    assert_eq!(
+        TypeIdSuffix::from_str("0Ih455vb4pex5vsknk084sn0lq"),
+        Err(DecodeError::InvalidSuffix(
+            InvalidSuffixReason::InvalidCharacter {
+                position: 1,
+                character: 'I'
+            }
+        ))
+    );
+}
+
+#[test]
+fn test_non_ascii_character_position() {
+    // 'é' takes two bytes, so the suffix is 26 bytes long
+    assert_eq!(
+        TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn\u{e9}q"),
+        Err(DecodeError::InvalidSuffix(
+            InvalidSuffixReason::NonAsciiCharacter {
+                position: 23,
+                character: '\u{e9}'
+            }
+        ))
+    );
+}
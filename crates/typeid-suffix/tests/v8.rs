@@ -0,0 +1,161 @@
+//! Integration tests for `UUIDv8` suffixes with custom layouts.
+//!
+//! These tests check that the 122 free bits round-trip through `V8` and `TypeIdSuffix`, and that
+//! `V8` suffixes sort by their packed value.
+
+use proptest::prelude::*;
+use typeid_suffix::prelude::*;
+
+/// A layout with a 48-bit timestamp, a 16-bit tenant, an 8-bit type tag and a 50-bit sequence.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct TenantEvent {
+    millis: u64,
+    tenant: u16,
+    tag: u8,
+    sequence: u64,
+}
+
+impl CustomLayout for TenantEvent {
+    const SORTABLE: bool = true;
+
+    fn pack(&self) -> u128 {
+        (u128::from(self.millis & 0xFFFF_FFFF_FFFF) << 74)
+            | (u128::from(self.tenant) << 58)
+            | (u128::from(self.tag) << 50)
+            | u128::from(self.sequence & ((1 << 50) - 1))
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn unpack(bits: u128) -> Self {
+        Self {
+            millis: (bits >> 74) as u64,
+            tenant: (bits >> 58) as u16,
+            tag: (bits >> 50) as u8,
+            sequence: (bits as u64) & ((1 << 50) - 1),
+        }
+    }
+}
+
+prop_compose! {
+    fn tenant_event()(
+        millis in 0..(1u64 << 48),
+        tenant in any::<u16>(),
+        tag in any::<u8>(),
+        sequence in 0..(1u64 << 50),
+    ) -> TenantEvent {
+        TenantEvent { millis, tenant, tag, sequence }
+    }
+}
+
+proptest! {
+    #[test]
+    fn test_bits_roundtrip(bits in any::<u128>()) {
+        let v8 = V8::from_bits(bits);
+        prop_assert_eq!(v8.bits(), bits & V8::MAX_BITS);
+        prop_assert_eq!(v8.get_version(), Some(Version::Custom));
+        prop_assert_eq!(v8.get_variant(), Variant::RFC4122);
+
+        let suffix = TypeIdSuffix::from(*v8);
+        let parsed = TypeIdSuffix::from_str(&suffix).unwrap();
+        prop_assert_eq!(V8::try_from(parsed.to_uuid()).unwrap().bits(), bits & V8::MAX_BITS);
+    }
+
+    #[test]
+    fn test_suffixes_sort_by_bits(a in 0..=V8::MAX_BITS, b in 0..=V8::MAX_BITS) {
+        let suffix_a = TypeIdSuffix::from(*V8::from_bits(a));
+        let suffix_b = TypeIdSuffix::from(*V8::from_bits(b));
+
+        prop_assert_eq!(suffix_a.cmp(&suffix_b), a.cmp(&b));
+        prop_assert_eq!(suffix_a.as_ref().cmp(suffix_b.as_ref()), a.cmp(&b));
+    }
+
+    #[test]
+    fn test_layout_roundtrip(event in tenant_event()) {
+        let suffix = TypeIdSuffix::from_layout(&event);
+        prop_assert_eq!(suffix.to_layout::<TenantEvent>(), Ok(event.clone()));
+        prop_assert_eq!(V8::from_layout(&event).to_layout::<TenantEvent>(), event);
+    }
+
+    #[test]
+    fn test_sortable_layout_sorts_suffixes(a in tenant_event(), b in tenant_event()) {
+        let suffix_a = TypeIdSuffix::from_layout(&a);
+        let suffix_b = TypeIdSuffix::from_layout(&b);
+        prop_assert!(suffix_a.is_sortable_as::<TenantEvent>());
+        prop_assert_eq!(suffix_a.cmp(&suffix_b), a.cmp(&b));
+    }
+}
+
+#[test]
+fn test_non_v8_suffixes_are_rejected() {
+    assert_eq!(
+        TypeIdSuffix::new::<V7>().to_layout::<TenantEvent>(),
+        Err(DecodeError::InvalidUuid(InvalidUuidReason::InvalidVersion))
+    );
+    assert!(V8::try_from(Uuid::nil()).is_err());
+
+    // Version 8 with a non-RFC 9562 variant
+    let microsoft = Uuid::from_u128(V8::from_bits(0).as_u128() | (0b11 << 62));
+    assert_eq!(
+        V8::try_from(microsoft).unwrap_err(),
+        DecodeError::InvalidUuid(InvalidUuidReason::InvalidVariant)
+    );
+}
+
+#[test]
+fn test_layouts_are_not_sortable_by_default() {
+    struct Unordered;
+
+    impl CustomLayout for Unordered {
+        fn pack(&self) -> u128 {
+            0
+        }
+
+        fn unpack(_bits: u128) -> Self {
+            Self
+        }
+    }
+
+    let suffix = TypeIdSuffix::from_layout(&Unordered);
+    assert!(!V8::is_sortable::<Unordered>());
+    assert!(!suffix.is_sortable());
+    assert!(!suffix.is_sortable_as::<Unordered>());
+}
+
+#[test]
+fn test_sortable_layout_is_reported_sortable() {
+    let earlier = TenantEvent {
+        millis: 1_700_000_000_000,
+        tenant: 9,
+        tag: 200,
+        sequence: 5,
+    };
+    let later = TenantEvent {
+        millis: 1_700_000_000_001,
+        tenant: 0,
+        tag: 0,
+        sequence: 0,
+    };
+    let suffix_earlier = TypeIdSuffix::from_layout(&earlier);
+    let suffix_later = TypeIdSuffix::from_layout(&later);
+
+    assert!(V8::is_sortable::<TenantEvent>());
+    assert!(suffix_earlier.is_sortable_as::<TenantEvent>());
+    assert!(suffix_later.is_sortable_as::<TenantEvent>());
+    assert!(suffix_earlier < suffix_later);
+
+    // Only V8 suffixes take on the sortability of a layout
+    assert!(!TypeIdSuffix::new::<V4>().is_sortable_as::<TenantEvent>());
+    assert!(TypeIdSuffix::new::<V7>().is_sortable_as::<TenantEvent>());
+}
+
+#[test]
+fn test_extreme_bits() {
+    assert_eq!(
+        TypeIdSuffix::from(*V8::from_bits(0)).to_string(),
+        "0000000000g008000000000000"
+    );
+    assert_eq!(
+        TypeIdSuffix::from(*V8::from_bits(V8::MAX_BITS)).to_string(),
+        "7zzzzzzzzzhzzvzzzzzzzzzzzz"
+    );
+}
@@ -0,0 +1,94 @@
+//! Integration tests for the version and variant introspection of `TypeIdSuffix`.
+//!
+//! These tests check that every UUID version maps onto the right `SuffixVersion`, and that the
+//! marker types agree with the versions of the suffixes they generate.
+
+use typeid_suffix::prelude::*;
+
+fn with_version(number: u8) -> TypeIdSuffix {
+    let bits = Uuid::new_v4().as_u128() & !(0xF << 76);
+    TypeIdSuffix::from(Uuid::from_u128(bits | (u128::from(number) << 76)))
+}
+
+#[test]
+fn test_marker_types_match_generated_versions() {
+    assert_eq!(TypeIdSuffix::new::<V1>().version(), V1::VERSION);
+    assert_eq!(TypeIdSuffix::new::<V3>().version(), V3::VERSION);
+    assert_eq!(TypeIdSuffix::new::<V4>().version(), V4::VERSION);
+    assert_eq!(TypeIdSuffix::new::<V5>().version(), V5::VERSION);
+    assert_eq!(TypeIdSuffix::new::<V6>().version(), V6::VERSION);
+    assert_eq!(TypeIdSuffix::new::<V7>().version(), V7::VERSION);
+    assert_eq!(TypeIdSuffix::new::<Nil>().version(), Nil::VERSION);
+    assert_eq!(TypeIdSuffix::from(*V8::from_bits(1)).version(), V8::VERSION);
+}
+
+#[test]
+fn test_version_numbers() {
+    let expected = [
+        (1, SuffixVersion::V1),
+        (2, SuffixVersion::V2),
+        (3, SuffixVersion::V3),
+        (4, SuffixVersion::V4),
+        (5, SuffixVersion::V5),
+        (6, SuffixVersion::V6),
+        (7, SuffixVersion::V7),
+        (8, SuffixVersion::V8),
+        (9, SuffixVersion::Unknown(9)),
+        (12, SuffixVersion::Unknown(12)),
+    ];
+    for (number, version) in expected {
+        assert_eq!(with_version(number).version(), version);
+        assert_eq!(version.number(), number);
+    }
+
+    assert_eq!(TypeIdSuffix::from(Uuid::nil()).version().number(), 0);
+    assert_eq!(TypeIdSuffix::from(Uuid::max()).version().number(), 15);
+    // Version nibbles 0 and 15 only mean Nil and Max for the all-zero and all-one UUIDs
+    assert_eq!(with_version(0).version(), SuffixVersion::Unknown(0));
+    assert_eq!(with_version(15).version(), SuffixVersion::Unknown(15));
+}
+
+#[test]
+fn test_is_version() {
+    let suffix = TypeIdSuffix::new::<V7>();
+    assert!(suffix.is_version::<V7>());
+    assert!(!suffix.is_version::<V6>());
+    assert!(!suffix.is_version::<V4>());
+    assert!(TypeIdSuffix::new::<Nil>().is_version::<Nil>());
+}
+
+#[test]
+fn test_time_based_and_sortable() {
+    let cases = [
+        (TypeIdSuffix::new::<V1>(), true, false),
+        (TypeIdSuffix::new::<V3>(), false, false),
+        (TypeIdSuffix::new::<V4>(), false, false),
+        (TypeIdSuffix::new::<V5>(), false, false),
+        (TypeIdSuffix::new::<V6>(), true, true),
+        (TypeIdSuffix::new::<V7>(), true, true),
+        (TypeIdSuffix::from(*V8::from_bits(1)), false, false),
+        (TypeIdSuffix::new::<Nil>(), false, false),
+        (TypeIdSuffix::from(Uuid::max()), false, false),
+    ];
+    for (suffix, time_based, sortable) in cases {
+        assert_eq!(suffix.is_time_based(), time_based, "{}", suffix.version());
+        assert_eq!(suffix.is_sortable(), sortable, "{}", suffix.version());
+        // Time-based suffixes are exactly those with a readable timestamp
+        assert_eq!(suffix.timestamp().is_some(), time_based);
+    }
+}
+
+#[test]
+fn test_variant() {
+    assert_eq!(TypeIdSuffix::new::<V7>().variant(), Variant::RFC4122);
+    assert_eq!(TypeIdSuffix::new::<Nil>().variant(), Variant::NCS);
+    assert_eq!(TypeIdSuffix::from(Uuid::max()).variant(), Variant::Future);
+}
+
+#[test]
+fn test_display() {
+    assert_eq!(SuffixVersion::V7.to_string(), "v7");
+    assert_eq!(SuffixVersion::Nil.to_string(), "nil");
+    assert_eq!(SuffixVersion::Max.to_string(), "max");
+    assert_eq!(SuffixVersion::Unknown(11).to_string(), "unknown version 11");
+}